@@ -0,0 +1,282 @@
+//! Derive macros for [`cdumay_core`](https://docs.rs/cdumay_core).
+//!
+//! This crate is not meant to be used directly: enable the `derive` feature of
+//! `cdumay_core` and use the re-exported macros instead.
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{Attribute, Data, DeriveInput, Expr, Fields, LitStr, Path, Token, Type, parse_macro_input};
+
+/// Returns the path of the `cdumay_core` crate, set with `#[cdumay(crate = ...)]` when it is
+/// renamed or re-exported, and defaulting to `::cdumay_core`.
+fn crate_path(attrs: &[Attribute]) -> syn::Result<Path> {
+    let mut path = None;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("cdumay")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("crate") {
+                path = Some(meta.value()?.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("expected `crate`"))
+            }
+        })?;
+    }
+    Ok(path.unwrap_or_else(|| syn::parse_quote!(::cdumay_core)))
+}
+
+/// Derives conversion of an enum into `cdumay_core::Error`.
+///
+/// Each variant must declare its kind with `#[kind(...)]` (an `ErrorKind` constant or any
+/// value implementing `cdumay_core::Kind`), and may override the
+/// status code with `#[code(...)]` and the message with `#[message("...")]`.
+/// Fields of a variant are serialized into the error details.
+///
+/// The generated code refers to `::cdumay_core`, which can be overridden with
+/// `#[cdumay(crate = path::to::cdumay_core)]` on the enum.
+///
+/// # Example
+/// ```ignore
+/// use cdumay_core::{define_kinds, AsCdumayError};
+///
+/// define_kinds! {
+///     NotFound = (404, "Resource Not Found"),
+/// }
+///
+/// #[derive(Debug, AsCdumayError)]
+/// enum UserError {
+///     #[kind(NotFound)]
+///     #[message("User not found")]
+///     UserNotFound { id: u64 },
+/// }
+/// ```
+#[proc_macro_derive(AsCdumayError, attributes(cdumay, kind, code, message))]
+pub fn derive_as_cdumay_error(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_as_cdumay_error(input).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+fn expand_as_cdumay_error(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let name = &input.ident;
+    let krate = crate_path(&input.attrs)?;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let Data::Enum(data) = &input.data else {
+        return Err(syn::Error::new_spanned(&input.ident, "AsCdumayError can only be derived for enums"));
+    };
+
+    let mut kind_arms = Vec::new();
+    let mut code_arms = Vec::new();
+    let mut message_arms = Vec::new();
+    let mut display_arms = Vec::new();
+    let mut class_arms = Vec::new();
+    let mut builder_arms = Vec::new();
+    let mut details_arms = Vec::new();
+
+    for variant in &data.variants {
+        let ident = &variant.ident;
+        let mut kind: Option<Path> = None;
+        let mut code: Option<Expr> = None;
+        let mut message: Option<LitStr> = None;
+        for attr in &variant.attrs {
+            if attr.path().is_ident("kind") {
+                kind = Some(attr.parse_args()?);
+            } else if attr.path().is_ident("code") {
+                code = Some(attr.parse_args()?);
+            } else if attr.path().is_ident("message") {
+                message = Some(attr.parse_args()?);
+            }
+        }
+        let kind = kind.ok_or_else(|| syn::Error::new_spanned(ident, "missing #[kind(...)] attribute"))?;
+
+        let (pattern, inserts) = match &variant.fields {
+            Fields::Named(fields) => {
+                let idents: Vec<_> = fields.named.iter().filter_map(|f| f.ident.clone()).collect();
+                let keys: Vec<_> = idents.iter().map(|i| i.to_string()).collect();
+                (
+                    quote! { #name::#ident { #(#idents),* } },
+                    quote! { #(details.insert(#keys.to_string(), #krate::__private::serde_value::to_value(#idents).unwrap_or(#krate::__private::serde_value::Value::Unit));)* },
+                )
+            }
+            Fields::Unnamed(fields) => {
+                let idents: Vec<_> = (0..fields.unnamed.len()).map(|i| quote::format_ident!("field{}", i)).collect();
+                let keys: Vec<_> = (0..fields.unnamed.len()).map(|i| i.to_string()).collect();
+                (
+                    quote! { #name::#ident ( #(#idents),* ) },
+                    quote! { #(details.insert(#keys.to_string(), #krate::__private::serde_value::to_value(#idents).unwrap_or(#krate::__private::serde_value::Value::Unit));)* },
+                )
+            }
+            Fields::Unit => (quote! { #name::#ident }, quote! {}),
+        };
+        let wildcard = match &variant.fields {
+            Fields::Named(_) => quote! { #name::#ident { .. } },
+            Fields::Unnamed(_) => quote! { #name::#ident ( .. ) },
+            Fields::Unit => quote! { #name::#ident },
+        };
+
+        kind_arms.push(quote! {
+            #wildcard => #krate::__private::error_kind(&#kind)
+        });
+        code_arms.push(match &code {
+            Some(code) => quote! { #wildcard => #code },
+            None => quote! { #wildcard => #krate::Kind::code(&#kind) },
+        });
+        message_arms.push(match &message {
+            Some(message) => quote! { #wildcard => #message.to_string() },
+            None => quote! { #wildcard => #krate::Kind::description(&#kind).to_string() },
+        });
+        display_arms.push(match &message {
+            Some(message) => quote! { #wildcard => write!(f, "{}", #message) },
+            None => quote! { #wildcard => f.write_str(#krate::Kind::description(&#kind)) },
+        });
+        let variant_name = ident.to_string();
+        class_arms.push(quote! {
+            #wildcard => format!("{}::{}::{}", #krate::Kind::side(&#kind), #krate::Kind::name(&#kind), #variant_name)
+        });
+        builder_arms.push(quote! { #wildcard => #krate::ErrorBuilder::new(#kind, #variant_name) });
+        details_arms.push(quote! { #[allow(unused_variables)] #pattern => { #inserts } });
+    }
+
+    Ok(quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Returns the kind of the error.
+            pub fn kind(&self) -> #krate::ErrorKind {
+                match self { #(#kind_arms,)* }
+            }
+            /// Numerical status or error code (e.g., HTTP status code).
+            pub fn code(&self) -> u16 {
+                match self { #(#code_arms,)* }
+            }
+            /// Returns the error message as a `String`.
+            pub fn message(&self) -> String {
+                match self { #(#message_arms,)* }
+            }
+            /// Returns the variant fields as a details map.
+            pub fn details(&self) -> std::collections::BTreeMap<String, #krate::__private::serde_value::Value> {
+                let mut details = std::collections::BTreeMap::new();
+                match self { #(#details_arms)* }
+                details
+            }
+            /// Returns the error class as a `String`.
+            pub fn class(&self) -> String {
+                match self { #(#class_arms,)* }
+            }
+        }
+
+        impl #impl_generics std::fmt::Display for #name #ty_generics #where_clause {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "{} ({}): ", self.class(), self.code())?;
+                match self { #(#display_arms,)* }?;
+                if f.alternate() {
+                    #krate::__private::write_verbose(f, None, None, self.details(), &[], None)?;
+                }
+                Ok(())
+            }
+        }
+
+        impl #impl_generics std::error::Error for #name #ty_generics #where_clause {}
+
+        impl #impl_generics From<#name #ty_generics> for #krate::Error #where_clause {
+            fn from(err: #name #ty_generics) -> #krate::Error {
+                // Built from the kind itself, so that its side and documentation URL are kept
+                let builder = match &err { #(#builder_arms,)* };
+                builder
+                    .with_code(err.code())
+                    .with_message(err.message())
+                    .with_details(err.details())
+                    .build()
+            }
+        }
+    })
+}
+
+/// Derives `cdumay_core::ErrorConverter` for a struct.
+///
+/// The struct must declare the converted error type and the target kind with
+/// `#[converter(error = ..., kind = ...)]`. The error name defaults to the last segment of the
+/// error type and can be overridden with `name = "..."`, the status code with `code = ...`.
+/// Each `#[detail("key", expr)]` attribute inserts the serialized value of `expr` into the
+/// details, where `expr` can use `error`, a reference to the converted error. The path of
+/// `cdumay_core` can be overridden with `#[cdumay(crate = ...)]`, as for `AsCdumayError`.
+///
+/// # Example
+/// ```ignore
+/// use cdumay_core::ErrorConverter;
+/// use cdumay_core::kinds::http::BadRequest;
+///
+/// #[derive(ErrorConverter)]
+/// #[converter(error = std::num::ParseIntError, kind = BadRequest)]
+/// #[detail("reason", format!("{:?}", error.kind()))]
+/// struct ParseIntConverter;
+/// ```
+#[proc_macro_derive(ErrorConverter, attributes(cdumay, converter, detail))]
+pub fn derive_error_converter(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_error_converter(input).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+fn expand_error_converter(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let ident = &input.ident;
+    let krate = crate_path(&input.attrs)?;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let mut error: Option<Type> = None;
+    let mut kind: Option<Path> = None;
+    let mut name: Option<LitStr> = None;
+    let mut code: Option<Expr> = None;
+    let mut details = Vec::new();
+    for attr in &input.attrs {
+        if attr.path().is_ident("converter") {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("error") {
+                    error = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("kind") {
+                    kind = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("name") {
+                    name = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("code") {
+                    code = Some(meta.value()?.parse()?);
+                } else {
+                    return Err(meta.error("expected `error`, `kind`, `name` or `code`"));
+                }
+                Ok(())
+            })?;
+        } else if attr.path().is_ident("detail") {
+            details.push(attr.parse_args_with(|input: syn::parse::ParseStream| {
+                let key: LitStr = input.parse()?;
+                input.parse::<Token![,]>()?;
+                let value: Expr = input.parse()?;
+                Ok((key, value))
+            })?);
+        }
+    }
+    let error = error.ok_or_else(|| syn::Error::new_spanned(ident, "missing `error` in #[converter(...)] attribute"))?;
+    let kind = kind.ok_or_else(|| syn::Error::new_spanned(ident, "missing `kind` in #[converter(...)] attribute"))?;
+    let segment = match &error {
+        Type::Path(path) => path.path.segments.last(),
+        _ => None,
+    };
+    let name = match (name, segment) {
+        (Some(name), _) => name,
+        (None, Some(segment)) => LitStr::new(&segment.ident.to_string(), segment.ident.span()),
+        (None, None) => return Err(syn::Error::new_spanned(&error, "cannot infer the error name, set `name` in #[converter(...)] attribute")),
+    };
+    let with_code = code.map(|code| quote! { .with_code(#code) });
+    let (keys, values): (Vec<_>, Vec<_>) = details.into_iter().unzip();
+
+    Ok(quote! {
+        impl #impl_generics #krate::ErrorConverter for #ident #ty_generics #where_clause {
+            type Error = #error;
+
+            fn convert(
+                error: &Self::Error,
+                text: String,
+                mut context: std::collections::BTreeMap<String, #krate::__private::serde_value::Value>,
+            ) -> #krate::Error {
+                #(context.insert(#keys.to_string(), #krate::__private::serde_value::to_value(&(#values)).unwrap_or(#krate::__private::serde_value::Value::Unit));)*
+                #krate::ErrorBuilder::new(#kind, #name)
+                    #with_code
+                    .with_message(text)
+                    .with_details(context)
+                    .build()
+            }
+        }
+    })
+}
@@ -0,0 +1,157 @@
+//! Circuit breaker built on top of the error taxonomy.
+//!
+//! A [`Breaker`] consumes the results of the calls to a dependency. Errors tripping the circuit
+//! (by default, server errors) are counted, and once the threshold is reached the circuit opens:
+//! calls are rejected until the open duration elapses. The circuit is then half-open: the next
+//! result closes it on success, or opens it again on failure.
+//!
+//! Errors are classified using [`Breaker::classify`], which can be tuned per kind using
+//! [`Breaker::with_kind_signal`] or per error using [`Error::with_circuit_signal`](crate::Error::with_circuit_signal).
+//!
+//! # Example
+//! ```
+//! use std::time::Duration;
+//! use cdumay_core::breaker::{Breaker, CircuitSignal, CircuitState};
+//! use cdumay_core::kinds::http::{BadGateway, NotFound, TooManyRequests};
+//! use cdumay_core::{ErrorBuilder, Result};
+//!
+//! let mut breaker = Breaker::new(2, Duration::from_secs(30)).with_kind_signal(&TooManyRequests, CircuitSignal::Trip);
+//!
+//! let results: Vec<Result<()>> = vec![
+//!     Err(ErrorBuilder::new(BadGateway, "UpstreamDown").build()),
+//!     Err(ErrorBuilder::new(NotFound, "UnknownUser").build()),
+//!     Err(ErrorBuilder::new(TooManyRequests, "Throttled").build()),
+//! ];
+//! breaker.record_all(&results);
+//! assert_eq!(breaker.state(), CircuitState::Open);
+//!
+//! let err = breaker.call(|| Ok(())).unwrap_err();
+//! assert_eq!(err.class(), "Server::ServiceUnavailable::CircuitOpen");
+//! ```
+use std::collections::BTreeMap;
+use std::time::{Duration, Instant};
+
+/// Effect of an error on a circuit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub enum CircuitSignal {
+    /// The error is counted as a failure of the dependency
+    Trip,
+    /// The error is not related to the health of the dependency, e.g. a validation error
+    Ignore,
+}
+
+/// State of a circuit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CircuitState {
+    /// Calls are allowed, failures are counted
+    Closed,
+    /// Calls are rejected
+    Open,
+    /// A call is allowed to probe the dependency
+    HalfOpen,
+}
+
+/// A circuit breaker, see the [module documentation](self).
+///
+/// It is not synchronized: share it between threads using a `Mutex`.
+#[derive(Debug, Clone)]
+pub struct Breaker {
+    failure_threshold: u32,
+    open_duration: Duration,
+    signals: BTreeMap<String, CircuitSignal>,
+    failures: u32,
+    opened_at: Option<Instant>,
+}
+
+impl Breaker {
+    /// Creates a closed breaker, opening after `failure_threshold` consecutive failures, for `open_duration`.
+    pub fn new(failure_threshold: u32, open_duration: Duration) -> Self {
+        Self {
+            failure_threshold,
+            open_duration,
+            signals: BTreeMap::new(),
+            failures: 0,
+            opened_at: None,
+        }
+    }
+
+    /// Sets the signal of the errors raised from the given kind.
+    pub fn with_kind_signal<K: crate::Kind>(mut self, kind: &K, signal: CircuitSignal) -> Self {
+        self.signals.insert(kind.name().to_string(), signal);
+        self
+    }
+
+    /// Returns the effect of the error on the circuit.
+    ///
+    /// The signal set on the error takes precedence over the signal set for its kind. By default,
+    /// server errors trip the circuit while other errors are ignored.
+    pub fn classify(&self, error: &crate::Error) -> CircuitSignal {
+        if let Some(signal) = error.circuit_signal() {
+            return signal;
+        }
+        match error.kind().and_then(|kind| self.signals.get(&kind.name)) {
+            Some(signal) => *signal,
+            None if error.is_server() => CircuitSignal::Trip,
+            None => CircuitSignal::Ignore,
+        }
+    }
+
+    /// Returns the current state of the circuit.
+    pub fn state(&self) -> CircuitState {
+        match self.opened_at {
+            None => CircuitState::Closed,
+            Some(opened_at) if opened_at.elapsed() < self.open_duration => CircuitState::Open,
+            Some(_) => CircuitState::HalfOpen,
+        }
+    }
+
+    /// Records the result of a call.
+    ///
+    /// Successes reset the failure count and close the circuit. Failures open the circuit once
+    /// the threshold is reached or if it is half-open. Ignored errors do not change the failure
+    /// count, but close a half-open circuit as the dependency answered.
+    pub fn record<T>(&mut self, result: &crate::Result<T>) {
+        let signal = match result {
+            Ok(_) => None,
+            Err(error) => Some(self.classify(error)),
+        };
+        match (signal, self.state()) {
+            (None, _) | (Some(CircuitSignal::Ignore), CircuitState::HalfOpen) => {
+                self.failures = 0;
+                self.opened_at = None;
+            }
+            (Some(CircuitSignal::Ignore), _) => {}
+            (Some(CircuitSignal::Trip), CircuitState::Closed) => {
+                self.failures += 1;
+                if self.failures >= self.failure_threshold {
+                    self.opened_at = Some(Instant::now());
+                }
+            }
+            (Some(CircuitSignal::Trip), _) => self.opened_at = Some(Instant::now()),
+        }
+    }
+
+    /// Records the results of several calls, in order.
+    pub fn record_all<'a, T: 'a, I: IntoIterator<Item = &'a crate::Result<T>>>(&mut self, results: I) {
+        for result in results {
+            self.record(result);
+        }
+    }
+
+    /// Runs the call if the circuit is not open, and records its result.
+    ///
+    /// When the circuit is open, a `503 Service Unavailable` error named `CircuitOpen` is
+    /// returned, with the remaining open duration in seconds under the `retry_after` key.
+    pub fn call<T, F: FnOnce() -> crate::Result<T>>(&mut self, f: F) -> crate::Result<T> {
+        if let (CircuitState::Open, Some(opened_at)) = (self.state(), self.opened_at) {
+            let remaining = self.open_duration.saturating_sub(opened_at.elapsed());
+            return Err(crate::ErrorBuilder::new(crate::kinds::http::ServiceUnavailable, "CircuitOpen")
+                .with_detail("retry_after", serde_value::Value::U64(remaining.as_secs() + u64::from(remaining.subsec_nanos() > 0)))
+                .build());
+        }
+        let result = f();
+        self.record(&result);
+        result
+    }
+}
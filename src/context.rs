@@ -0,0 +1,108 @@
+//! Ambient error context propagation.
+//!
+//! Values set using [`with_error_context`] (or [`with_error_context_sync`]) are merged into the
+//! details of every error built by [`ErrorBuilder::build`](crate::ErrorBuilder::build) within
+//! the scope, so that values such as a tenant or request identifier do not have to be passed
+//! through every layer. Details set explicitly on the builder take precedence, and nested scopes
+//! override the values of outer scopes.
+//!
+//! The context is stored in a thread-local, which is set while the wrapped future is polled: it
+//! follows the task even when it is moved between threads.
+//!
+//! # Example
+//! ```
+//! use cdumay_core::context::{ContextMap, with_error_context_sync};
+//! use cdumay_core::ErrorBuilder;
+//! use serde_value::Value;
+//!
+//! let mut context = ContextMap::new();
+//! context.insert("tenant".to_string(), Value::String("acme".to_string()));
+//!
+//! let err = with_error_context_sync(context, || ErrorBuilder::default().build());
+//! assert_eq!(err.details()["tenant"], Value::String("acme".to_string()));
+//! ```
+use std::cell::RefCell;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// Values merged into the details of the errors built within a scope.
+pub type ContextMap = std::collections::BTreeMap<String, serde_value::Value>;
+
+thread_local! {
+    static CURRENT: RefCell<ContextMap> = const { RefCell::new(ContextMap::new()) };
+}
+
+/// Restores the previous context when dropped, even on panic.
+struct Guard(Option<ContextMap>);
+
+impl Guard {
+    fn enter(context: &ContextMap) -> Self {
+        CURRENT.with(|current| {
+            let mut current = current.borrow_mut();
+            let previous = current.clone();
+            current.extend(context.iter().map(|(key, value)| (key.clone(), value.clone())));
+            Guard(Some(previous))
+        })
+    }
+}
+
+impl Drop for Guard {
+    fn drop(&mut self) {
+        if let Some(previous) = self.0.take() {
+            CURRENT.with(|current| *current.borrow_mut() = previous);
+        }
+    }
+}
+
+/// Returns the context of the current scope.
+pub fn current() -> ContextMap {
+    CURRENT.with(|current| current.borrow().clone())
+}
+
+/// Runs `f` with the given values added to the error context.
+pub fn with_error_context_sync<T, F: FnOnce() -> T>(context: ContextMap, f: F) -> T {
+    let _guard = Guard::enter(&context);
+    f()
+}
+
+/// Wraps a future so that the given values are added to the error context while it is polled.
+///
+/// # Example
+/// ```
+/// use cdumay_core::context::{ContextMap, with_error_context};
+/// use cdumay_core::ErrorBuilder;
+/// use serde_value::Value;
+///
+/// async fn handle() -> cdumay_core::Result<()> {
+///     let mut context = ContextMap::new();
+///     context.insert("request_id".to_string(), Value::String("req-42".to_string()));
+///     with_error_context(context, async {
+///         // Any error built here carries the `request_id` detail.
+///         Err(ErrorBuilder::default().build())
+///     })
+///     .await
+/// }
+/// ```
+pub fn with_error_context<F: Future>(context: ContextMap, future: F) -> WithErrorContext<F> {
+    WithErrorContext {
+        context,
+        future: Box::pin(future),
+    }
+}
+
+/// Future returned by [`with_error_context`].
+pub struct WithErrorContext<F: Future> {
+    context: ContextMap,
+    future: Pin<Box<F>>,
+}
+
+impl<F: Future> Future for WithErrorContext<F> {
+    type Output = F::Output;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+        let _guard = Guard::enter(&this.context);
+        this.future.as_mut().poll(cx)
+    }
+}
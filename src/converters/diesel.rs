@@ -0,0 +1,59 @@
+//! Converter for the errors of `diesel`.
+use crate::kinds::http::{Conflict, InternalServerError, NotFound, ServiceUnavailable, UnprocessableEntity};
+use ::diesel::result::DatabaseErrorKind;
+use std::collections::BTreeMap;
+
+/// Converts a `diesel::result::Error` according to its variant.
+///
+/// | Variant | Kind |
+/// |---|---|
+/// | `NotFound` | `404 Not Found` |
+/// | `DatabaseError` (unique, foreign key, restrict or exclusion violation) | `409 Conflict` |
+/// | `DatabaseError` (serialization failure) | `409 Conflict` |
+/// | `DatabaseError` (not null or check violation) | `422 Unprocessable Entity` |
+/// | `DatabaseError` (closed connection, unable to send command) | `503 Service Unavailable` |
+/// | Other | `500 Internal Server Error` |
+///
+/// For database errors, the constraint, table and column (if any) are stored in the details
+/// under the `constraint`, `table` and `column` keys.
+///
+/// # Example
+/// ```
+/// use std::collections::BTreeMap;
+/// use cdumay_core::ErrorConverter;
+/// use cdumay_core::converters::DieselErrorConverter;
+///
+/// let err = DieselErrorConverter::convert_error(&diesel::result::Error::NotFound, None, BTreeMap::new());
+/// assert_eq!(err.code(), 404);
+/// assert_eq!(err.class(), "Client::NotFound::DieselError");
+/// ```
+pub struct DieselErrorConverter;
+
+impl crate::ErrorConverter for DieselErrorConverter {
+    type Error = ::diesel::result::Error;
+
+    fn convert(error: &Self::Error, text: String, mut context: BTreeMap<String, serde_value::Value>) -> crate::Error {
+        let kind = match error {
+            ::diesel::result::Error::NotFound => NotFound,
+            ::diesel::result::Error::DatabaseError(db_kind, info) => {
+                for (key, value) in [("constraint", info.constraint_name()), ("table", info.table_name()), ("column", info.column_name())] {
+                    if let Some(value) = value {
+                        context.insert(key.to_string(), serde_value::Value::String(value.to_string()));
+                    }
+                }
+                match db_kind {
+                    DatabaseErrorKind::UniqueViolation
+                    | DatabaseErrorKind::ForeignKeyViolation
+                    | DatabaseErrorKind::RestrictViolation
+                    | DatabaseErrorKind::ExclusionViolation
+                    | DatabaseErrorKind::SerializationFailure => Conflict,
+                    DatabaseErrorKind::NotNullViolation | DatabaseErrorKind::CheckViolation => UnprocessableEntity,
+                    DatabaseErrorKind::ClosedConnection | DatabaseErrorKind::UnableToSendCommand => ServiceUnavailable,
+                    _ => InternalServerError,
+                }
+            }
+            _ => InternalServerError,
+        };
+        super::build_error(kind, "DieselError", error, text, context)
+    }
+}
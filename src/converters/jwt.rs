@@ -0,0 +1,63 @@
+//! Converter for the errors of `jsonwebtoken`.
+use crate::kinds::http::{Forbidden, InternalServerError, Unauthorized};
+use ::jsonwebtoken::errors::ErrorKind;
+use std::collections::BTreeMap;
+
+/// Converts a `jsonwebtoken::errors::Error` according to its kind.
+///
+/// | Error | Kind |
+/// |---|---|
+/// | Expired or immature signature, invalid signature, malformed token or claims | `401 Unauthorized` |
+/// | Invalid audience, issuer or subject | `403 Forbidden` |
+/// | Invalid key, signing failure and other | `500 Internal Server Error` |
+///
+/// The category of the error (e.g. `expired_signature`, `invalid_signature` or `invalid_audience`)
+/// is stored in the details under the `jwt_category` key, and the name of the missing or invalid
+/// claim (if any) under the `claim` key.
+///
+/// # Example
+/// ```
+/// use std::collections::BTreeMap;
+/// use cdumay_core::ErrorConverter;
+/// use cdumay_core::converters::JwtErrorConverter;
+/// use jsonwebtoken::errors::{Error, ErrorKind};
+///
+/// let err = JwtErrorConverter::convert_error(&Error::from(ErrorKind::ExpiredSignature), None, BTreeMap::new());
+/// assert_eq!(err.code(), 401);
+/// assert_eq!(err.class(), "Client::Unauthorized::JwtError");
+/// assert_eq!(err.details()["jwt_category"], serde_value::Value::String("expired_signature".to_string()));
+/// ```
+pub struct JwtErrorConverter;
+
+impl crate::ErrorConverter for JwtErrorConverter {
+    type Error = ::jsonwebtoken::errors::Error;
+
+    fn convert(error: &Self::Error, text: String, mut context: BTreeMap<String, serde_value::Value>) -> crate::Error {
+        let (kind, category) = match error.kind() {
+            ErrorKind::ExpiredSignature => (Unauthorized, "expired_signature"),
+            ErrorKind::ImmatureSignature => (Unauthorized, "immature_signature"),
+            ErrorKind::InvalidSignature => (Unauthorized, "invalid_signature"),
+            ErrorKind::InvalidToken | ErrorKind::Base64(_) | ErrorKind::Json(_) | ErrorKind::Utf8(_) => (Unauthorized, "invalid_token"),
+            ErrorKind::InvalidAlgorithm | ErrorKind::MissingAlgorithm => (Unauthorized, "invalid_algorithm"),
+            ErrorKind::MissingRequiredClaim(claim) => {
+                context.insert("claim".to_string(), serde_value::Value::String(claim.clone()));
+                (Unauthorized, "missing_claim")
+            }
+            ErrorKind::InvalidClaimFormat(claim) => {
+                context.insert("claim".to_string(), serde_value::Value::String(claim.clone()));
+                (Unauthorized, "invalid_claim")
+            }
+            ErrorKind::InvalidAudience => (Forbidden, "invalid_audience"),
+            ErrorKind::InvalidIssuer => (Forbidden, "invalid_issuer"),
+            ErrorKind::InvalidSubject => (Forbidden, "invalid_subject"),
+            ErrorKind::InvalidEcdsaKey
+            | ErrorKind::InvalidEddsaKey
+            | ErrorKind::InvalidRsaKey(_)
+            | ErrorKind::InvalidAlgorithmName
+            | ErrorKind::InvalidKeyFormat => (InternalServerError, "invalid_key"),
+            _ => (InternalServerError, "signing"),
+        };
+        context.insert("jwt_category".to_string(), serde_value::Value::String(category.to_string()));
+        super::build_error(kind, "JwtError", error, text, context)
+    }
+}
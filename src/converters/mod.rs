@@ -0,0 +1,74 @@
+//! Ready-made [`ErrorConverter`](crate::ErrorConverter) implementations for common error types.
+//!
+//! Each set of converters is enabled by its own feature flag.
+#[cfg(feature = "diesel")]
+mod diesel;
+#[cfg(feature = "jwt")]
+mod jwt;
+#[cfg(feature = "mongodb")]
+mod mongodb;
+#[cfg(feature = "redis")]
+mod redis;
+#[cfg(feature = "reqwest")]
+mod reqwest;
+#[cfg(feature = "serde-json")]
+mod serde_json;
+#[cfg(feature = "sqlx")]
+mod sqlx;
+#[cfg(feature = "std-converters")]
+mod std_errors;
+#[cfg(feature = "url")]
+mod url;
+#[cfg(feature = "uuid")]
+mod uuid;
+
+#[cfg(feature = "diesel")]
+pub use diesel::DieselErrorConverter;
+#[cfg(feature = "jwt")]
+pub use jwt::JwtErrorConverter;
+#[cfg(feature = "mongodb")]
+pub use mongodb::MongodbErrorConverter;
+#[cfg(feature = "redis")]
+pub use redis::RedisErrorConverter;
+#[cfg(feature = "reqwest")]
+pub use reqwest::ReqwestErrorConverter;
+#[cfg(feature = "serde-json")]
+pub use serde_json::SerdeJsonErrorConverter;
+
+#[cfg(feature = "sqlx")]
+pub use sqlx::SqlxErrorConverter;
+#[cfg(feature = "std-converters")]
+pub use std_errors::{
+    AddrParseErrorConverter, FromUtf8ErrorConverter, IoErrorConverter, ParseBoolErrorConverter, ParseFloatErrorConverter, ParseIntErrorConverter, Utf8ErrorConverter,
+    VarErrorConverter,
+};
+#[cfg(feature = "url")]
+pub use url::UrlParseErrorConverter;
+#[cfg(feature = "uuid")]
+pub use uuid::{InvalidIdentifier, UuidErrorConverter};
+
+/// Builds an `Error` from a converter output, keeping the original error string in the details.
+#[cfg(any(
+    feature = "diesel",
+    feature = "jwt",
+    feature = "mongodb",
+    feature = "redis",
+    feature = "reqwest",
+    feature = "serde-json",
+    feature = "sqlx",
+    feature = "std-converters",
+    feature = "url",
+    feature = "uuid"
+))]
+pub(crate) fn build_error(
+    kind: crate::ErrorKind,
+    name: &str,
+    error: &dyn std::error::Error,
+    text: String,
+    mut context: std::collections::BTreeMap<String, serde_value::Value>,
+) -> crate::Error {
+    context
+        .entry("origin".to_string())
+        .or_insert_with(|| serde_value::Value::String(error.to_string()));
+    crate::ErrorBuilder::new(kind, name).with_message(text).with_details(context).build()
+}
@@ -0,0 +1,89 @@
+//! Converter for the errors of `mongodb`.
+use crate::kinds::http::{Conflict, GatewayTimeout, InternalServerError, ServiceUnavailable};
+use ::mongodb::error::{ErrorKind, WriteFailure};
+use std::collections::BTreeMap;
+
+/// Server codes of the duplicate key errors.
+const DUPLICATE_KEY_CODES: [i32; 3] = [11000, 11001, 12582];
+
+/// Server codes of the timeouts (`MaxTimeMSExpired`, `WriteConcernFailed` i.e. `wtimeout`).
+const TIMEOUT_CODES: [i32; 2] = [50, 64];
+
+/// Converts a `mongodb::error::Error` according to its kind and server code.
+///
+/// | Cause | Kind |
+/// |---|---|
+/// | Duplicate key | `409 Conflict` |
+/// | Network timeout, operation or write concern timeout | `504 Gateway Timeout` |
+/// | Write concern error | `503 Service Unavailable` |
+/// | I/O failure, DNS resolution, server selection, cleared connection pool | `503 Service Unavailable` |
+/// | Other | `500 Internal Server Error` |
+///
+/// The code returned by the server (if any) is stored in the details under the `mongodb_code` key,
+/// and the labels of the error (e.g. `TransientTransactionError`) under the `mongodb_labels` key.
+///
+/// # Example
+/// ```
+/// use std::collections::BTreeMap;
+/// use cdumay_core::ErrorConverter;
+/// use cdumay_core::converters::MongodbErrorConverter;
+///
+/// let error = mongodb::error::Error::from(std::io::Error::from(std::io::ErrorKind::TimedOut));
+/// let err = MongodbErrorConverter::convert_error(&error, None, BTreeMap::new());
+/// assert_eq!(err.code(), 504);
+/// assert_eq!(err.class(), "Server::GatewayTimeout::MongodbError");
+/// ```
+pub struct MongodbErrorConverter;
+
+/// Returns the code returned by the server, the first write error being used for batch writes.
+fn server_code(kind: &ErrorKind) -> Option<i32> {
+    match kind {
+        ErrorKind::Command(error) => Some(error.code),
+        ErrorKind::Write(WriteFailure::WriteError(error)) => Some(error.code),
+        ErrorKind::Write(WriteFailure::WriteConcernError(error)) => Some(error.code),
+        ErrorKind::InsertMany(error) => error
+            .write_errors
+            .as_ref()
+            .and_then(|errors| errors.first())
+            .map(|error| error.code)
+            .or_else(|| error.write_concern_error.as_ref().map(|error| error.code)),
+        ErrorKind::BulkWrite(error) => error
+            .write_errors
+            .iter()
+            .min_by_key(|(index, _)| **index)
+            .map(|(_, error)| error.code)
+            .or_else(|| error.write_concern_errors.first().map(|error| error.code)),
+        _ => None,
+    }
+}
+
+impl crate::ErrorConverter for MongodbErrorConverter {
+    type Error = ::mongodb::error::Error;
+
+    fn convert(error: &Self::Error, text: String, mut context: BTreeMap<String, serde_value::Value>) -> crate::Error {
+        let code = server_code(&error.kind);
+        let kind = match (error.kind.as_ref(), code) {
+            (_, Some(code)) if DUPLICATE_KEY_CODES.contains(&code) => Conflict,
+            (_, Some(code)) if TIMEOUT_CODES.contains(&code) => GatewayTimeout,
+            (ErrorKind::Io(io_error), _) if io_error.kind() == std::io::ErrorKind::TimedOut => GatewayTimeout,
+            (ErrorKind::Write(WriteFailure::WriteConcernError(_)), _)
+            | (ErrorKind::Io(_), _)
+            | (ErrorKind::DnsResolve { .. }, _)
+            | (ErrorKind::ServerSelection { .. }, _)
+            | (ErrorKind::ConnectionPoolCleared { .. }, _) => ServiceUnavailable,
+            _ => InternalServerError,
+        };
+        if let Some(code) = code {
+            context.insert("mongodb_code".to_string(), serde_value::Value::I32(code));
+        }
+        if !error.labels().is_empty() {
+            let mut labels: Vec<_> = error.labels().iter().cloned().collect();
+            labels.sort();
+            context.insert(
+                "mongodb_labels".to_string(),
+                serde_value::Value::Seq(labels.into_iter().map(serde_value::Value::String).collect()),
+            );
+        }
+        super::build_error(kind, "MongodbError", error, text, context)
+    }
+}
@@ -0,0 +1,56 @@
+//! Converter for the errors of `redis`.
+use crate::kinds::http::{GatewayTimeout, InternalServerError, ServiceUnavailable};
+use ::redis::{ErrorKind, ServerErrorKind};
+use std::collections::BTreeMap;
+
+/// Converts a `redis::RedisError` according to its cause.
+///
+/// | Cause | Kind |
+/// |---|---|
+/// | Timeout | `504 Gateway Timeout` |
+/// | Connection refused or dropped, other I/O failure | `503 Service Unavailable` |
+/// | Server loading, read-only, cluster or master down | `503 Service Unavailable` |
+/// | Type error, response error and other | `500 Internal Server Error` |
+///
+/// The category of the error is stored in the details under the `redis_category` key, and the
+/// error code returned by the server (if any) under the `redis_code` key.
+///
+/// # Example
+/// ```
+/// use std::collections::BTreeMap;
+/// use cdumay_core::ErrorConverter;
+/// use cdumay_core::converters::RedisErrorConverter;
+///
+/// let error = redis::RedisError::from(std::io::Error::from(std::io::ErrorKind::ConnectionRefused));
+/// let err = RedisErrorConverter::convert_error(&error, None, BTreeMap::new());
+/// assert_eq!(err.code(), 503);
+/// assert_eq!(err.class(), "Server::ServiceUnavailable::RedisError");
+/// ```
+pub struct RedisErrorConverter;
+
+impl crate::ErrorConverter for RedisErrorConverter {
+    type Error = ::redis::RedisError;
+
+    fn convert(error: &Self::Error, text: String, mut context: BTreeMap<String, serde_value::Value>) -> crate::Error {
+        let kind = if error.is_timeout() {
+            GatewayTimeout
+        } else if error.is_connection_refusal() || error.is_connection_dropped() || error.is_io_error() {
+            ServiceUnavailable
+        } else {
+            match error.kind() {
+                ErrorKind::ClusterConnectionNotFound
+                | ErrorKind::Server(ServerErrorKind::BusyLoading)
+                | ErrorKind::Server(ServerErrorKind::ReadOnly)
+                | ErrorKind::Server(ServerErrorKind::TryAgain)
+                | ErrorKind::Server(ServerErrorKind::ClusterDown)
+                | ErrorKind::Server(ServerErrorKind::MasterDown) => ServiceUnavailable,
+                _ => InternalServerError,
+            }
+        };
+        context.insert("redis_category".to_string(), serde_value::Value::String(error.category().to_string()));
+        if let Some(code) = error.code() {
+            context.insert("redis_code".to_string(), serde_value::Value::String(code.to_string()));
+        }
+        super::build_error(kind, "RedisError", error, text, context)
+    }
+}
@@ -0,0 +1,54 @@
+//! Converter for the errors of `reqwest`.
+use crate::kinds::http::{BadGateway, GatewayTimeout, InternalServerError};
+use std::collections::BTreeMap;
+
+/// Converts a `reqwest::Error` according to its cause.
+///
+/// | Cause | Kind |
+/// |---|---|
+/// | Timeout | `504 Gateway Timeout` |
+/// | Connection failure | `502 Bad Gateway` |
+/// | Error status | Kind of the same code (see [`kind_from_code`](crate::kinds::http::kind_from_code)) |
+/// | Invalid response body | `502 Bad Gateway` |
+/// | Other | `500 Internal Server Error` |
+///
+/// The requested URL is stored in the details under the `url` key, and the response status
+/// (if any) under the `status` key.
+///
+/// # Example
+/// ```
+/// use std::collections::BTreeMap;
+/// use cdumay_core::ErrorConverter;
+/// use cdumay_core::converters::ReqwestErrorConverter;
+///
+/// let error = reqwest::Client::new().get("not a url").build().unwrap_err();
+/// let err = ReqwestErrorConverter::convert_error(&error, None, BTreeMap::new());
+/// assert_eq!(err.code(), 500);
+/// assert_eq!(err.class(), "Server::InternalServerError::ReqwestError");
+/// ```
+pub struct ReqwestErrorConverter;
+
+impl crate::ErrorConverter for ReqwestErrorConverter {
+    type Error = ::reqwest::Error;
+
+    fn convert(error: &Self::Error, text: String, mut context: BTreeMap<String, serde_value::Value>) -> crate::Error {
+        let kind = if error.is_timeout() {
+            GatewayTimeout
+        } else if error.is_connect() {
+            BadGateway
+        } else if let Some(status) = error.status() {
+            crate::kinds::http::kind_from_code(status.as_u16())
+        } else if error.is_decode() || error.is_body() {
+            BadGateway
+        } else {
+            InternalServerError
+        };
+        if let Some(url) = error.url() {
+            context.insert("url".to_string(), serde_value::Value::String(url.to_string()));
+        }
+        if let Some(status) = error.status() {
+            context.insert("status".to_string(), serde_value::Value::U16(status.as_u16()));
+        }
+        super::build_error(kind, "ReqwestError", error, text, context)
+    }
+}
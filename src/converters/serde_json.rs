@@ -0,0 +1,43 @@
+//! Converter for the errors of `serde_json`.
+use crate::kinds::http::{BadRequest, InternalServerError, UnprocessableEntity};
+use std::collections::BTreeMap;
+
+/// Converts a `serde_json::Error` according to its category.
+///
+/// | Category | Kind | Name |
+/// |---|---|---|
+/// | `Syntax` | `400 Bad Request` | `JsonSyntaxError` |
+/// | `Eof` | `400 Bad Request` | `JsonEofError` |
+/// | `Data` | `422 Unprocessable Entity` | `JsonDataError` |
+/// | `Io` | `500 Internal Server Error` | `JsonIoError` |
+///
+/// The position of the error is stored in the details under the `line` and `column` keys.
+///
+/// # Example
+/// ```
+/// use std::collections::BTreeMap;
+/// use cdumay_core::ErrorConverter;
+/// use cdumay_core::converters::SerdeJsonErrorConverter;
+///
+/// let error = serde_json::from_str::<u32>("\"foo\"").unwrap_err();
+/// let err = SerdeJsonErrorConverter::convert_error(&error, None, BTreeMap::new());
+/// assert_eq!(err.code(), 422);
+/// assert_eq!(err.class(), "Client::UnprocessableEntity::JsonDataError");
+/// ```
+pub struct SerdeJsonErrorConverter;
+
+impl crate::ErrorConverter for SerdeJsonErrorConverter {
+    type Error = ::serde_json::Error;
+
+    fn convert(error: &Self::Error, text: String, mut context: BTreeMap<String, serde_value::Value>) -> crate::Error {
+        let (kind, name) = match error.classify() {
+            ::serde_json::error::Category::Syntax => (BadRequest, "JsonSyntaxError"),
+            ::serde_json::error::Category::Eof => (BadRequest, "JsonEofError"),
+            ::serde_json::error::Category::Data => (UnprocessableEntity, "JsonDataError"),
+            ::serde_json::error::Category::Io => (InternalServerError, "JsonIoError"),
+        };
+        context.insert("line".to_string(), serde_value::Value::U64(error.line() as u64));
+        context.insert("column".to_string(), serde_value::Value::U64(error.column() as u64));
+        super::build_error(kind, name, error, text, context)
+    }
+}
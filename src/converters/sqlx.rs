@@ -0,0 +1,59 @@
+//! Converter for the errors of `sqlx`.
+use crate::kinds::http::{Conflict, InternalServerError, NotFound, ServiceUnavailable, UnprocessableEntity};
+use std::collections::BTreeMap;
+
+/// Converts a `sqlx::Error` according to its variant.
+///
+/// | Variant | Kind |
+/// |---|---|
+/// | `RowNotFound` | `404 Not Found` |
+/// | `Database` (unique or foreign key violation) | `409 Conflict` |
+/// | `Database` (not null or check violation) | `422 Unprocessable Entity` |
+/// | `Io`, `Tls`, `PoolTimedOut`, `PoolClosed`, `WorkerCrashed` | `503 Service Unavailable` |
+/// | Other | `500 Internal Server Error` |
+///
+/// For database errors, the database error code, constraint and table (if any) are stored in the
+/// details under the `db_code`, `constraint` and `table` keys.
+///
+/// # Example
+/// ```
+/// use std::collections::BTreeMap;
+/// use cdumay_core::ErrorConverter;
+/// use cdumay_core::converters::SqlxErrorConverter;
+///
+/// let err = SqlxErrorConverter::convert_error(&sqlx::Error::RowNotFound, None, BTreeMap::new());
+/// assert_eq!(err.code(), 404);
+/// assert_eq!(err.class(), "Client::NotFound::SqlxError");
+/// ```
+pub struct SqlxErrorConverter;
+
+impl crate::ErrorConverter for SqlxErrorConverter {
+    type Error = ::sqlx::Error;
+
+    fn convert(error: &Self::Error, text: String, mut context: BTreeMap<String, serde_value::Value>) -> crate::Error {
+        let kind = match error {
+            ::sqlx::Error::RowNotFound => NotFound,
+            ::sqlx::Error::Database(db_error) => {
+                if let Some(code) = db_error.code() {
+                    context.insert("db_code".to_string(), serde_value::Value::String(code.to_string()));
+                }
+                if let Some(constraint) = db_error.constraint() {
+                    context.insert("constraint".to_string(), serde_value::Value::String(constraint.to_string()));
+                }
+                if let Some(table) = db_error.table() {
+                    context.insert("table".to_string(), serde_value::Value::String(table.to_string()));
+                }
+                match db_error.kind() {
+                    ::sqlx::error::ErrorKind::UniqueViolation | ::sqlx::error::ErrorKind::ForeignKeyViolation => Conflict,
+                    ::sqlx::error::ErrorKind::NotNullViolation | ::sqlx::error::ErrorKind::CheckViolation => UnprocessableEntity,
+                    _ => InternalServerError,
+                }
+            }
+            ::sqlx::Error::Io(_) | ::sqlx::Error::Tls(_) | ::sqlx::Error::PoolTimedOut | ::sqlx::Error::PoolClosed | ::sqlx::Error::WorkerCrashed => {
+                ServiceUnavailable
+            }
+            _ => InternalServerError,
+        };
+        super::build_error(kind, "SqlxError", error, text, context)
+    }
+}
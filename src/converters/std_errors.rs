@@ -0,0 +1,149 @@
+//! Converters for the errors of the standard library.
+use crate::kinds::http::{BadRequest, InternalServerError};
+use std::collections::BTreeMap;
+
+/// Converts a `std::io::Error` according to its `std::io::ErrorKind`.
+///
+/// The error is raised from its [POSIX kind](crate::kinds::posix::kind_from_io_error), so that
+/// it gets the same class and code as when converted using `From<std::io::Error>`.
+///
+/// The I/O error kind is stored in the details under the `io_kind` key, and the OS error
+/// code (if any) under the `errno` key.
+///
+/// # Example
+/// ```
+/// use std::collections::BTreeMap;
+/// use cdumay_core::ErrorConverter;
+/// use cdumay_core::converters::IoErrorConverter;
+///
+/// let error = std::io::Error::new(std::io::ErrorKind::NotFound, "config.toml");
+/// let err = IoErrorConverter::convert_error(&error, None, BTreeMap::new());
+/// assert_eq!(err.code(), 404);
+/// assert_eq!(err.class(), "Client::ENOENT::IoError");
+/// ```
+pub struct IoErrorConverter;
+
+impl crate::ErrorConverter for IoErrorConverter {
+    type Error = std::io::Error;
+
+    fn convert(error: &Self::Error, text: String, mut context: BTreeMap<String, serde_value::Value>) -> crate::Error {
+        let kind = crate::kinds::posix::kind_from_io_error(error);
+        context.insert("io_kind".to_string(), serde_value::Value::String(error.kind().to_string()));
+        if let Some(errno) = error.raw_os_error() {
+            context.insert("errno".to_string(), serde_value::Value::I32(errno));
+        }
+        super::build_error(kind, "IoError", error, text, context)
+    }
+}
+
+/// Converts a `std::num::ParseIntError` into a `400 Bad Request` error.
+pub struct ParseIntErrorConverter;
+
+impl crate::ErrorConverter for ParseIntErrorConverter {
+    type Error = std::num::ParseIntError;
+
+    fn convert(error: &Self::Error, text: String, context: BTreeMap<String, serde_value::Value>) -> crate::Error {
+        super::build_error(BadRequest, "ParseIntError", error, text, context)
+    }
+}
+
+/// Converts a `std::num::ParseFloatError` into a `400 Bad Request` error.
+pub struct ParseFloatErrorConverter;
+
+impl crate::ErrorConverter for ParseFloatErrorConverter {
+    type Error = std::num::ParseFloatError;
+
+    fn convert(error: &Self::Error, text: String, context: BTreeMap<String, serde_value::Value>) -> crate::Error {
+        super::build_error(BadRequest, "ParseFloatError", error, text, context)
+    }
+}
+
+/// Converts a `std::str::ParseBoolError` into a `400 Bad Request` error.
+pub struct ParseBoolErrorConverter;
+
+impl crate::ErrorConverter for ParseBoolErrorConverter {
+    type Error = std::str::ParseBoolError;
+
+    fn convert(error: &Self::Error, text: String, context: BTreeMap<String, serde_value::Value>) -> crate::Error {
+        super::build_error(BadRequest, "ParseBoolError", error, text, context)
+    }
+}
+
+/// Converts a `std::str::Utf8Error` into a `400 Bad Request` error.
+///
+/// The index of the first invalid byte is stored in the details under the `valid_up_to` key.
+pub struct Utf8ErrorConverter;
+
+impl crate::ErrorConverter for Utf8ErrorConverter {
+    type Error = std::str::Utf8Error;
+
+    fn convert(error: &Self::Error, text: String, mut context: BTreeMap<String, serde_value::Value>) -> crate::Error {
+        context.insert("valid_up_to".to_string(), serde_value::Value::U64(error.valid_up_to() as u64));
+        super::build_error(BadRequest, "Utf8Error", error, text, context)
+    }
+}
+
+/// Converts a `std::string::FromUtf8Error` into a `400 Bad Request` error.
+///
+/// The index of the first invalid byte is stored in the details under the `valid_up_to` key.
+pub struct FromUtf8ErrorConverter;
+
+impl crate::ErrorConverter for FromUtf8ErrorConverter {
+    type Error = std::string::FromUtf8Error;
+
+    fn convert(error: &Self::Error, text: String, mut context: BTreeMap<String, serde_value::Value>) -> crate::Error {
+        context.insert("valid_up_to".to_string(), serde_value::Value::U64(error.utf8_error().valid_up_to() as u64));
+        super::build_error(BadRequest, "FromUtf8Error", error, text, context)
+    }
+}
+
+/// Converts a `std::env::VarError` into a `500 Internal Server Error`.
+///
+/// A missing or invalid environment variable is a configuration issue of the server.
+pub struct VarErrorConverter;
+
+impl crate::ErrorConverter for VarErrorConverter {
+    type Error = std::env::VarError;
+
+    fn convert(error: &Self::Error, text: String, context: BTreeMap<String, serde_value::Value>) -> crate::Error {
+        let name = match error {
+            std::env::VarError::NotPresent => "MissingEnvironmentVariable",
+            std::env::VarError::NotUnicode(_) => "InvalidEnvironmentVariable",
+        };
+        super::build_error(InternalServerError, name, error, text, context)
+    }
+}
+
+/// Converts a `std::net::AddrParseError` into a `400 Bad Request` error.
+///
+/// As the error does not hold the parsed string, use [`AddrParseErrorConverter::parse`] to store
+/// it in the details under the `input` key.
+pub struct AddrParseErrorConverter;
+
+impl AddrParseErrorConverter {
+    /// Parses an IP or socket address, converting the failure with the raw input in the details.
+    ///
+    /// # Example
+    /// ```
+    /// use std::net::SocketAddr;
+    /// use cdumay_core::converters::AddrParseErrorConverter;
+    ///
+    /// let err = AddrParseErrorConverter::parse::<SocketAddr>("localhost:80").unwrap_err();
+    /// assert_eq!(err.code(), 400);
+    /// assert_eq!(err.class(), "Client::BadRequest::AddrParseError");
+    /// assert_eq!(err.details()["input"], serde_value::Value::String("localhost:80".to_string()));
+    /// ```
+    pub fn parse<T: std::str::FromStr<Err = std::net::AddrParseError>>(input: &str) -> crate::Result<T> {
+        let mut context = BTreeMap::new();
+        context.insert("input".to_string(), serde_value::Value::String(input.to_string()));
+        <Self as crate::ErrorConverter>::convert_result(input.parse(), None, context)
+    }
+}
+
+impl crate::ErrorConverter for AddrParseErrorConverter {
+    type Error = std::net::AddrParseError;
+
+    fn convert(error: &Self::Error, text: String, context: BTreeMap<String, serde_value::Value>) -> crate::Error {
+        super::build_error(BadRequest, "AddrParseError", error, text, context)
+    }
+}
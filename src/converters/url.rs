@@ -0,0 +1,47 @@
+//! Converter for the errors of `url`.
+use crate::kinds::http::BadRequest;
+use std::collections::BTreeMap;
+
+/// Converts a `url::ParseError` into a `400 Bad Request` error.
+///
+/// As the error does not hold the parsed string, use [`UrlParseErrorConverter::parse`] to store
+/// it in the details under the `input` key.
+///
+/// # Example
+/// ```
+/// use std::collections::BTreeMap;
+/// use cdumay_core::ErrorConverter;
+/// use cdumay_core::converters::UrlParseErrorConverter;
+///
+/// let err = UrlParseErrorConverter::convert_error(&url::ParseError::EmptyHost, None, BTreeMap::new());
+/// assert_eq!(err.code(), 400);
+/// assert_eq!(err.class(), "Client::BadRequest::UrlParseError");
+/// assert_eq!(err.message(), "empty host");
+/// ```
+pub struct UrlParseErrorConverter;
+
+impl UrlParseErrorConverter {
+    /// Parses an absolute URL, converting the failure with the raw input in the details.
+    ///
+    /// # Example
+    /// ```
+    /// use cdumay_core::converters::UrlParseErrorConverter;
+    ///
+    /// let err = UrlParseErrorConverter::parse("example.com/callback").unwrap_err();
+    /// assert_eq!(err.message(), "relative URL without a base");
+    /// assert_eq!(err.details()["input"], serde_value::Value::String("example.com/callback".to_string()));
+    /// ```
+    pub fn parse(input: &str) -> crate::Result<::url::Url> {
+        let mut context = BTreeMap::new();
+        context.insert("input".to_string(), serde_value::Value::String(input.to_string()));
+        <Self as crate::ErrorConverter>::convert_result(::url::Url::parse(input), None, context)
+    }
+}
+
+impl crate::ErrorConverter for UrlParseErrorConverter {
+    type Error = ::url::ParseError;
+
+    fn convert(error: &Self::Error, text: String, context: BTreeMap<String, serde_value::Value>) -> crate::Error {
+        super::build_error(BadRequest, "UrlParseError", error, text, context)
+    }
+}
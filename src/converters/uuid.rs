@@ -0,0 +1,40 @@
+//! Converter for the errors of `uuid`.
+use std::collections::BTreeMap;
+
+crate::define_kinds! {
+    InvalidIdentifier = (400, "Invalid identifier")
+}
+
+/// Converts a `uuid::Error` into a `400 Invalid identifier` error.
+///
+/// As the error does not hold the parsed string, use [`UuidErrorConverter::parse`] to store it in
+/// the details under the `input` key, e.g. to validate the identifiers of a path.
+///
+/// # Example
+/// ```
+/// use cdumay_core::converters::UuidErrorConverter;
+///
+/// let err = UuidErrorConverter::parse("user-42").unwrap_err();
+/// assert_eq!(err.code(), 400);
+/// assert_eq!(err.class(), "Client::InvalidIdentifier::UuidError");
+/// assert_eq!(err.details()["input"], serde_value::Value::String("user-42".to_string()));
+/// ```
+pub struct UuidErrorConverter;
+
+impl UuidErrorConverter {
+    /// Parses a UUID in any of the formats supported by `Uuid::parse_str`, converting the failure
+    /// with the raw input in the details.
+    pub fn parse(input: &str) -> crate::Result<::uuid::Uuid> {
+        let mut context = BTreeMap::new();
+        context.insert("input".to_string(), serde_value::Value::String(input.to_string()));
+        <Self as crate::ErrorConverter>::convert_result(::uuid::Uuid::parse_str(input), None, context)
+    }
+}
+
+impl crate::ErrorConverter for UuidErrorConverter {
+    type Error = ::uuid::Error;
+
+    fn convert(error: &Self::Error, text: String, context: BTreeMap<String, serde_value::Value>) -> crate::Error {
+        super::build_error(InvalidIdentifier, "UuidError", error, text, context)
+    }
+}
@@ -0,0 +1,217 @@
+use actix_web::HttpMessage;
+use actix_web::body::{BoxBody, MessageBody};
+use actix_web::dev::{ServiceRequest, ServiceResponse};
+use actix_web::middleware::Next;
+
+/// Identifier of the current request, read from the request extensions by [`attach_request_id`].
+///
+/// It is meant to be inserted by the middleware generating or propagating request identifiers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestId(pub String);
+
+/// Middleware function setting the [`RequestId`] of the request on the `Error` it returned.
+///
+/// It must be used with `actix_web::middleware::from_fn`, and wrapped by the middleware which
+/// inserts the [`RequestId`] in the request extensions.
+///
+/// # Example
+/// ```
+/// use actix_web::middleware::from_fn;
+/// use actix_web::{App, HttpMessage, web};
+/// use cdumay_core::{ErrorBuilder, RequestId, Result, attach_request_id};
+///
+/// async fn handler() -> Result<String> {
+///     Err(ErrorBuilder::default().build())
+/// }
+///
+/// let app = App::new()
+///     .wrap(from_fn(attach_request_id))
+///     .wrap_fn(|req, srv| {
+///         req.extensions_mut().insert(RequestId("req-42".to_string()));
+///         actix_web::dev::Service::call(srv, req)
+///     })
+///     .route("/", web::get().to(handler));
+/// ```
+pub async fn attach_request_id(
+    req: ServiceRequest,
+    next: Next<impl MessageBody + 'static>,
+) -> std::result::Result<ServiceResponse<BoxBody>, actix_web::Error> {
+    let response = next.call(req).await?;
+    let request_id = response.request().extensions().get::<RequestId>().map(|id| id.0.clone());
+    let error = response.response().error().and_then(|error| error.as_error::<crate::Error>()).cloned();
+    match (request_id, error) {
+        (Some(request_id), Some(error)) => {
+            let format = response.request().extensions().get::<crate::ErrorFormat>().copied().unwrap_or_default();
+            let http_response = render(error.with_request_id(request_id), format);
+            Ok(response.into_response(http_response))
+        }
+        _ => Ok(response.map_into_boxed_body()),
+    }
+}
+
+/// Middleware function rendering the `Error` returned by the wrapped service according to the
+/// `Accept` header of the request, see [`ErrorFormat`](crate::ErrorFormat).
+///
+/// The negotiated format is also inserted in the request extensions, so that [`attach_request_id`]
+/// keeps it when it is wrapped by this middleware. It must be used with `actix_web::middleware::from_fn`.
+///
+/// # Example
+/// ```
+/// use actix_web::middleware::from_fn;
+/// use actix_web::{App, web};
+/// use cdumay_core::{ErrorBuilder, Result, negotiate_error_format};
+///
+/// async fn handler() -> Result<String> {
+///     Err(ErrorBuilder::default().build())
+/// }
+///
+/// let app = App::new().wrap(from_fn(negotiate_error_format)).route("/", web::get().to(handler));
+/// ```
+pub async fn negotiate_error_format(
+    req: ServiceRequest,
+    next: Next<impl MessageBody + 'static>,
+) -> std::result::Result<ServiceResponse<BoxBody>, actix_web::Error> {
+    let format = req
+        .headers()
+        .get(actix_web::http::header::ACCEPT)
+        .and_then(|accept| accept.to_str().ok())
+        .map(crate::ErrorFormat::from_accept)
+        .unwrap_or_default();
+    req.extensions_mut().insert(format);
+    let response = next.call(req).await?;
+    match response.response().error().and_then(|error| error.as_error::<crate::Error>()).cloned() {
+        Some(error) if format != crate::ErrorFormat::Json => Ok(response.into_response(render(error, format))),
+        _ => Ok(response.map_into_boxed_body()),
+    }
+}
+
+/// Renders the error in the given format, keeping it attached to the response for outer middlewares.
+///
+/// The error is finalized once, `HttpResponse::from_error` leaving it unchanged.
+fn render(error: crate::Error, format: crate::ErrorFormat) -> actix_web::HttpResponse {
+    let error = error.finalize();
+    let exposed = match format {
+        crate::ErrorFormat::Json => None,
+        _ => Some(crate::ErrorResponseConfig::global().apply(&error)),
+    };
+    let body = exposed.and_then(|exposed| match format {
+        crate::ErrorFormat::Json => None,
+        crate::ErrorFormat::ProblemJson => serde_json::to_string(&crate::ProblemDetails::from(&exposed)).ok(),
+        crate::ErrorFormat::Text => Some(exposed.to_string()),
+        #[cfg(feature = "xml")]
+        crate::ErrorFormat::Xml => quick_xml::se::to_string_with_root("error", &exposed).ok(),
+    });
+    let response = actix_web::HttpResponse::from_error(error);
+    match body {
+        Some(body) => response.map_body(|head, _| {
+            head.headers_mut().insert(
+                actix_web::http::header::CONTENT_TYPE,
+                actix_web::http::header::HeaderValue::from_static(format.content_type()),
+            );
+            BoxBody::new(body)
+        }),
+        None => response,
+    }
+}
+
+/// Middleware function converting a panic of the wrapped service into a `500 Internal Server Error`.
+///
+/// The panic message and location are stored in the details, see [`catch_panic`](crate::catch_panic).
+/// The `Error` is returned as the service error, which actix renders as the response.
+/// It must be used with `actix_web::middleware::from_fn`.
+///
+/// # Example
+/// ```
+/// use actix_web::middleware::from_fn;
+/// use actix_web::{App, web};
+/// use cdumay_core::catch_panic_middleware;
+///
+/// async fn handler() -> String {
+///     panic!("boom")
+/// }
+///
+/// let app = App::new().wrap(from_fn(catch_panic_middleware)).route("/", web::get().to(handler));
+/// ```
+pub async fn catch_panic_middleware(
+    req: ServiceRequest,
+    next: Next<impl MessageBody + 'static>,
+) -> std::result::Result<ServiceResponse<BoxBody>, actix_web::Error> {
+    Ok(super::panic::CatchPanic(Box::pin(next.call(req))).await??.map_into_boxed_body())
+}
+
+/// Converts an extractor failure into an `Error`, using the display of the failure as message.
+fn extractor_error(
+    kind: crate::ErrorKind,
+    name: &str,
+    error: &dyn std::fmt::Display,
+    details: std::collections::BTreeMap<String, serde_value::Value>,
+) -> actix_web::Error {
+    crate::ErrorBuilder::new(kind, name)
+        .with_message(error.to_string())
+        .with_details(details)
+        .build()
+        .into()
+}
+
+/// Returns a `JsonConfig` whose error handler responds to invalid JSON payloads with an `Error`.
+///
+/// The error is a `415 Unsupported Media Type` for an invalid content type, a `422 Unprocessable
+/// Entity` for valid JSON not matching the expected type, and of the status code of actix
+/// otherwise (e.g. `413` for a payload too large, `400` for invalid JSON). For deserialization
+/// failures, the position of the error is stored in the details under the `line` and `column` keys.
+///
+/// The returned configuration can be customized further, e.g. using `limit`.
+///
+/// # Example
+/// ```
+/// use actix_web::{App, web};
+/// use cdumay_core::{json_config, path_config, query_config};
+///
+/// #[derive(serde::Deserialize)]
+/// struct User {
+///     name: String,
+/// }
+///
+/// async fn create_user(user: web::Json<User>) -> String {
+///     user.name.clone()
+/// }
+///
+/// let app = App::new()
+///     .app_data(json_config().limit(4096))
+///     .app_data(path_config())
+///     .app_data(query_config())
+///     .route("/users", web::post().to(create_user));
+/// ```
+pub fn json_config() -> actix_web::web::JsonConfig {
+    actix_web::web::JsonConfig::default().error_handler(|error, _| {
+        let mut details = std::collections::BTreeMap::new();
+        let kind = match &error {
+            actix_web::error::JsonPayloadError::ContentType => crate::kinds::http::UnsupportedMediaType,
+            actix_web::error::JsonPayloadError::Deserialize(error) => {
+                details.insert("line".to_string(), serde_value::Value::U64(error.line() as u64));
+                details.insert("column".to_string(), serde_value::Value::U64(error.column() as u64));
+                if error.is_data() {
+                    crate::kinds::http::UnprocessableEntity
+                } else {
+                    crate::kinds::http::BadRequest
+                }
+            }
+            error => crate::kinds::http::kind_from_code(actix_web::ResponseError::status_code(error).as_u16()),
+        };
+        extractor_error(kind, "InvalidJsonPayload", &error, details)
+    })
+}
+
+/// Returns a `PathConfig` whose error handler responds to invalid path parameters with a
+/// `404 Not Found` `Error`, as actix does by default, see [`json_config`].
+pub fn path_config() -> actix_web::web::PathConfig {
+    actix_web::web::PathConfig::default()
+        .error_handler(|error, _| extractor_error(crate::kinds::http::NotFound, "InvalidPath", &error, Default::default()))
+}
+
+/// Returns a `QueryConfig` whose error handler responds to invalid query strings with a
+/// `400 Bad Request` `Error`, see [`json_config`].
+pub fn query_config() -> actix_web::web::QueryConfig {
+    actix_web::web::QueryConfig::default()
+        .error_handler(|error, _| extractor_error(crate::kinds::http::BadRequest, "InvalidQuery", &error, Default::default()))
+}
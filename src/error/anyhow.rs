@@ -0,0 +1,50 @@
+/// Converts an `anyhow::Error` into an `Error`.
+///
+/// If the `anyhow::Error` wraps an `Error` (see `From<Error> for anyhow::Error`), the
+/// original error is returned unchanged. Otherwise, a `500 Internal Server Error` is built
+/// with the top-level message, and the whole chain of causes is stored in the details
+/// under the `origin_chain` key.
+///
+/// # Example
+/// ```
+/// use cdumay_core::Error;
+///
+/// let report = anyhow::anyhow!("connection reset").context("while loading user profile");
+/// let err: Error = report.into();
+/// assert_eq!(err.code(), 500);
+/// assert_eq!(err.message(), "while loading user profile");
+/// ```
+impl From<::anyhow::Error> for crate::Error {
+    fn from(error: ::anyhow::Error) -> Self {
+        match error.downcast::<crate::Error>() {
+            Ok(error) => error,
+            Err(error) => {
+                let chain = error.chain().map(|cause| serde_value::Value::String(cause.to_string())).collect();
+                let mut details = std::collections::BTreeMap::new();
+                details.insert("origin_chain".to_string(), serde_value::Value::Seq(chain));
+                crate::ErrorBuilder::new(crate::kinds::http::InternalServerError, "AnyhowError")
+                    .with_message(error.to_string())
+                    .with_details(details)
+                    .build()
+            }
+        }
+    }
+}
+
+/// Converts an `Error` into an `anyhow::Error`.
+///
+/// The original `Error` can be recovered using `anyhow::Error::downcast`.
+///
+/// # Example
+/// ```
+/// use cdumay_core::{Error, ErrorBuilder};
+///
+/// let report: anyhow::Error = ErrorBuilder::default().build().into();
+/// let err: Error = report.into();
+/// assert_eq!(err.class(), "Server::InternalServerError::UnknownError");
+/// ```
+impl From<crate::Error> for ::anyhow::Error {
+    fn from(error: crate::Error) -> Self {
+        ::anyhow::Error::msg(error)
+    }
+}
@@ -0,0 +1,81 @@
+use ::proptest::prelude::*;
+use serde_value::Value;
+
+/// Generates one of the [standard HTTP kinds](crate::kinds::http).
+impl Arbitrary for crate::ErrorKind {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        proptest::sample::select(&crate::kinds::http::KINDS[..]).boxed()
+    }
+}
+
+/// Generates a detail value which is kept unchanged by a JSON round-trip, so that serialization
+/// layers can be checked for equality.
+fn detail_value() -> impl Strategy<Value = Value> {
+    let leaf = prop_oneof![
+        Just(Value::Unit),
+        any::<bool>().prop_map(Value::Bool),
+        any::<u64>().prop_map(Value::U64),
+        (i64::MIN..0).prop_map(Value::I64),
+        "[a-zA-Z0-9 _.-]{0,24}".prop_map(Value::String),
+    ];
+    leaf.prop_recursive(2, 8, 4, |inner| {
+        prop_oneof![
+            proptest::collection::vec(inner.clone(), 0..4).prop_map(Value::Seq),
+            proptest::collection::btree_map("[a-z_]{1,8}".prop_map(Value::String), inner, 0..4).prop_map(Value::Map),
+        ]
+    })
+}
+
+/// Generates a realistic error: a standard HTTP kind, a `PascalCase` name, the default message
+/// of the kind or a custom one, details, context frames, and optionally a request identifier,
+/// a help and a retryable flag.
+///
+/// Details are chosen to be kept unchanged by a JSON round-trip. As `Result<T>` is an alias of
+/// `std::result::Result`, it implements `Arbitrary` as well when `T` does.
+///
+/// # Example
+/// ```
+/// use cdumay_core::Error;
+/// use proptest::prelude::*;
+///
+/// proptest!(|(err in any::<Error>())| {
+///     prop_assert!(err.to_string().starts_with(&err.class()));
+/// });
+/// ```
+impl Arbitrary for crate::Error {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any::<crate::ErrorKind>(),
+            "[A-Z][a-z]{2,8}([A-Z][a-z]{2,8}){0,2}",
+            proptest::option::of("[A-Z][a-z]{2,10}( [a-z]{1,10}){0,6}"),
+            proptest::collection::btree_map("[a-z][a-z_]{0,11}", detail_value(), 0..5),
+            proptest::collection::vec("while [a-z]{3,10}( [a-z]{2,10}){0,3}", 0..3),
+            proptest::option::of("[a-f0-9]{8}-[a-f0-9]{4}-[a-f0-9]{4}-[a-f0-9]{4}-[a-f0-9]{12}"),
+            proptest::option::of("[A-Z][a-z]{2,10}( [a-z]{1,10}){0,6}"),
+            proptest::option::of(any::<bool>()),
+        )
+            .prop_map(|(kind, name, message, details, context, request_id, help, retryable)| {
+                let mut builder = crate::ErrorBuilder::new(kind, &name).with_details(details);
+                if let Some(message) = message {
+                    builder = builder.with_message(message);
+                }
+                if let Some(request_id) = request_id {
+                    builder = builder.with_request_id(request_id);
+                }
+                if let Some(help) = help {
+                    builder = builder.with_help(help);
+                }
+                if let Some(retryable) = retryable {
+                    builder = builder.with_retryable(retryable);
+                }
+                context.into_iter().fold(builder.build(), |error, frame| error.with_context(frame))
+            })
+            .boxed()
+    }
+}
@@ -0,0 +1,105 @@
+use std::collections::BTreeMap;
+
+/// Version of the layout written by the binary encodings.
+const BINARY_VERSION: u8 = 3;
+
+/// Versioned layout of an `Error` used by the binary encodings.
+///
+/// Fields must never be reordered or removed: changes require a new version.
+#[derive(serde::Serialize)]
+pub(crate) struct BinaryError<'a> {
+    version: u8,
+    code: u16,
+    class: &'a str,
+    message: &'a str,
+    details: std::borrow::Cow<'a, BTreeMap<String, serde_value::Value>>,
+    context: &'a [String],
+    kind: Option<&'a crate::KindInfo>,
+    fingerprint: Option<&'a str>,
+    request_id: Option<&'a str>,
+    /// RFC 3339 date and time, only set when the `timestamp` feature is enabled
+    timestamp: Option<String>,
+    retryable: Option<bool>,
+    circuit_signal: Option<crate::breaker::CircuitSignal>,
+    /// Added in version 2
+    help: Option<&'a str>,
+    /// Added in version 3
+    docs_url: Option<&'a str>,
+}
+
+impl<'a> From<&'a crate::Error> for BinaryError<'a> {
+    fn from(error: &'a crate::Error) -> Self {
+        #[cfg(feature = "timestamp")]
+        let timestamp = error.metadata.timestamp.map(|timestamp| timestamp.to_rfc3339_opts(chrono::SecondsFormat::AutoSi, true));
+        #[cfg(not(feature = "timestamp"))]
+        let timestamp = None;
+        Self {
+            version: BINARY_VERSION,
+            code: error.code,
+            class: &error.class,
+            message: &error.message,
+            details: error.resolved_details(),
+            context: &error.context,
+            kind: error.metadata.kind.as_ref(),
+            fingerprint: error.metadata.fingerprint.as_deref(),
+            request_id: error.metadata.request_id.as_deref(),
+            timestamp,
+            retryable: error.metadata.retryable,
+            circuit_signal: error.metadata.circuit_signal,
+            help: error.metadata.help.as_deref(),
+            docs_url: error.metadata.docs_url.as_deref(),
+        }
+    }
+}
+
+/// Owned counterpart of [`BinaryError`], used to decode it.
+#[derive(serde::Deserialize)]
+pub(crate) struct OwnedBinaryError {
+    version: u8,
+    code: u16,
+    class: String,
+    message: String,
+    details: BTreeMap<String, serde_value::Value>,
+    context: Vec<String>,
+    kind: Option<crate::KindInfo>,
+    fingerprint: Option<String>,
+    request_id: Option<String>,
+    #[cfg_attr(not(feature = "timestamp"), allow(dead_code))]
+    timestamp: Option<String>,
+    retryable: Option<bool>,
+    circuit_signal: Option<crate::breaker::CircuitSignal>,
+    /// Missing from version 1
+    #[serde(default)]
+    help: Option<String>,
+    /// Missing before version 3
+    #[serde(default)]
+    docs_url: Option<String>,
+}
+
+impl TryFrom<OwnedBinaryError> for crate::Error {
+    type Error = String;
+
+    fn try_from(decoded: OwnedBinaryError) -> Result<Self, Self::Error> {
+        if decoded.version == 0 || decoded.version > BINARY_VERSION {
+            return Err(format!("unsupported error layout version {}", decoded.version));
+        }
+        let mut error = Self::new(decoded.code, decoded.class, decoded.message, decoded.details);
+        error.context = decoded.context.into();
+        let metadata = error.metadata_mut();
+        metadata.kind = decoded.kind;
+        metadata.fingerprint = decoded.fingerprint;
+        metadata.request_id = decoded.request_id;
+        #[cfg(feature = "timestamp")]
+        {
+            metadata.timestamp = match decoded.timestamp {
+                Some(timestamp) => Some(chrono::DateTime::parse_from_rfc3339(&timestamp).map_err(|e| e.to_string())?.with_timezone(&chrono::Utc)),
+                None => None,
+            };
+        }
+        metadata.retryable = decoded.retryable;
+        metadata.circuit_signal = decoded.circuit_signal;
+        metadata.help = decoded.help;
+        metadata.docs_url = decoded.docs_url;
+        Ok(error)
+    }
+}
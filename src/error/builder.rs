@@ -26,7 +26,7 @@
 #[derive(Debug, Clone)]
 pub struct ErrorBuilder {
     /// The high-level classification of the error (e.g. NotFound, FileError).
-    kind: crate::error::ErrorKind,
+    kind: crate::error::KindInfo,
     /// Optional HTTP-like status code (e.g. 400, 500).
     code: Option<u16>,
     /// A unique, contextual name for the error (e.g. "InvalidInput").
@@ -35,13 +35,41 @@ pub struct ErrorBuilder {
     message: Option<String>,
     /// Optional structured details to include with the error.
     details: std::collections::BTreeMap<String, serde_value::Value>,
+    /// Details computed on first use.
+    lazy_details: std::collections::BTreeMap<String, crate::error::LazyDetail>,
+    /// Optional fingerprint overriding the computed one.
+    fingerprint: Option<String>,
+    /// Optional identifier of the request which raised the error.
+    request_id: Option<String>,
+    /// Optional retryability, overriding the default one.
+    retryable: Option<bool>,
+    /// Optional effect on a circuit breaker, overriding the default one.
+    circuit_signal: Option<crate::breaker::CircuitSignal>,
+    /// Optional hint telling how to fix the problem.
+    help: Option<String>,
+    /// Optional URL template of the documentation, defaulting to the one of the kind.
+    docs_url: Option<String>,
+    /// Optional creation date and time, overriding the current one.
+    #[cfg(feature = "timestamp")]
+    timestamp: Option<chrono::DateTime<chrono::Utc>>,
+    /// Optional underlying error.
+    source: Option<std::sync::Arc<dyn std::error::Error + Send + Sync>>,
+    /// Class of the error this builder was created from, kept as is by `build()`.
+    class: Option<String>,
+    /// Context frames of the error this builder was created from.
+    context: Vec<String>,
+    /// Optional location where the error was created.
+    location: Option<crate::error::CodeLocation>,
+    /// Whether the builder was created from an existing error, for which the ambient context was
+    /// already merged and the hooks already run.
+    rebuilt: bool,
 }
 
 impl ErrorBuilder {
-    /// Creates a new `ErrorBuilder` with a required `ErrorKind` and name.
+    /// Creates a new `ErrorBuilder` with a required kind and name.
     ///
     /// # Arguments
-    /// * `kind` - The kind/category of the error.
+    /// * `kind` - The kind/category of the error, either an `ErrorKind` or any type implementing [`Kind`](crate::Kind).
     /// * `name` - A short identifier for the specific error case.
     ///
     /// # Example
@@ -51,13 +79,27 @@ impl ErrorBuilder {
     /// let kind = ErrorKind("ValidationError", 400, "Invalid input");
     /// let builder = ErrorBuilder::new(kind, "MissingField");
     /// ```
-    pub fn new(kind: crate::error::ErrorKind, name: &str) -> Self {
+    pub fn new<K: crate::error::Kind>(kind: K, name: &str) -> Self {
         Self {
-            kind,
+            kind: crate::error::KindInfo::from(&kind),
             name: name.to_string(),
             code: None,
             message: None,
             details: std::collections::BTreeMap::new(),
+            lazy_details: std::collections::BTreeMap::new(),
+            fingerprint: None,
+            request_id: None,
+            retryable: None,
+            circuit_signal: None,
+            help: None,
+            docs_url: kind.docs_url().map(str::to_string),
+            #[cfg(feature = "timestamp")]
+            timestamp: None,
+            source: None,
+            class: None,
+            context: Vec::new(),
+            location: None,
+            rebuilt: false,
         }
     }
 
@@ -89,7 +131,48 @@ impl ErrorBuilder {
         self
     }
 
-    /// Adds a structured map of additional error details.
+    /// Adds a single detail, replacing any previous value for the same key.
+    ///
+    /// # Example
+    /// ```
+    /// use serde_value::Value;
+    /// use cdumay_core::{ErrorBuilder, ErrorKind};
+    ///
+    /// let kind = ErrorKind("ValidationError", 400, "Invalid input");
+    /// let error = ErrorBuilder::new(kind, "InvalidField").with_detail("field", Value::String("username".into())).build();
+    /// assert!(error.details().contains_key("field"));
+    /// ```
+    pub fn with_detail<K: Into<String>>(mut self, key: K, value: serde_value::Value) -> Self {
+        let key = key.into();
+        self.lazy_details.remove(&key);
+        self.details.insert(key, value);
+        self
+    }
+
+    /// Adds a single detail computed on first use, replacing any previous value for the same key.
+    ///
+    /// The value is computed at most once, when the error is serialized, logged, reported or its
+    /// [details](crate::Error::details) are read, so that costly details do not slow down errors
+    /// which are handled silently.
+    ///
+    /// # Example
+    /// ```
+    /// use serde_value::Value;
+    /// use cdumay_core::ErrorBuilder;
+    ///
+    /// let request = vec![0u8; 1024];
+    /// let error = ErrorBuilder::default().with_detail_lazy("request_size", move || Value::U64(request.len() as u64)).build();
+    /// assert!(error.details_ref().is_empty());
+    /// assert_eq!(error.details()["request_size"], Value::U64(1024));
+    /// ```
+    pub fn with_detail_lazy<K: Into<String>, F: FnOnce() -> serde_value::Value + Send + 'static>(mut self, key: K, compute: F) -> Self {
+        let key = key.into();
+        self.details.remove(&key);
+        self.lazy_details.insert(key, crate::error::LazyDetail::new(compute));
+        self
+    }
+
+    /// Replaces the details, including the lazy ones, by a structured map.
     ///
     /// # Example
     /// ```
@@ -104,12 +187,149 @@ impl ErrorBuilder {
     /// ```
     pub fn with_details(mut self, details: std::collections::BTreeMap<String, serde_value::Value>) -> Self {
         self.details = details;
+        self.lazy_details.clear();
+        self
+    }
+
+    /// Overrides the fingerprint used to group identical errors.
+    ///
+    /// # Example
+    /// ```
+    /// use cdumay_core::{ErrorBuilder, ErrorKind};
+    ///
+    /// let kind = ErrorKind("ServiceUnavailable", 503, "Service Unavailable");
+    /// let error = ErrorBuilder::new(kind, "DatabaseDown").with_fingerprint("database-down".to_string()).build();
+    /// assert_eq!(error.fingerprint(), "database-down");
+    /// ```
+    pub fn with_fingerprint(mut self, fingerprint: String) -> Self {
+        self.fingerprint = Some(fingerprint);
+        self
+    }
+
+    /// Sets the identifier of the request which raised the error.
+    ///
+    /// # Example
+    /// ```
+    /// use cdumay_core::{ErrorBuilder, ErrorKind};
+    ///
+    /// let kind = ErrorKind("ValidationError", 400, "Invalid input");
+    /// let error = ErrorBuilder::new(kind, "InvalidField").with_request_id("req-42".to_string()).build();
+    /// assert_eq!(error.request_id(), Some("req-42"));
+    /// ```
+    pub fn with_request_id(mut self, request_id: String) -> Self {
+        self.request_id = Some(request_id);
+        self
+    }
+
+    /// Marks whether retrying the operation which raised the error may succeed.
+    ///
+    /// See [`Error::is_retryable`](crate::Error::is_retryable).
+    pub fn with_retryable(mut self, retryable: bool) -> Self {
+        self.retryable = Some(retryable);
+        self
+    }
+
+    /// Sets the effect of the error on a circuit breaker.
+    ///
+    /// See [`Error::with_circuit_signal`](crate::Error::with_circuit_signal).
+    pub fn with_circuit_signal(mut self, signal: crate::breaker::CircuitSignal) -> Self {
+        self.circuit_signal = Some(signal);
+        self
+    }
+
+    /// Sets a hint telling how to fix the problem, see [`Error::with_help`](crate::Error::with_help).
+    pub fn with_help(mut self, help: String) -> Self {
+        self.help = Some(help);
+        self
+    }
+
+    /// Sets the URL template of the documentation of the error, overriding the one of the kind.
+    ///
+    /// The `{class}`, `{code}`, `{kind}` (kind name), `{name}` (error name) and `{side}`
+    /// placeholders are replaced when the error is built. Without template, the global one
+    /// set using [`set_docs_url_template`](crate::set_docs_url_template) is used.
+    ///
+    /// # Example
+    /// ```
+    /// use cdumay_core::ErrorBuilder;
+    /// use cdumay_core::kinds::http::NotFound;
+    ///
+    /// let err = ErrorBuilder::new(NotFound, "UnknownUser").with_docs_url("https://docs.example.com/errors/{kind}/{name}".to_string()).build();
+    /// assert_eq!(err.docs_url(), Some("https://docs.example.com/errors/NotFound/UnknownUser"));
+    /// ```
+    pub fn with_docs_url(mut self, template: String) -> Self {
+        self.docs_url = Some(template);
+        self
+    }
+
+    /// Overrides the date and time at which the error was created.
+    ///
+    /// # Example
+    /// ```
+    /// use cdumay_core::ErrorBuilder;
+    ///
+    /// let timestamp = chrono::DateTime::from_timestamp(0, 0).unwrap();
+    /// let error = ErrorBuilder::default().with_timestamp(timestamp).build();
+    /// assert_eq!(error.timestamp(), Some(timestamp));
+    /// ```
+    #[cfg(feature = "timestamp")]
+    pub fn with_timestamp(mut self, timestamp: chrono::DateTime<chrono::Utc>) -> Self {
+        self.timestamp = Some(timestamp);
+        self
+    }
+
+    /// Sets the underlying error.
+    ///
+    /// # Example
+    /// ```
+    /// use cdumay_core::ErrorBuilder;
+    ///
+    /// let error = ErrorBuilder::default().with_source(std::io::Error::other("disk full")).build();
+    /// assert!(error.source().is_some());
+    /// ```
+    pub fn with_source<E: Into<Box<dyn std::error::Error + Send + Sync>>>(self, source: E) -> Self {
+        self.with_shared_source(std::sync::Arc::from(source.into()))
+    }
+
+    /// Sets the underlying error, shared with other errors.
+    pub fn with_shared_source(mut self, source: std::sync::Arc<dyn std::error::Error + Send + Sync>) -> Self {
+        self.source = Some(source);
+        self
+    }
+
+    /// Records the location where the method is called in the details.
+    ///
+    /// The location is stored under the `location` key as `file:line:column`.
+    ///
+    /// # Example
+    /// ```
+    /// use cdumay_core::ErrorBuilder;
+    ///
+    /// let err = ErrorBuilder::default().with_location().build();
+    /// let location = format!("{}:{}:", file!(), line!() - 1);
+    /// assert!(matches!(&err.details()["location"], serde_value::Value::String(value) if value.starts_with(&location)));
+    /// ```
+    #[track_caller]
+    pub fn with_location(self) -> Self {
+        self.with_code_location(crate::error::CodeLocation::caller())
+    }
+
+    /// Records the given location in the details, see [`here!`](crate::here).
+    ///
+    /// The location is stored under the `location` key as `file:line:column`, and the module
+    /// (if known) under the `module` key.
+    pub fn with_code_location(mut self, location: crate::error::CodeLocation) -> Self {
+        self.location = Some(location);
         self
     }
 
     /// Finalizes the builder and constructs an `Error`.
     ///
     /// If no message or code is provided, it falls back to defaults from the `ErrorKind`.
+    /// The values of the [error context](crate::context) are merged into the details, and the
+    /// error is recorded, logged and reported when enabled. Both are skipped for builders created
+    /// from an existing error (see [`Error::to_builder`](crate::Error::to_builder)), as they were
+    /// already done when it was built.
     ///
     /// # Example
     /// ```
@@ -118,13 +338,58 @@ impl ErrorBuilder {
     /// let kind = ErrorKind("ValidationError", 400, "Invalid input");
     /// let error = ErrorBuilder::new(kind, "InvalidField").build();
     /// ```
-    pub fn build(self) -> crate::error::Error {
-        crate::error::Error::new(
-            self.code.unwrap_or(self.kind.code()),
-            format!("{}::{}::{}", self.kind.side(), self.kind.name(), self.name),
-            self.message.unwrap_or(self.kind.description().to_string()),
+    pub fn build(mut self) -> crate::error::Error {
+        if let Some(location) = self.location {
+            self = self.with_detail("location", serde_value::Value::String(location.to_string()));
+            if let Some(module) = location.module {
+                self = self.with_detail("module", serde_value::Value::String(module.to_string()));
+            }
+        }
+        if !self.rebuilt {
+            for (key, value) in crate::context::current() {
+                if !self.lazy_details.contains_key(&key) {
+                    self.details.entry(key).or_insert(value);
+                }
+            }
+        }
+        let mut error = crate::error::Error::new(
+            self.code.unwrap_or(self.kind.code),
+            self.class.unwrap_or_else(|| format!("{}::{}::{}", self.kind.side, self.kind.name, self.name)),
+            self.message.unwrap_or_else(|| self.kind.description.clone()),
             self.details,
-        )
+        );
+        error.context = self.context.into();
+        let docs_url = self
+            .docs_url
+            .or_else(crate::error::docs::docs_url_template)
+            .map(|template| crate::error::docs::render_docs_url(&template, &error.class, error.code, &self.kind));
+        let metadata = error.metadata_mut();
+        metadata.kind = Some(self.kind);
+        metadata.fingerprint = self.fingerprint;
+        metadata.request_id = self.request_id;
+        metadata.retryable = self.retryable;
+        metadata.circuit_signal = self.circuit_signal;
+        metadata.help = self.help;
+        metadata.docs_url = docs_url;
+        metadata.source = self.source;
+        metadata.lazy_details = self.lazy_details;
+        #[cfg(feature = "timestamp")]
+        if self.timestamp.is_some() {
+            metadata.timestamp = self.timestamp;
+        }
+        if self.rebuilt {
+            return error;
+        }
+        #[cfg(feature = "metrics")]
+        if crate::metrics::auto_record() {
+            error.record();
+        }
+        #[cfg(feature = "log")]
+        if crate::log::auto_log() {
+            error.log();
+        }
+        crate::report::auto_report(&error);
+        error
     }
 }
 
@@ -149,8 +414,39 @@ impl Default for ErrorBuilder {
     /// ```
     fn default() -> Self {
         ErrorBuilder::new(
-            crate::error::ErrorKind("InternalServerError", 500, "Internal Server Error"),
-            "UnknownError".into(),
+            crate::kinds::http::InternalServerError,
+            "UnknownError",
         )
     }
 }
+
+/// Creates an `ErrorBuilder` pre-populated with the fields of an existing `Error`.
+///
+/// See [`Error::to_builder`](crate::Error::to_builder).
+impl From<crate::error::Error> for ErrorBuilder {
+    fn from(error: crate::error::Error) -> Self {
+        let name = error.class.rsplit("::").next().unwrap_or_default().to_string();
+        let metadata = std::sync::Arc::unwrap_or_clone(error.metadata);
+        Self {
+            kind: metadata.kind.unwrap_or_else(|| crate::error::KindInfo::from(&crate::kinds::http::kind_from_code(error.code))),
+            code: Some(error.code),
+            name,
+            message: Some(error.message.to_string()),
+            details: std::sync::Arc::unwrap_or_clone(error.details),
+            lazy_details: metadata.lazy_details,
+            fingerprint: metadata.fingerprint,
+            request_id: metadata.request_id,
+            retryable: metadata.retryable,
+            circuit_signal: metadata.circuit_signal,
+            help: metadata.help,
+            docs_url: metadata.docs_url,
+            #[cfg(feature = "timestamp")]
+            timestamp: metadata.timestamp,
+            source: metadata.source,
+            class: Some(error.class.to_string()),
+            context: std::sync::Arc::unwrap_or_clone(error.context),
+            location: None,
+            rebuilt: true,
+        }
+    }
+}
@@ -0,0 +1,121 @@
+use std::collections::BTreeMap;
+
+/// A kind listed in an [`ErrorCatalog`].
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct CatalogKind {
+    /// Name of the kind
+    pub name: String,
+    /// Default code of the kind
+    pub code: u16,
+    /// Side of the kind (`Client` or `Server`)
+    pub side: String,
+    /// Description of the kind
+    pub description: String,
+}
+
+/// An error listed in an [`ErrorCatalog`].
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct CatalogError {
+    /// Class of the error
+    pub class: String,
+    /// Code of the error
+    pub code: u16,
+    /// Default message of the error
+    pub message: String,
+    /// Example of details
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub example: BTreeMap<String, serde_value::Value>,
+}
+
+/// A reference of the kinds and errors of an application, generated from code.
+///
+/// Errors are added from an example instance, whose details are used as example payload.
+/// The catalog implements `serde::Serialize` so that it can be published in any format
+/// (e.g. JSON or YAML), and can be rendered as Markdown using [`ErrorCatalog::to_markdown`].
+///
+/// # Example
+/// ```
+/// use cdumay_core::{define_errors, ErrorCatalog};
+/// use cdumay_core::kinds::http::{NotFound, TooManyRequests};
+///
+/// define_errors! {
+///     UserNotFound = NotFound,
+///     QuotaExceeded = TooManyRequests { limit: u64 },
+/// }
+///
+/// let catalog = ErrorCatalog::new()
+///     .with_kind(NotFound)
+///     .with_kind(TooManyRequests)
+///     .with_error(UserNotFound::new())
+///     .with_error(QuotaExceeded::new(100));
+///
+/// assert_eq!(catalog.errors().len(), 2);
+/// assert!(catalog.to_markdown().contains("| Client::TooManyRequests::QuotaExceeded | 429 | Too Many Requests | `limit` |"));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ErrorCatalog {
+    kinds: Vec<CatalogKind>,
+    errors: Vec<CatalogError>,
+}
+
+impl ErrorCatalog {
+    /// Creates an empty catalog.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a kind, replacing any kind with the same name.
+    pub fn with_kind<K: crate::Kind>(mut self, kind: K) -> Self {
+        let entry = CatalogKind {
+            name: kind.name().to_string(),
+            code: kind.code(),
+            side: kind.side().to_string(),
+            description: kind.description().to_string(),
+        };
+        match self.kinds.iter_mut().find(|existing| existing.name == entry.name) {
+            Some(existing) => *existing = entry,
+            None => self.kinds.push(entry),
+        }
+        self
+    }
+
+    /// Adds an error from an example instance, replacing any error with the same class.
+    pub fn with_error<E: Into<crate::Error>>(mut self, example: E) -> Self {
+        let error: crate::Error = example.into();
+        let entry = CatalogError {
+            class: error.class.to_string(),
+            code: error.code,
+            message: error.message.to_string(),
+            example: std::sync::Arc::unwrap_or_clone(error.details),
+        };
+        match self.errors.iter_mut().find(|existing| existing.class == entry.class) {
+            Some(existing) => *existing = entry,
+            None => self.errors.push(entry),
+        }
+        self
+    }
+
+    /// Returns the kinds of the catalog.
+    pub fn kinds(&self) -> &[CatalogKind] {
+        &self.kinds
+    }
+
+    /// Returns the errors of the catalog.
+    pub fn errors(&self) -> &[CatalogError] {
+        &self.errors
+    }
+
+    /// Renders the catalog as Markdown tables, listing the detail keys of each error.
+    pub fn to_markdown(&self) -> String {
+        let mut output = String::from("## Kinds\n\n| Name | Code | Side | Description |\n|---|---|---|---|\n");
+        for kind in &self.kinds {
+            output.push_str(&format!("| {} | {} | {} | {} |\n", kind.name, kind.code, kind.side, kind.description));
+        }
+        output.push_str("\n## Errors\n\n| Class | Code | Message | Details |\n|---|---|---|---|\n");
+        for error in &self.errors {
+            let details = error.example.keys().map(|key| format!("`{}`", key)).collect::<Vec<_>>().join(", ");
+            output.push_str(&format!("| {} | {} | {} | {} |\n", error.class, error.code, error.message, details));
+        }
+        output
+    }
+}
@@ -0,0 +1,33 @@
+use crate::error::binary::{BinaryError, OwnedBinaryError};
+
+impl crate::Error {
+    /// Encodes the error in CBOR, e.g. for CoAP transports.
+    ///
+    /// The layout is the same as [`Error::to_msgpack`]: it is versioned and always includes the
+    /// `code`. The source is not encoded, and integer details are decoded as 64-bit integers.
+    ///
+    /// # Example
+    /// ```
+    /// use serde_value::Value;
+    /// use cdumay_core::{Error, ErrorBuilder};
+    /// use cdumay_core::kinds::http::BadRequest;
+    ///
+    /// let err = ErrorBuilder::new(BadRequest, "InvalidReading").with_detail("sensor", Value::String("t-01".to_string())).build();
+    /// let decoded = Error::from_cbor(&err.to_cbor().unwrap()).unwrap();
+    /// assert_eq!(decoded.code(), 400);
+    /// assert_eq!(decoded, err);
+    /// ```
+    pub fn to_cbor(&self) -> std::result::Result<Vec<u8>, ciborium::ser::Error<std::io::Error>> {
+        let mut data = Vec::new();
+        ciborium::into_writer(&BinaryError::from(self), &mut data)?;
+        Ok(data)
+    }
+
+    /// Decodes an error encoded using [`Error::to_cbor`].
+    ///
+    /// Fails if the data is not a valid error, or was written using an unsupported layout version.
+    pub fn from_cbor(data: &[u8]) -> std::result::Result<Self, ciborium::de::Error<std::io::Error>> {
+        let decoded: OwnedBinaryError = ciborium::from_reader(data)?;
+        Self::try_from(decoded).map_err(|message| ciborium::de::Error::Semantic(None, message))
+    }
+}
@@ -0,0 +1,117 @@
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+/// Compares the errors field by field, including their details and metadata.
+///
+/// The source is ignored as it cannot be compared, and the creation date (`timestamp` feature)
+/// so that identical errors built separately are equal. The [lazy details](crate::ErrorBuilder::with_detail_lazy)
+/// are computed and compared along with the other details. To compare errors by class and code
+/// only, use [`Error::key`](crate::Error::key).
+///
+/// # Example
+/// ```
+/// use std::collections::HashSet;
+/// use cdumay_core::ErrorBuilder;
+/// use cdumay_core::kinds::http::NotFound;
+///
+/// let err = ErrorBuilder::new(NotFound, "UnknownUser").build();
+/// let other = err.to_builder().with_message("User 42 not found".to_string()).build();
+/// let same = ErrorBuilder::new(NotFound, "UnknownUser").build();
+/// let errors: HashSet<_> = [err.clone(), err, same, other].into_iter().collect();
+/// assert_eq!(errors.len(), 2);
+/// ```
+impl PartialEq for crate::Error {
+    fn eq(&self, other: &Self) -> bool {
+        self.code == other.code
+            && self.class == other.class
+            && self.message == other.message
+            && self.context == other.context
+            && self.metadata == other.metadata
+            && self.resolved_details() == other.resolved_details()
+    }
+}
+
+impl Eq for crate::Error {}
+
+impl Hash for crate::Error {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.class.hash(state);
+        self.code.hash(state);
+        self.message.hash(state);
+        self.resolved_details().hash(state);
+        self.context.hash(state);
+        self.metadata.compared().hash(state);
+    }
+}
+
+/// Orders the errors by class, code, message, details, context and then metadata.
+impl Ord for crate::Error {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.class
+            .cmp(&other.class)
+            .then_with(|| self.code.cmp(&other.code))
+            .then_with(|| self.message.cmp(&other.message))
+            .then_with(|| self.resolved_details().cmp(&other.resolved_details()))
+            .then_with(|| self.context.cmp(&other.context))
+            .then_with(|| self.metadata.compared().cmp(&other.metadata.compared()))
+    }
+}
+
+impl PartialOrd for crate::Error {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Identity of an `Error`, made of its class and code, see [`Error::key`](crate::Error::key).
+///
+/// It is cheap to clone, and compared, hashed and ordered by class then code.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ErrorKey {
+    class: Arc<str>,
+    code: u16,
+}
+
+impl ErrorKey {
+    /// Returns the error class.
+    pub fn class(&self) -> &str {
+        &self.class
+    }
+
+    /// Returns the error code.
+    pub fn code(&self) -> u16 {
+        self.code
+    }
+}
+
+impl std::fmt::Display for ErrorKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({})", self.class, self.code)
+    }
+}
+
+impl crate::Error {
+    /// Returns the identity of the error, to compare or deduplicate errors by class and code only.
+    ///
+    /// # Example
+    /// ```
+    /// use std::collections::BTreeSet;
+    /// use cdumay_core::ErrorBuilder;
+    /// use cdumay_core::kinds::http::NotFound;
+    ///
+    /// let first = ErrorBuilder::new(NotFound, "UnknownUser").with_message("User 1 not found".to_string()).build();
+    /// let second = ErrorBuilder::new(NotFound, "UnknownUser").with_message("User 2 not found".to_string()).build();
+    /// assert_ne!(first, second);
+    /// assert_eq!(first.key(), second.key());
+    ///
+    /// let keys: BTreeSet<_> = [first, second].iter().map(|err| err.key()).collect();
+    /// assert_eq!(keys.len(), 1);
+    /// ```
+    pub fn key(&self) -> ErrorKey {
+        ErrorKey {
+            class: self.class.clone(),
+            code: self.code,
+        }
+    }
+}
@@ -51,6 +51,56 @@ pub trait ErrorConverter {
         Self::convert(error, text, context)
     }
 
+    /// Converts the error of a result (if any) using [`convert_error`](ErrorConverter::convert_error).
+    ///
+    /// # Arguments
+    /// - `result`: The result to convert.
+    /// - `text`: Optional message override.
+    /// - `context`: Additional structured metadata to include.
+    ///
+    /// # Example
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use serde_value::Value;
+    /// use cdumay_core::{Error, ErrorBuilder, ErrorConverter};
+    ///
+    /// struct ParseIntConverter;
+    ///
+    /// impl ErrorConverter for ParseIntConverter {
+    ///     type Error = std::num::ParseIntError;
+    ///
+    ///     fn convert(_: &Self::Error, text: String, context: BTreeMap<String, Value>) -> Error {
+    ///         ErrorBuilder::default().with_code(400).with_message(text).with_details(context).build()
+    ///     }
+    /// }
+    ///
+    /// let result = ParseIntConverter::convert_result("foo".parse::<u32>(), Some("Invalid port".to_string()), BTreeMap::new());
+    /// assert_eq!(result.unwrap_err().message(), "Invalid port");
+    /// ```
+    fn convert_result<T>(
+        result: std::result::Result<T, Self::Error>,
+        text: Option<String>,
+        context: std::collections::BTreeMap<String, serde_value::Value>,
+    ) -> crate::Result<T> {
+        result.map_err(|error| Self::convert_error(&error, text, context))
+    }
+
+    /// Converts the error of a result (if any) into a `std::io::Error`, going through a `cdumay_core::Error`.
+    ///
+    /// This is useful in code bound to `std::io::Result`, such as `std::io::Read` implementations.
+    ///
+    /// # Arguments
+    /// - `result`: The result to convert.
+    /// - `text`: Optional message override.
+    /// - `context`: Additional structured metadata to include.
+    fn convert_io<T>(
+        result: std::result::Result<T, Self::Error>,
+        text: Option<String>,
+        context: std::collections::BTreeMap<String, serde_value::Value>,
+    ) -> std::io::Result<T> {
+        Self::convert_result(result, text, context).map_err(std::io::Error::from)
+    }
+
     /// Implemented by concrete types to define how to transform the error into a `cdumay_core::Error`.
     ///
     /// This function should construct a fully-typed `cdumay_core::Error` using the provided message and context.
@@ -0,0 +1,62 @@
+use std::sync::{PoisonError, RwLock};
+
+/// URL template of the documentation used when neither the kind nor the builder sets one.
+static DOCS_URL_TEMPLATE: RwLock<Option<String>> = RwLock::new(None);
+
+/// Sets the URL template of the documentation of every error, or removes it.
+///
+/// It is used when neither the [kind](crate::Kind::docs_url) nor the
+/// [builder](crate::ErrorBuilder::with_docs_url) sets one. See [`ErrorBuilder::with_docs_url`](crate::ErrorBuilder::with_docs_url)
+/// for the supported placeholders.
+///
+/// # Example
+/// ```
+/// use cdumay_core::{ErrorBuilder, set_docs_url_template};
+/// use cdumay_core::kinds::http::NotFound;
+///
+/// set_docs_url_template(Some("https://docs.example.com/errors/{class}"));
+/// let err = ErrorBuilder::new(NotFound, "UnknownUser").build();
+/// assert_eq!(err.docs_url(), Some("https://docs.example.com/errors/Client::NotFound::UnknownUser"));
+/// # set_docs_url_template(None);
+/// ```
+pub fn set_docs_url_template(template: Option<&str>) {
+    *DOCS_URL_TEMPLATE.write().unwrap_or_else(PoisonError::into_inner) = template.map(str::to_string);
+}
+
+/// Returns the global URL template of the documentation, if any.
+pub(crate) fn docs_url_template() -> Option<String> {
+    DOCS_URL_TEMPLATE.read().unwrap_or_else(PoisonError::into_inner).clone()
+}
+
+/// Renders a URL template, replacing the `{class}`, `{code}`, `{kind}`, `{name}` and `{side}` placeholders.
+///
+/// The replaced values are percent-encoded, `:` excepted so that namespaced classes stay readable.
+pub(crate) fn render_docs_url(template: &str, class: &str, code: u16, kind: &crate::KindInfo) -> String {
+    template
+        .replace("{class}", &encode(class))
+        .replace("{code}", &code.to_string())
+        .replace("{kind}", &encode(&kind.name))
+        .replace("{name}", &encode(class.rsplit("::").next().unwrap_or_default()))
+        .replace("{side}", &kind.side.to_string())
+}
+
+/// Percent-encodes every byte of the value which is neither an unreserved character nor `:`.
+fn encode(value: &str) -> String {
+    value.bytes().fold(String::with_capacity(value.len()), |mut encoded, byte| {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b':' => encoded.push(byte as char),
+            _ => encoded.push_str(&format!("%{byte:02X}")),
+        }
+        encoded
+    })
+}
+
+impl crate::Error {
+    /// Returns the URL of the documentation of the error, if any.
+    ///
+    /// It is serialized with the error and used as `type` of the [problem details](crate::ProblemDetails),
+    /// so that clients can click through to remediation docs.
+    pub fn docs_url(&self) -> Option<&str> {
+        self.metadata.docs_url.as_deref()
+    }
+}
@@ -0,0 +1,102 @@
+use serde_value::Value;
+use std::collections::BTreeMap;
+
+/// Version of the Elastic Common Schema the documents comply with.
+const ECS_VERSION: &str = "8.11.0";
+
+/// Converts a detail value into an ECS label, which must be a keyword.
+///
+/// Scalars are formatted using their display representation, other values using their debug representation.
+fn to_label(value: &Value) -> String {
+    match value {
+        Value::Bool(value) => value.to_string(),
+        Value::U8(value) => value.to_string(),
+        Value::U16(value) => value.to_string(),
+        Value::U32(value) => value.to_string(),
+        Value::U64(value) => value.to_string(),
+        Value::I8(value) => value.to_string(),
+        Value::I16(value) => value.to_string(),
+        Value::I32(value) => value.to_string(),
+        Value::I64(value) => value.to_string(),
+        Value::F32(value) => value.to_string(),
+        Value::F64(value) => value.to_string(),
+        Value::Char(value) => value.to_string(),
+        Value::String(value) => value.clone(),
+        other => format!("{:?}", other),
+    }
+}
+
+/// Builds an object from its fields.
+fn object<'a, I: IntoIterator<Item = (&'a str, Value)>>(fields: I) -> Value {
+    Value::Map(fields.into_iter().map(|(key, value)| (Value::String(key.to_string()), value)).collect())
+}
+
+impl crate::Error {
+    /// Returns the error as an [Elastic Common Schema](https://www.elastic.co/guide/en/ecs/current/index.html) document.
+    ///
+    /// - `error.type`: the error class,
+    /// - `error.code`: the error code,
+    /// - `error.message`: the error message,
+    /// - `error.stack_trace`: the context frames from the innermost, then the chain of sources, one per line, when any,
+    /// - `labels`: the details scrubbed using the global [`Redactor`](crate::Redactor), as strings
+    ///   and with dots in keys replaced by underscores,
+    /// - `http.request.id`: the request identifier, when known,
+    /// - `@timestamp`: the creation date, when the `timestamp` feature is enabled,
+    /// - `ecs.version`: the version of the schema.
+    ///
+    /// # Example
+    /// ```
+    /// use serde_value::Value;
+    /// use cdumay_core::ErrorBuilder;
+    /// use cdumay_core::kinds::http::NotFound;
+    ///
+    /// let err = ErrorBuilder::new(NotFound, "UnknownUser").with_detail("user.id", Value::U64(42)).build();
+    /// let document = serde_json::to_value(err.to_ecs()).unwrap();
+    /// assert_eq!(document["error"]["type"], "Client::NotFound::UnknownUser");
+    /// assert_eq!(document["error"]["code"], "404");
+    /// assert_eq!(document["labels"]["user_id"], "42");
+    /// ```
+    pub fn to_ecs(&self) -> BTreeMap<String, Value> {
+        let mut error = vec![
+            ("type", Value::String(self.class.to_string())),
+            ("code", Value::String(self.code.to_string())),
+            ("message", Value::String(self.message.to_string())),
+        ];
+        let mut stack_trace = self.context.to_vec();
+        let mut source = self.source().map(|source| source as &(dyn std::error::Error + 'static));
+        while let Some(error) = source {
+            stack_trace.push(format!("caused by: {}", error));
+            source = error.source();
+        }
+        if !stack_trace.is_empty() {
+            error.push(("stack_trace", Value::String(stack_trace.join("\n"))));
+        }
+
+        let mut document = BTreeMap::new();
+        document.insert("error".to_string(), object(error));
+        document.insert(
+            "labels".to_string(),
+            Value::Map(
+                self.redacted_details()
+                    .iter()
+                    .map(|(key, value)| (Value::String(key.replace('.', "_")), Value::String(to_label(value))))
+                    .collect(),
+            ),
+        );
+        if let Some(request_id) = self.request_id() {
+            document.insert(
+                "http".to_string(),
+                object([("request", object([("id", Value::String(request_id.to_string()))]))]),
+            );
+        }
+        #[cfg(feature = "timestamp")]
+        if let Some(timestamp) = self.timestamp() {
+            document.insert(
+                "@timestamp".to_string(),
+                Value::String(timestamp.to_rfc3339_opts(chrono::SecondsFormat::Millis, true)),
+            );
+        }
+        document.insert("ecs".to_string(), object([("version", Value::String(ECS_VERSION.to_string()))]));
+        document
+    }
+}
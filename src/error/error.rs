@@ -1,5 +1,4 @@
-#[cfg(feature = "utoipa")]
-use serde_json::json;
+use std::sync::Arc;
 
 /// A structured error type with categorized information.
 ///
@@ -9,22 +8,202 @@ use serde_json::json;
 /// This structure is designed to facilitate error handling by providing
 /// detailed information that can be logged or displayed.
 ///
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq)]
+/// Its fields are shared using `Arc`, so that cloning an error does not allocate.
+///
+/// Deserialization is forgiving, so that errors produced by other versions of this crate or by
+/// other languages can be read: unknown members are ignored, missing or `null` `details` and
+/// `context` are empty, a missing `code` or `message` defaults to the code or the description of the
+/// kind (if any), and the legacy `name` member is accepted in place of `class`.
+///
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(try_from = "TolerantError")]
 #[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 /// Error
 pub struct Error {
-    #[serde(skip_serializing)]
     /// Error code
-    code: u16,
+    ///
+    /// Only serialized when the `wire` feature is enabled; defaults to the code of the kind, or to `500`,
+    /// when missing on deserialization.
+    #[cfg_attr(all(feature = "utoipa", not(feature = "wire")), schema(ignore))]
+    #[cfg_attr(all(feature = "utoipa", feature = "wire"), schema(required = false))]
+    pub(crate) code: u16,
     /// Error class
-    #[cfg_attr(feature = "utoipa", schema(example = "Client::ConfigurationError::InvalidConfiguration"))]
-    class: String,
+    #[cfg_attr(feature = "utoipa", schema(value_type = String, example = "Client::ConfigurationError::InvalidConfiguration"))]
+    pub(crate) class: Arc<str>,
     /// Human-readable message
-    #[cfg_attr(feature = "utoipa", schema(example = "Invalid configuration"))]
-    message: String,
+    #[cfg_attr(feature = "utoipa", schema(value_type = String, example = "Invalid configuration"))]
+    pub(crate) message: Arc<str>,
     /// metadata for internationalization
-    #[cfg_attr(feature = "utoipa", schema(example = json!({ "msg": "Missing value for LOG_CLUSTER" })))]
-    details: std::collections::BTreeMap<String, serde_value::Value>,
+    #[cfg_attr(
+        feature = "utoipa",
+        schema(value_type = std::collections::BTreeMap<String, serde_json::Value>, example = json!({ "msg": "Missing value for LOG_CLUSTER" }))
+    )]
+    pub(crate) details: Arc<std::collections::BTreeMap<String, serde_value::Value>>,
+    /// Context frames, from the innermost to the outermost
+    #[serde(default)]
+    #[cfg_attr(feature = "utoipa", schema(value_type = Vec<String>, example = json!(["while loading user profile"])))]
+    pub(crate) context: Arc<Vec<String>>,
+    /// Optional metadata, kept apart to keep `Error` small
+    #[serde(flatten)]
+    #[cfg_attr(feature = "utoipa", schema(value_type = Metadata))]
+    pub(crate) metadata: Arc<Metadata>,
+}
+
+/// Optional metadata of an `Error`, serialized alongside its other fields.
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub(crate) struct Metadata {
+    /// Kind from which the error was raised
+    ///
+    /// Only serialized when the `wire` feature is enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(not(feature = "wire"), serde(skip_serializing))]
+    pub(crate) kind: Option<crate::KindInfo>,
+    /// Fingerprint overriding the computed one, used to group identical errors
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) fingerprint: Option<String>,
+    /// Identifier of the request which raised the error
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "utoipa", schema(example = "5f0c3a8e-9b1d-4c6e-8a27-2d4f1e7b9c30"))]
+    pub(crate) request_id: Option<String>,
+    /// Date and time at which the error was created, serialized in ISO-8601
+    #[cfg(feature = "timestamp")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) timestamp: Option<chrono::DateTime<chrono::Utc>>,
+    /// Whether retrying the operation which raised the error may succeed, overriding the default
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) retryable: Option<bool>,
+    /// Effect of the error on a circuit breaker, overriding the default
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) circuit_signal: Option<crate::breaker::CircuitSignal>,
+    /// Hint telling how to fix the problem
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "utoipa", schema(example = "Set the LOG_CLUSTER environment variable"))]
+    pub(crate) help: Option<String>,
+    /// URL of the documentation of the error
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "utoipa", schema(example = "https://docs.example.com/errors/Client::ConfigurationError::InvalidConfiguration"))]
+    pub(crate) docs_url: Option<String>,
+    /// Underlying error, never serialized
+    #[serde(skip)]
+    pub(crate) source: Option<std::sync::Arc<dyn std::error::Error + Send + Sync>>,
+    /// Details computed on first use, serialized with the other details
+    #[serde(skip)]
+    pub(crate) lazy_details: std::collections::BTreeMap<String, crate::error::LazyDetail>,
+    /// Whether the pipeline transformers were already applied, never serialized
+    #[serde(skip)]
+    pub(crate) finalized: bool,
+}
+
+impl Metadata {
+    /// Returns the fields used to compare and hash the metadata.
+    ///
+    /// The source is ignored as it cannot be compared, and the creation date so that identical
+    /// errors built at different times are equal. The lazy details are compared along with the
+    /// other details of the `Error`.
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn compared(
+        &self,
+    ) -> (
+        Option<&crate::KindInfo>,
+        Option<&str>,
+        Option<&str>,
+        Option<bool>,
+        Option<crate::breaker::CircuitSignal>,
+        Option<&str>,
+        Option<&str>,
+    ) {
+        (
+            self.kind.as_ref(),
+            self.fingerprint.as_deref(),
+            self.request_id.as_deref(),
+            self.retryable,
+            self.circuit_signal,
+            self.help.as_deref(),
+            self.docs_url.as_deref(),
+        )
+    }
+}
+
+impl PartialEq for Metadata {
+    fn eq(&self, other: &Self) -> bool {
+        self.compared() == other.compared()
+    }
+}
+
+/// Borrowed view of an `Error` used to serialize it, with its lazy details computed.
+#[derive(serde::Serialize)]
+struct SerializedError<'a> {
+    #[cfg(feature = "wire")]
+    code: u16,
+    class: &'a str,
+    message: &'a str,
+    details: std::borrow::Cow<'a, std::collections::BTreeMap<String, serde_value::Value>>,
+    #[serde(skip_serializing_if = "<[String]>::is_empty")]
+    context: &'a [String],
+    #[serde(flatten)]
+    metadata: &'a Metadata,
+}
+
+/// Serializes the error, computing its [lazy details](crate::ErrorBuilder::with_detail_lazy).
+impl serde::Serialize for Error {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SerializedError {
+            #[cfg(feature = "wire")]
+            code: self.code,
+            class: &self.class,
+            message: &self.message,
+            details: self.resolved_details(),
+            context: &self.context,
+            metadata: &self.metadata,
+        }
+        .serialize(serializer)
+    }
+}
+
+/// Status code used when a deserialized `Error` carries neither a code nor a kind.
+fn default_code() -> u16 {
+    500
+}
+
+/// Members of a serialized `Error`, all optional but the class, see its forgiving deserialization.
+#[derive(serde::Deserialize)]
+struct TolerantError {
+    #[serde(default)]
+    code: Option<u16>,
+    #[serde(default)]
+    class: Option<String>,
+    /// Legacy name of the class
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default)]
+    message: Option<String>,
+    #[serde(default)]
+    details: Option<std::collections::BTreeMap<String, serde_value::Value>>,
+    #[serde(default)]
+    context: Option<Vec<String>>,
+    #[serde(flatten)]
+    metadata: Metadata,
+}
+
+impl TryFrom<TolerantError> for Error {
+    type Error = String;
+
+    fn try_from(error: TolerantError) -> Result<Self, Self::Error> {
+        let class = error.class.or(error.name).ok_or_else(|| "missing field `class`".to_string())?;
+        let message = error
+            .message
+            .or_else(|| error.metadata.kind.as_ref().map(|kind| kind.description.clone()))
+            .unwrap_or_default();
+        Ok(Self {
+            code: error.code.or(error.metadata.kind.as_ref().map(|kind| kind.code)).unwrap_or_else(default_code),
+            class: class.into(),
+            message: message.into(),
+            details: Arc::new(error.details.unwrap_or_default()),
+            context: Arc::new(error.context.unwrap_or_default()),
+            metadata: Arc::new(error.metadata),
+        })
+    }
 }
 
 impl Error {
@@ -55,9 +234,15 @@ impl Error {
     pub fn new(code: u16, class: String, message: String, details: std::collections::BTreeMap<String, serde_value::Value>) -> Self {
         Self {
             code,
-            class,
-            message,
-            details,
+            class: class.into(),
+            message: message.into(),
+            details: Arc::new(details),
+            context: Arc::default(),
+            metadata: Arc::new(Metadata {
+                #[cfg(feature = "timestamp")]
+                timestamp: Some(chrono::Utc::now()),
+                ..Default::default()
+            }),
         }
     }
 
@@ -105,6 +290,8 @@ impl Error {
 
     /// Returns a clone of the details map.
     ///
+    /// The [lazy details](crate::ErrorBuilder::with_detail_lazy) are computed and included.
+    ///
     /// # Example
     /// ```
     /// use std::collections::BTreeMap;
@@ -118,7 +305,379 @@ impl Error {
     /// assert!(err.details().contains_key("field"));
     /// ```
     pub fn details(&self) -> std::collections::BTreeMap<String, serde_value::Value> {
-        self.details.clone()
+        self.resolved_details().into_owned()
+    }
+
+    /// Returns the error class without cloning it.
+    ///
+    /// # Example
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use cdumay_core::Error;
+    ///
+    /// let err = Error::new(400, "ValidationError".to_string(), "Invalid username".to_string(), BTreeMap::new());
+    /// assert_eq!(err.class_ref(), "ValidationError");
+    /// ```
+    pub fn class_ref(&self) -> &str {
+        &self.class
+    }
+
+    /// Returns the error message without cloning it.
+    ///
+    /// # Example
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use cdumay_core::Error;
+    ///
+    /// let err = Error::new(400, "ValidationError".to_string(), "Invalid username".to_string(), BTreeMap::new());
+    /// assert_eq!(err.message_ref(), "Invalid username");
+    /// ```
+    pub fn message_ref(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns a reference to the details map.
+    ///
+    /// The [lazy details](crate::ErrorBuilder::with_detail_lazy) are not included, see [`Error::details`].
+    ///
+    /// # Example
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use serde_value::Value;
+    /// use cdumay_core::Error;
+    ///
+    /// let mut details = BTreeMap::new();
+    /// details.insert("field".to_string(), Value::String("username".to_string()));
+    ///
+    /// let err = Error::new(400, "ValidationError".to_string(), "Invalid username".to_string(), details);
+    /// assert!(err.details_ref().contains_key("field"));
+    /// ```
+    pub fn details_ref(&self) -> &std::collections::BTreeMap<String, serde_value::Value> {
+        &self.details
+    }
+
+    /// Returns the kind from which the error was raised.
+    ///
+    /// It is `None` for errors created using [`Error::new`], or deserialized without a kind.
+    ///
+    /// # Example
+    /// ```
+    /// use cdumay_core::ErrorBuilder;
+    /// use cdumay_core::kinds::http::NotFound;
+    ///
+    /// let err = ErrorBuilder::new(NotFound, "UserNotFound").with_code(410).build();
+    /// let kind = err.kind().unwrap();
+    /// assert_eq!(kind.name, "NotFound");
+    /// assert_eq!(kind.code, 404);
+    /// assert_eq!(kind.description, "Not Found");
+    /// ```
+    pub fn kind(&self) -> Option<&crate::KindInfo> {
+        self.metadata.kind.as_ref()
+    }
+
+    /// Returns `true` if the error was raised from the given kind.
+    ///
+    /// # Example
+    /// ```
+    /// use cdumay_core::ErrorBuilder;
+    /// use cdumay_core::kinds::http::{NotFound, Unauthorized};
+    ///
+    /// let err = ErrorBuilder::new(NotFound, "UserNotFound").build();
+    /// assert!(err.is_kind(&NotFound));
+    /// assert!(!err.is_kind(&Unauthorized));
+    /// ```
+    pub fn is_kind<K: crate::Kind>(&self, kind: &K) -> bool {
+        match self.kind() {
+            Some(info) => info.name == kind.name() && info.code == kind.code(),
+            None => self.has_class_prefix(&format!("{}::{}", kind.side(), kind.name())),
+        }
+    }
+
+    /// Returns the side from which the error originates.
+    ///
+    /// It is the side of the [kind](Error::kind) if known, otherwise it is derived from the code
+    /// using [`Side::from_code`](crate::Side::from_code).
+    ///
+    /// # Example
+    /// ```
+    /// use cdumay_core::{ErrorBuilder, Side};
+    /// use cdumay_core::kinds::http::NotFound;
+    ///
+    /// let err = ErrorBuilder::new(NotFound, "UserNotFound").build();
+    /// assert_eq!(err.side(), Side::Client);
+    /// assert!(err.is_client());
+    /// assert!(!err.is_server());
+    /// ```
+    pub fn side(&self) -> crate::Side {
+        match self.kind() {
+            Some(kind) => kind.side,
+            None => crate::Side::from_code(self.code),
+        }
+    }
+
+    /// Returns `true` if the error is a client error, see [`Error::side`].
+    pub fn is_client(&self) -> bool {
+        self.side() == crate::Side::Client
+    }
+
+    /// Returns `true` if the error is a server error, see [`Error::side`].
+    pub fn is_server(&self) -> bool {
+        self.side() == crate::Side::Server
+    }
+
+    /// Returns `true` if the class starts with the given `::` separated segments.
+    ///
+    /// Only whole segments are matched: `Client::Unauth` is not a prefix of `Client::Unauthorized::InvalidToken`.
+    ///
+    /// # Example
+    /// ```
+    /// use cdumay_core::ErrorBuilder;
+    /// use cdumay_core::kinds::http::Unauthorized;
+    ///
+    /// let err = ErrorBuilder::new(Unauthorized, "InvalidToken").build();
+    /// assert!(err.has_class_prefix("Client::Unauthorized"));
+    /// assert!(!err.has_class_prefix("Client::Unauth"));
+    /// ```
+    pub fn has_class_prefix(&self, prefix: &str) -> bool {
+        match self.class.strip_prefix(prefix) {
+            Some(rest) => rest.is_empty() || rest.starts_with("::"),
+            None => false,
+        }
+    }
+
+    /// Pushes a human-readable context frame onto the error.
+    ///
+    /// Frames are kept in the order they were added, so that the innermost context comes first.
+    ///
+    /// # Example
+    /// ```
+    /// use cdumay_core::ErrorBuilder;
+    ///
+    /// let err = ErrorBuilder::default()
+    ///     .build()
+    ///     .with_context("while reading the database")
+    ///     .with_context("while loading user profile");
+    /// assert_eq!(err.context(), ["while reading the database", "while loading user profile"]);
+    /// ```
+    pub fn with_context<C: Into<String>>(mut self, frame: C) -> Self {
+        self.context_mut().push(frame.into());
+        self
+    }
+
+    /// Returns the context frames, from the innermost to the outermost.
+    pub fn context(&self) -> &[String] {
+        &self.context
+    }
+
+    /// Sets the identifier of the request which raised the error.
+    ///
+    /// The request identifier is serialized with the error, so that an error reported by a client
+    /// can be matched with the server logs.
+    ///
+    /// # Example
+    /// ```
+    /// use cdumay_core::ErrorBuilder;
+    ///
+    /// let err = ErrorBuilder::default().build().with_request_id("req-42");
+    /// assert_eq!(err.request_id(), Some("req-42"));
+    /// ```
+    pub fn with_request_id<R: Into<String>>(mut self, request_id: R) -> Self {
+        self.metadata_mut().request_id = Some(request_id.into());
+        self
+    }
+
+    /// Returns the identifier of the request which raised the error, if any.
+    pub fn request_id(&self) -> Option<&str> {
+        self.metadata.request_id.as_deref()
+    }
+
+    /// Sets a hint telling how to fix the problem.
+    ///
+    /// The hint is serialized with the error and included in its verbose display, so that API
+    /// responses and CLI output can tell users how to fix the problem.
+    ///
+    /// # Example
+    /// ```
+    /// use cdumay_core::ErrorBuilder;
+    ///
+    /// let err = ErrorBuilder::default().build().with_help("Retry in a few minutes");
+    /// assert_eq!(format!("{:#}", err), "Server::InternalServerError::UnknownError (500) - Internal Server Error\n  help: Retry in a few minutes");
+    /// ```
+    pub fn with_help<H: Into<String>>(mut self, help: H) -> Self {
+        self.metadata_mut().help = Some(help.into());
+        self
+    }
+
+    /// Returns the hint telling how to fix the problem, if any.
+    ///
+    /// # Example
+    /// ```
+    /// use cdumay_core::ErrorBuilder;
+    ///
+    /// let err = ErrorBuilder::default().with_help("Retry in a few minutes".to_string()).build();
+    /// assert_eq!(err.help(), Some("Retry in a few minutes"));
+    /// ```
+    pub fn help(&self) -> Option<&str> {
+        self.metadata.help.as_deref()
+    }
+
+    /// Returns the date and time at which the error was created.
+    ///
+    /// It is `None` only for errors deserialized without a timestamp.
+    ///
+    /// # Example
+    /// ```
+    /// use cdumay_core::ErrorBuilder;
+    ///
+    /// let err = ErrorBuilder::default().build();
+    /// assert!(err.timestamp().is_some());
+    /// ```
+    #[cfg(feature = "timestamp")]
+    pub fn timestamp(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        self.metadata.timestamp
+    }
+
+    /// Marks whether retrying the operation which raised the error may succeed.
+    ///
+    /// # Example
+    /// ```
+    /// use cdumay_core::ErrorBuilder;
+    /// use cdumay_core::kinds::http::Conflict;
+    ///
+    /// let err = ErrorBuilder::new(Conflict, "VersionMismatch").build();
+    /// assert!(!err.is_retryable());
+    /// assert!(err.with_retryable(true).is_retryable());
+    /// ```
+    pub fn with_retryable(mut self, retryable: bool) -> Self {
+        self.metadata_mut().retryable = Some(retryable);
+        self
+    }
+
+    /// Returns whether retrying the operation which raised the error may succeed.
+    ///
+    /// Unless set using [`Error::with_retryable`], errors with a `408 Request Timeout`,
+    /// `429 Too Many Requests`, `502 Bad Gateway`, `503 Service Unavailable` or
+    /// `504 Gateway Timeout` code are retryable.
+    pub fn is_retryable(&self) -> bool {
+        self.metadata.retryable.unwrap_or(matches!(self.code, 408 | 429 | 502 | 503 | 504))
+    }
+
+    /// Sets the effect of the error on a circuit breaker, see [`Breaker::classify`](crate::breaker::Breaker::classify).
+    ///
+    /// # Example
+    /// ```
+    /// use cdumay_core::ErrorBuilder;
+    /// use cdumay_core::breaker::CircuitSignal;
+    ///
+    /// let err = ErrorBuilder::default().build().with_circuit_signal(CircuitSignal::Ignore);
+    /// assert_eq!(err.circuit_signal(), Some(CircuitSignal::Ignore));
+    /// ```
+    pub fn with_circuit_signal(mut self, signal: crate::breaker::CircuitSignal) -> Self {
+        self.metadata_mut().circuit_signal = Some(signal);
+        self
+    }
+
+    /// Returns the effect of the error on a circuit breaker, if set.
+    pub fn circuit_signal(&self) -> Option<crate::breaker::CircuitSignal> {
+        self.metadata.circuit_signal
+    }
+
+    /// Returns the underlying error, if any.
+    ///
+    /// The source is kept in memory only: it is neither serialized nor compared.
+    ///
+    /// # Example
+    /// ```
+    /// use cdumay_core::ErrorBuilder;
+    ///
+    /// let io_error = std::io::Error::other("disk full");
+    /// let err = ErrorBuilder::default().with_source(io_error).build();
+    /// assert_eq!(err.source().unwrap().to_string(), "disk full");
+    /// ```
+    pub fn source(&self) -> Option<&(dyn std::error::Error + Send + Sync + 'static)> {
+        self.metadata.source.as_deref()
+    }
+
+    /// Returns the underlying error if it is of type `T`.
+    ///
+    /// # Example
+    /// ```
+    /// use cdumay_core::ErrorBuilder;
+    ///
+    /// let err = ErrorBuilder::default().with_source(std::io::Error::other("disk full")).build();
+    /// assert_eq!(err.downcast_source::<std::io::Error>().map(|source| source.kind()), Some(std::io::ErrorKind::Other));
+    /// assert!(err.downcast_source::<std::fmt::Error>().is_none());
+    /// ```
+    pub fn downcast_source<T: std::error::Error + 'static>(&self) -> Option<&T> {
+        self.source()?.downcast_ref::<T>()
+    }
+
+    /// Returns the first error of type `T` in the chain of underlying errors, starting with the source.
+    ///
+    /// Unlike [`Error::downcast_source`], it also inspects the sources of the source, e.g. an I/O
+    /// error wrapped in a database error.
+    pub fn find_source<T: std::error::Error + 'static>(&self) -> Option<&T> {
+        let mut source: Option<&(dyn std::error::Error + 'static)> = self.source().map(|source| source as &(dyn std::error::Error + 'static));
+        while let Some(error) = source {
+            if let Some(error) = error.downcast_ref::<T>() {
+                return Some(error);
+            }
+            source = error.source();
+        }
+        None
+    }
+
+    /// Converts the error back into an [`ErrorBuilder`](crate::ErrorBuilder) pre-populated with its fields.
+    ///
+    /// The class and the context frames are kept as is, while the code, message, details and
+    /// fingerprint can be adjusted before building a new error. Building it neither merges the
+    /// [error context](crate::context) again nor records, logs or reports the error a second time.
+    ///
+    /// # Example
+    /// ```
+    /// use serde_value::Value;
+    /// use cdumay_core::ErrorBuilder;
+    /// use cdumay_core::kinds::http::NotFound;
+    ///
+    /// let err = ErrorBuilder::new(NotFound, "UserNotFound").build();
+    /// let err = err.to_builder().with_code(410).with_detail("user_id", Value::U64(42)).build();
+    /// assert_eq!(err.code(), 410);
+    /// assert_eq!(err.class(), "Client::NotFound::UserNotFound");
+    /// assert!(err.details().contains_key("user_id"));
+    /// ```
+    pub fn to_builder(&self) -> crate::ErrorBuilder {
+        crate::ErrorBuilder::from(self.clone())
+    }
+
+    /// Returns the details with the lazy ones computed, borrowing them if there is none.
+    ///
+    /// A detail set eagerly takes precedence over a lazy one with the same key.
+    pub(crate) fn resolved_details(&self) -> std::borrow::Cow<'_, std::collections::BTreeMap<String, serde_value::Value>> {
+        if self.metadata.lazy_details.is_empty() {
+            return std::borrow::Cow::Borrowed(&self.details);
+        }
+        let mut details = self.details.as_ref().clone();
+        for (key, lazy) in self.metadata.lazy_details.iter() {
+            if !details.contains_key(key) {
+                details.insert(key.clone(), lazy.value().clone());
+            }
+        }
+        std::borrow::Cow::Owned(details)
+    }
+
+    /// Returns the details for modification, cloning them only if they are shared.
+    pub(crate) fn details_mut(&mut self) -> &mut std::collections::BTreeMap<String, serde_value::Value> {
+        Arc::make_mut(&mut self.details)
+    }
+
+    /// Returns the context frames for modification, cloning them only if they are shared.
+    pub(crate) fn context_mut(&mut self) -> &mut Vec<String> {
+        Arc::make_mut(&mut self.context)
+    }
+
+    /// Returns the metadata for modification, cloning them only if they are shared.
+    pub(crate) fn metadata_mut(&mut self) -> &mut Metadata {
+        Arc::make_mut(&mut self.metadata)
     }
 }
 
@@ -144,29 +703,120 @@ impl From<Error> for std::io::Error {
     }
 }
 
+/// Converts a `std::io::Error` into an `Error`.
+///
+/// The kind is chosen among the [POSIX kinds](crate::kinds::posix) by inspecting the I/O error
+/// kind and raw OS error. The I/O error kind is stored in the details under the `io_kind` key,
+/// and the OS error code (if any) under the `errno` key.
+///
+/// # Example
+/// ```rust
+/// use cdumay_core::Error;
+///
+/// let io_error = std::io::Error::new(std::io::ErrorKind::PermissionDenied, "access denied");
+/// let err: Error = io_error.into();
+/// assert_eq!(err.code(), 403);
+/// assert_eq!(err.class(), "Client::EACCES::IoError");
+/// ```
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        let mut details = std::collections::BTreeMap::new();
+        details.insert("io_kind".to_string(), serde_value::Value::String(e.kind().to_string()));
+        if let Some(errno) = e.raw_os_error() {
+            details.insert("errno".to_string(), serde_value::Value::I32(errno));
+        }
+        crate::ErrorBuilder::new(crate::kinds::posix::kind_from_io_error(&e), "IoError")
+            .with_message(e.to_string())
+            .with_details(details)
+            .build()
+    }
+}
+
+/// Converts a boxed error, e.g. returned by legacy code, into an `Error`.
+///
+/// A `500` error with the `Server::Unknown::Boxed` class is built, with the display of the boxed
+/// error as message. The whole chain of causes is stored in the details under the `origin_chain`
+/// key, and the boxed error is kept as source.
+///
+/// # Example
+/// ```rust
+/// use cdumay_core::Error;
+///
+/// fn legacy() -> Result<u32, Box<dyn std::error::Error + Send + Sync>> {
+///     Err("invalid configuration".into())
+/// }
+///
+/// fn handler() -> cdumay_core::Result<u32> {
+///     Ok(legacy()?)
+/// }
+///
+/// let err = handler().unwrap_err();
+/// assert_eq!(err.code(), 500);
+/// assert_eq!(err.class(), "Server::Unknown::Boxed");
+/// assert_eq!(err.message(), "invalid configuration");
+/// ```
+impl From<Box<dyn std::error::Error + Send + Sync>> for Error {
+    fn from(e: Box<dyn std::error::Error + Send + Sync>) -> Self {
+        let mut chain = Vec::new();
+        let mut cause: Option<&(dyn std::error::Error + 'static)> = Some(e.as_ref());
+        while let Some(error) = cause {
+            chain.push(serde_value::Value::String(error.to_string()));
+            cause = error.source();
+        }
+        crate::ErrorBuilder::new(crate::kinds::grpc::Unknown, "Boxed")
+            .with_message(e.to_string())
+            .with_detail("origin_chain", serde_value::Value::Seq(chain))
+            .with_source(e)
+            .build()
+    }
+}
+
 /// Implements the `Display` trait for `Error`.
 ///
 /// This implementation formats the error as a human-readable string,
 /// including its kind, class, code, and message. It provides a structured
 /// error output that can be useful for logging or displaying errors in a UI.
 ///
+/// The error is written directly to the formatter, without intermediate allocation.
+///
 /// # Format
 /// ```text
 /// class (code) - message
 /// ```
 ///
+/// The alternate format (`{:#}`) is verbose: it spans multiple lines and includes the request
+/// identifier, the [help](Error::help), the details (scrubbed using the global [`Redactor`](crate::Redactor)), the context
+/// frames and the chain of underlying errors.
+/// ```text
+/// class (code) - message
+///   request_id: req-42
+///   help: hint
+///   details:
+///     key: value
+///   context:
+///     while loading user profile
+///   caused by: source
+/// ```
+///
 /// # Example
 /// ```rust
 /// use cdumay_core::{ErrorBuilder, ErrorKind};
 ///
 /// let custom_error = ErrorBuilder::new(ErrorKind("NotFound", 404, "Not Found"), "MyNotFoundError")
 ///     .with_message("foo".to_string())
+///     .with_detail("user", serde_value::Value::String("john".to_string()))
 ///     .build();
-/// println!("{}", custom_error);
+/// assert_eq!(format!("{}", custom_error), "Client::NotFound::MyNotFoundError (404) - foo");
+/// assert_eq!(format!("{:#}", custom_error), "Client::NotFound::MyNotFoundError (404) - foo\n  details:\n    user: john");
 /// ```
 impl std::fmt::Display for Error {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", format!("{} ({}) - {}", self.class, self.code, self.message))
+        write!(f, "{} ({}) - {}", self.class, self.code, self.message)?;
+        if f.alternate() {
+            let source = self.source().map(|source| source as &(dyn std::error::Error + 'static));
+            crate::__private::write_verbose(f, self.request_id(), self.help(), self.details(), &self.context, source)?;
+        }
+        Ok(())
     }
 }
 
@@ -180,6 +830,10 @@ impl std::fmt::Display for Error {
 /// and `self` as the JSON body. If the status code is invalid or unrecognized,
 /// it defaults to `500 Internal Server Error`.
 ///
+/// The error is first [finalized](crate::Error::finalize), then the body can be redacted using
+/// the global [`ErrorResponseConfig`](crate::ErrorResponseConfig). The headers describing the
+/// error are set according to the global [`ErrorHeadersConfig`](crate::ErrorHeadersConfig).
+///
 /// # Example (handler usage)
 /// ```
 /// use actix_web::{get, web, App, HttpServer};
@@ -204,7 +858,28 @@ impl std::fmt::Display for Error {
 #[cfg(feature = "actix-web")]
 impl actix_web::ResponseError for Error {
     fn error_response(&self) -> actix_web::HttpResponse {
-        actix_web::HttpResponse::build(actix_web::http::StatusCode::from_u16(self.code).unwrap_or(actix_web::http::StatusCode::INTERNAL_SERVER_ERROR))
-            .json(self)
+        let error = self.clone().finalize();
+        let mut response =
+            actix_web::HttpResponse::build(actix_web::http::StatusCode::from_u16(error.code).unwrap_or(actix_web::http::StatusCode::INTERNAL_SERVER_ERROR));
+        for header in actix_headers(&error) {
+            response.insert_header(header);
+        }
+        response.json(crate::ErrorResponseConfig::global().apply(&error))
     }
 }
+
+/// Returns the headers describing the error according to the global [`ErrorHeadersConfig`](crate::ErrorHeadersConfig),
+/// skipping the names and values which are not valid in HTTP.
+#[cfg(feature = "actix-web")]
+pub(crate) fn actix_headers(error: &Error) -> Vec<(actix_web::http::header::HeaderName, actix_web::http::header::HeaderValue)> {
+    crate::ErrorHeadersConfig::global()
+        .headers(error)
+        .into_iter()
+        .filter_map(|(name, value)| {
+            Some((
+                actix_web::http::header::HeaderName::try_from(name).ok()?,
+                actix_web::http::header::HeaderValue::try_from(value).ok()?,
+            ))
+        })
+        .collect()
+}
@@ -0,0 +1,109 @@
+/// Extension trait to turn a `std::result::Result<T, E>` into a `cdumay_core::Result<T>`.
+///
+/// This avoids an explicit `match` followed by a call to [`ErrorConverter::convert_error`]
+/// at every call site.
+///
+/// [`ErrorConverter::convert_error`]: crate::ErrorConverter::convert_error
+pub trait ResultExt<T, E: std::error::Error> {
+    /// Converts the error (if any) using the given `ErrorConverter`.
+    ///
+    /// # Arguments
+    /// - `context`: Key-value metadata that will be included with the error.
+    ///
+    /// # Example
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use serde_value::Value;
+    /// use cdumay_core::{define_errors, define_kinds, Error, ErrorConverter, ResultExt};
+    ///
+    /// define_kinds! {
+    ///     InvalidNumber = (400, "Invalid number")
+    /// }
+    ///
+    /// define_errors! {
+    ///     ParseError = InvalidNumber
+    /// }
+    ///
+    /// struct ParseIntConverter;
+    ///
+    /// impl ErrorConverter for ParseIntConverter {
+    ///     type Error = std::num::ParseIntError;
+    ///
+    ///     fn convert(_: &Self::Error, text: String, context: BTreeMap<String, Value>) -> Error {
+    ///         ParseError::new().with_message(text).with_details(context).into()
+    ///     }
+    /// }
+    ///
+    /// let result = "foo".parse::<u32>().convert_with::<ParseIntConverter>(BTreeMap::new());
+    /// assert_eq!(result.unwrap_err().code(), 400);
+    /// ```
+    fn convert_with<C: crate::ErrorConverter<Error = E>>(self, context: std::collections::BTreeMap<String, serde_value::Value>) -> crate::Result<T>;
+
+    /// Converts the error (if any) using the given closure, without declaring an `ErrorConverter`.
+    ///
+    /// # Arguments
+    /// - `context`: Key-value metadata passed to the closure along with the error.
+    /// - `convert`: Closure building the `Error` from the original error and the context.
+    ///
+    /// # Example
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use cdumay_core::{define_errors, define_kinds, ResultExt};
+    ///
+    /// define_kinds! {
+    ///     InvalidNumber = (400, "Invalid number")
+    /// }
+    ///
+    /// define_errors! {
+    ///     ParseError = InvalidNumber
+    /// }
+    ///
+    /// let result = "foo".parse::<u32>().convert_err(BTreeMap::new(), |err, context| {
+    ///     ParseError::new().with_message(err.to_string()).with_details(context).into()
+    /// });
+    /// assert_eq!(result.unwrap_err().message(), "invalid digit found in string");
+    /// ```
+    fn convert_err<F>(self, context: std::collections::BTreeMap<String, serde_value::Value>, convert: F) -> crate::Result<T>
+    where
+        F: FnOnce(&E, std::collections::BTreeMap<String, serde_value::Value>) -> crate::Error;
+
+    /// Replaces the error (if any) with an `Error` of the given kind and message.
+    ///
+    /// The original error string is kept in the details under the `origin` key.
+    ///
+    /// # Example
+    /// ```
+    /// use cdumay_core::{ErrorKind, ResultExt};
+    ///
+    /// let result = "foo".parse::<u32>().map_kind(ErrorKind("InvalidNumber", 400, "Invalid number"), "Port must be a number");
+    /// let err = result.unwrap_err();
+    /// assert_eq!(err.class(), "Client::InvalidNumber::InvalidNumber");
+    /// assert_eq!(err.message(), "Port must be a number");
+    /// ```
+    fn map_kind<K: crate::Kind>(self, kind: K, message: &str) -> crate::Result<T>;
+}
+
+impl<T, E: std::error::Error> ResultExt<T, E> for std::result::Result<T, E> {
+    fn convert_with<C: crate::ErrorConverter<Error = E>>(self, context: std::collections::BTreeMap<String, serde_value::Value>) -> crate::Result<T> {
+        self.map_err(|err| C::convert_error(&err, None, context))
+    }
+
+    fn convert_err<F>(self, context: std::collections::BTreeMap<String, serde_value::Value>, convert: F) -> crate::Result<T>
+    where
+        F: FnOnce(&E, std::collections::BTreeMap<String, serde_value::Value>) -> crate::Error,
+    {
+        self.map_err(|err| convert(&err, context))
+    }
+
+    fn map_kind<K: crate::Kind>(self, kind: K, message: &str) -> crate::Result<T> {
+        self.map_err(|err| {
+            let mut details = std::collections::BTreeMap::new();
+            details.insert("origin".to_string(), serde_value::Value::String(err.to_string()));
+            let name = kind.name();
+            crate::ErrorBuilder::new(kind, name)
+                .with_message(message.to_string())
+                .with_details(details)
+                .build()
+        })
+    }
+}
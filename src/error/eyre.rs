@@ -0,0 +1,131 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static EYRE_REPORT: AtomicBool = AtomicBool::new(false);
+
+/// Enables or disables the storage of the rendering of `eyre::Report`s in the details of the errors
+/// converted from them.
+///
+/// The rendering of handlers such as `color-eyre` holds the sections attached to the report (notes,
+/// suggestions, ...), but also the location and the backtrace of the report, which must not leak to
+/// clients. It is disabled by default.
+pub fn set_eyre_report(enabled: bool) {
+    EYRE_REPORT.store(enabled, Ordering::Relaxed);
+}
+
+/// Converts an `eyre::Report` into an `Error`.
+///
+/// If the report wraps an `Error` (see [`IntoEyre`]), the original error is returned unchanged.
+/// Otherwise, a `500 Internal Server Error` is built with the top-level message, the whole chain of
+/// causes is stored in the details under the `origin_chain` key, and the report is kept as source.
+///
+/// When enabled using [`set_eyre_report`] and the report is handled by another handler than the
+/// default one, such as `color-eyre`, its rendering (without terminal colors) is stored under the
+/// `report` key, so that the sections attached to the report are kept.
+///
+/// # Example
+/// ```
+/// use cdumay_core::Error;
+/// use eyre::WrapErr;
+///
+/// let report = Err::<(), _>(eyre::eyre!("connection reset")).wrap_err("while loading user profile").unwrap_err();
+/// let err: Error = report.into();
+/// assert_eq!(err.code(), 500);
+/// assert_eq!(err.message(), "while loading user profile");
+/// ```
+impl From<::eyre::Report> for crate::Error {
+    fn from(report: ::eyre::Report) -> Self {
+        match report.downcast::<crate::Error>() {
+            Ok(error) => error,
+            Err(report) => {
+                let chain = report.chain().map(|cause| serde_value::Value::String(cause.to_string())).collect();
+                let mut details = std::collections::BTreeMap::new();
+                details.insert("origin_chain".to_string(), serde_value::Value::Seq(chain));
+                if EYRE_REPORT.load(Ordering::Relaxed) && report.handler().downcast_ref::<::eyre::DefaultHandler>().is_none() {
+                    details.insert("report".to_string(), serde_value::Value::String(strip_ansi(&format!("{:?}", report))));
+                }
+                crate::ErrorBuilder::new(crate::kinds::http::InternalServerError, "EyreReport")
+                    .with_message(report.to_string())
+                    .with_details(details)
+                    .with_source(report)
+                    .build()
+            }
+        }
+    }
+}
+
+/// Removes the terminal escape sequences (e.g. colors) from a rendered report.
+fn strip_ansi(text: &str) -> String {
+    let mut stripped = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '\u{1b}' {
+            // Control sequences end with a character in the `@` to `~` range
+            if chars.next() == Some('[') {
+                for c in chars.by_ref() {
+                    if ('@'..='~').contains(&c) {
+                        break;
+                    }
+                }
+            }
+        } else {
+            stripped.push(c);
+        }
+    }
+    stripped
+}
+
+/// Converts an `Error` into an `eyre::Report`, so that `?` can be used in functions returning an `eyre::Result`.
+///
+/// The original `Error` can be recovered using `eyre::Report::downcast`.
+impl From<crate::Error> for ::eyre::Report {
+    fn from(error: crate::Error) -> Self {
+        ::eyre::Report::msg(error)
+    }
+}
+
+/// Converts an `Error`, or the error of a result, into an `eyre::Report`.
+///
+/// The original `Error` can be recovered using `eyre::Report::downcast`, or by converting the
+/// report back into an `Error`.
+///
+/// # Example
+/// ```
+/// use cdumay_core::{Error, ErrorBuilder, IntoEyre};
+/// use cdumay_core::kinds::http::NotFound;
+///
+/// fn find_user() -> cdumay_core::Result<String> {
+///     Err(ErrorBuilder::new(NotFound, "UnknownUser").build())
+/// }
+///
+/// fn main() -> eyre::Result<()> {
+///     let report = find_user().into_eyre().unwrap_err();
+///     assert_eq!(report.to_string(), "Client::NotFound::UnknownUser (404) - Not Found");
+///
+///     let err: Error = report.into();
+///     assert_eq!(err.code(), 404);
+///     Ok(())
+/// }
+/// ```
+pub trait IntoEyre {
+    /// The type obtained once the error is converted.
+    type Output;
+
+    /// Converts the error into an `eyre::Report`.
+    fn into_eyre(self) -> Self::Output;
+}
+
+impl IntoEyre for crate::Error {
+    type Output = ::eyre::Report;
+
+    fn into_eyre(self) -> Self::Output {
+        self.into()
+    }
+}
+
+impl<T> IntoEyre for crate::Result<T> {
+    type Output = ::eyre::Result<T>;
+
+    fn into_eyre(self) -> Self::Output {
+        self.map_err(IntoEyre::into_eyre)
+    }
+}
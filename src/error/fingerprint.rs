@@ -0,0 +1,98 @@
+use std::sync::{PoisonError, RwLock};
+
+/// Detail keys included in the fingerprint computed by `Error::fingerprint`.
+static FINGERPRINT_KEYS: RwLock<Vec<String>> = RwLock::new(Vec::new());
+
+/// Sets the detail keys included in the fingerprint of every error.
+///
+/// By default, only the class and the code are used.
+///
+/// # Example
+/// ```
+/// use cdumay_core::set_fingerprint_keys;
+///
+/// set_fingerprint_keys(&["tenant"]);
+/// ```
+pub fn set_fingerprint_keys(keys: &[&str]) {
+    *FINGERPRINT_KEYS.write().unwrap_or_else(PoisonError::into_inner) = keys.iter().map(|key| key.to_string()).collect();
+}
+
+/// 64-bit FNV-1a hasher, used because its output is stable across Rust releases and platforms.
+struct Fnv1a(u64);
+
+impl Fnv1a {
+    fn new() -> Self {
+        Self(0xcbf29ce484222325)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= *byte as u64;
+            self.0 = self.0.wrapping_mul(0x100000001b3);
+        }
+        // Field separator, so that ("ab", "c") and ("a", "bc") differ.
+        self.0 ^= 0xff;
+        self.0 = self.0.wrapping_mul(0x100000001b3);
+    }
+}
+
+impl crate::Error {
+    /// Returns a stable fingerprint used to group identical errors.
+    ///
+    /// If a fingerprint was set using `ErrorBuilder::with_fingerprint`, it is returned as is.
+    /// Otherwise, it is computed from the class, the code and the detail keys configured using
+    /// [`set_fingerprint_keys`](crate::set_fingerprint_keys).
+    ///
+    /// # Example
+    /// ```
+    /// use cdumay_core::ErrorBuilder;
+    ///
+    /// let first = ErrorBuilder::default().with_message("foo".to_string()).build();
+    /// let second = ErrorBuilder::default().with_message("bar".to_string()).build();
+    /// assert_eq!(first.fingerprint(), second.fingerprint());
+    ///
+    /// let custom = ErrorBuilder::default().with_fingerprint("db-down".to_string()).build();
+    /// assert_eq!(custom.fingerprint(), "db-down");
+    /// ```
+    pub fn fingerprint(&self) -> String {
+        match &self.metadata.fingerprint {
+            Some(fingerprint) => fingerprint.to_string(),
+            None => {
+                let keys = FINGERPRINT_KEYS.read().unwrap_or_else(PoisonError::into_inner);
+                self.fingerprint_with_keys(&keys)
+            }
+        }
+    }
+
+    /// Computes a fingerprint from the class, the code and the given detail keys.
+    ///
+    /// Unlike [`fingerprint`](Self::fingerprint), this ignores any fingerprint set on the error.
+    ///
+    /// # Example
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use serde_value::Value;
+    /// use cdumay_core::ErrorBuilder;
+    ///
+    /// let mut details = BTreeMap::new();
+    /// details.insert("tenant".to_string(), Value::String("acme".to_string()));
+    /// let err = ErrorBuilder::default().with_details(details).build();
+    /// assert_ne!(err.fingerprint_with_keys(&["tenant"]), err.fingerprint_with_keys(&[] as &[&str]));
+    /// ```
+    pub fn fingerprint_with_keys<K: AsRef<str>>(&self, keys: &[K]) -> String {
+        let mut hasher = Fnv1a::new();
+        hasher.write(self.class.as_bytes());
+        hasher.write(&self.code.to_be_bytes());
+        for key in keys {
+            let key = key.as_ref();
+            if let Some(value) = self.details.get(key).or_else(|| self.metadata.lazy_details.get(key).map(crate::error::LazyDetail::value)) {
+                hasher.write(key.as_bytes());
+                match value {
+                    serde_value::Value::String(value) => hasher.write(value.as_bytes()),
+                    other => hasher.write(format!("{:?}", other).as_bytes()),
+                }
+            }
+        }
+        format!("{:016x}", hasher.0)
+    }
+}
@@ -0,0 +1,124 @@
+use std::collections::BTreeMap;
+
+/// Format of an `Error` in an HTTP response body, negotiated from the `Accept` header.
+///
+/// # Example
+/// ```
+/// use cdumay_core::ErrorFormat;
+///
+/// assert_eq!(ErrorFormat::from_accept("text/html, text/plain;q=0.9, */*;q=0.1"), ErrorFormat::Text);
+/// assert_eq!(ErrorFormat::from_accept("application/problem+json"), ErrorFormat::ProblemJson);
+/// assert_eq!(ErrorFormat::from_accept("image/png"), ErrorFormat::Json);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum ErrorFormat {
+    /// The `Error` serialized as JSON (`application/json`)
+    #[default]
+    Json,
+    /// A problem details object as defined by RFC 9457 (`application/problem+json`)
+    ProblemJson,
+    /// The `Error` display (`text/plain`)
+    Text,
+    /// The `Error` serialized as XML (`application/xml`)
+    #[cfg(feature = "xml")]
+    Xml,
+}
+
+impl ErrorFormat {
+    /// Returns the preferred format according to the given `Accept` header value.
+    ///
+    /// Media ranges are sorted by quality, the first one supported is selected. If none is
+    /// supported, [`ErrorFormat::Json`] is returned.
+    pub fn from_accept(accept: &str) -> Self {
+        let mut ranges: Vec<(&str, f32)> = accept
+            .split(',')
+            .filter_map(|range| {
+                let mut parts = range.split(';').map(str::trim);
+                let media_type = parts.next().filter(|media_type| !media_type.is_empty())?;
+                let quality = parts
+                    .filter_map(|param| param.strip_prefix("q="))
+                    .find_map(|quality| quality.parse::<f32>().ok())
+                    .unwrap_or(1.0);
+                Some((media_type, quality))
+            })
+            .filter(|(_, quality)| *quality > 0.0)
+            .collect();
+        ranges.sort_by(|a, b| b.1.total_cmp(&a.1));
+        ranges
+            .into_iter()
+            .find_map(|(media_type, _)| Self::from_media_type(media_type))
+            .unwrap_or_default()
+    }
+
+    fn from_media_type(media_type: &str) -> Option<Self> {
+        match media_type.to_ascii_lowercase().as_str() {
+            "application/json" | "application/*" | "*/*" => Some(Self::Json),
+            "application/problem+json" => Some(Self::ProblemJson),
+            "text/plain" | "text/*" => Some(Self::Text),
+            #[cfg(feature = "xml")]
+            "application/xml" | "text/xml" => Some(Self::Xml),
+            _ => None,
+        }
+    }
+
+    /// Returns the `Content-Type` of the format.
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            Self::Json => "application/json",
+            Self::ProblemJson => "application/problem+json",
+            Self::Text => "text/plain; charset=utf-8",
+            #[cfg(feature = "xml")]
+            Self::Xml => "application/xml",
+        }
+    }
+}
+
+/// A problem details object as defined by RFC 9457, built from an `Error`.
+///
+/// The error class and details are exposed as extension members.
+///
+/// # Example
+/// ```
+/// use cdumay_core::{ErrorBuilder, ProblemDetails};
+///
+/// let err = ErrorBuilder::new(cdumay_core::kinds::http::NotFound, "UserNotFound").build();
+/// let problem = ProblemDetails::from(&err);
+/// assert_eq!(problem.title, "Client::NotFound::UserNotFound");
+/// assert_eq!(problem.status, 404);
+/// ```
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ProblemDetails {
+    /// URI reference identifying the problem type: the [documentation URL](crate::Error::docs_url)
+    /// of the error, or `about:blank`
+    #[serde(rename = "type")]
+    pub type_: String,
+    /// Short summary of the problem type
+    pub title: String,
+    /// HTTP status code
+    pub status: u16,
+    /// Explanation specific to this occurrence of the problem
+    pub detail: String,
+    /// Identifier of this occurrence of the problem
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub instance: Option<String>,
+    /// Hint telling how to fix the problem
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub help: Option<String>,
+    /// Details of the error
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub details: BTreeMap<String, serde_value::Value>,
+}
+
+impl From<&crate::Error> for ProblemDetails {
+    fn from(error: &crate::Error) -> Self {
+        Self {
+            type_: error.docs_url().unwrap_or("about:blank").to_string(),
+            title: error.class.to_string(),
+            status: error.code,
+            detail: error.message.to_string(),
+            instance: error.request_id().map(|request_id| format!("urn:request:{request_id}")),
+            help: error.help().map(str::to_string),
+            details: error.details(),
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use serde_json::{Map, Value};
+
+/// Returns a valid GELF additional field name, replacing the forbidden characters by underscores.
+fn field_name(key: &str) -> String {
+    let name: String = key
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    // `_id` is reserved by Graylog
+    match name.as_str() {
+        "id" => "_id_".to_string(),
+        _ => format!("_{}", name),
+    }
+}
+
+/// Flattens a detail into additional fields, which must be strings or numbers.
+///
+/// Nested keys and sequence indices are joined using underscores, booleans are sent as strings
+/// and null values are dropped.
+fn flatten(prefix: String, value: Value, fields: &mut Map<String, Value>) {
+    match value {
+        Value::Null => {}
+        Value::Bool(value) => {
+            fields.insert(field_name(&prefix), Value::String(value.to_string()));
+        }
+        Value::Object(map) => map
+            .into_iter()
+            .for_each(|(key, value)| flatten(format!("{}_{}", prefix, key), value, fields)),
+        Value::Array(values) => values
+            .into_iter()
+            .enumerate()
+            .for_each(|(index, value)| flatten(format!("{}_{}", prefix, index), value, fields)),
+        value => {
+            fields.insert(field_name(&prefix), value);
+        }
+    }
+}
+
+/// Returns the current time in milliseconds since the Unix epoch.
+fn now_millis() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_millis() as i64)
+        .unwrap_or_default()
+}
+
+impl crate::Error {
+    /// Returns the error as a [GELF 1.1](https://go2docs.graylog.org/current/getting_in_log_data/gelf.html) message sent by `host`.
+    ///
+    /// - `short_message` is the error message and `full_message` its display, followed by the context frames,
+    /// - `level` is the [syslog severity](crate::Error::syslog_severity),
+    /// - `_class`, `_code` and `_request_id` describe the error, taking precedence over the details,
+    /// - the details, scrubbed using the global [`Redactor`](crate::Redactor), are flattened to
+    ///   `_`-prefixed additional fields (e.g. `_user_id` for the `id` of the `user` detail).
+    ///
+    /// # Example
+    /// ```
+    /// use serde_value::Value;
+    /// use cdumay_core::ErrorBuilder;
+    /// use cdumay_core::kinds::http::NotFound;
+    ///
+    /// let err = ErrorBuilder::new(NotFound, "UnknownUser").with_detail("user", Value::String("john".to_string())).build();
+    /// let message = err.to_gelf("api-1");
+    /// assert_eq!(message["version"], "1.1");
+    /// assert_eq!(message["level"], 4);
+    /// assert_eq!(message["_user"], "john");
+    /// ```
+    pub fn to_gelf(&self, host: &str) -> Value {
+        #[cfg(feature = "timestamp")]
+        let timestamp = self
+            .metadata
+            .timestamp
+            .map(|timestamp| timestamp.timestamp_millis())
+            .unwrap_or_else(now_millis);
+        #[cfg(not(feature = "timestamp"))]
+        let timestamp = now_millis();
+        let full_message = std::iter::once(self.to_string())
+            .chain(self.context.iter().cloned())
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let mut message = Map::new();
+        message.insert("version".to_string(), "1.1".into());
+        message.insert("host".to_string(), host.into());
+        message.insert("short_message".to_string(), self.message_ref().into());
+        message.insert("full_message".to_string(), full_message.into());
+        message.insert("timestamp".to_string(), (timestamp as f64 / 1000.0).into());
+        message.insert("level".to_string(), self.syslog_severity().code().into());
+        for (key, value) in self.redacted_details() {
+            if let Ok(value) = value.deserialize_into::<Value>() {
+                flatten(key, value, &mut message);
+            }
+        }
+        message.insert("_class".to_string(), self.class_ref().into());
+        message.insert("_code".to_string(), self.code.into());
+        if let Some(request_id) = self.request_id() {
+            message.insert("_request_id".to_string(), request_id.into());
+        }
+        Value::Object(message)
+    }
+}
@@ -0,0 +1,33 @@
+use ::http::StatusCode;
+
+impl crate::Error {
+    /// Returns the HTTP status matching the error code.
+    ///
+    /// Codes outside of the `100..=599` range are not valid HTTP statuses: they are clamped to
+    /// `500 Internal Server Error`.
+    ///
+    /// # Example
+    /// ```
+    /// use cdumay_core::{Error, ErrorBuilder};
+    /// use cdumay_core::kinds::http::NotFound;
+    ///
+    /// let err = ErrorBuilder::new(NotFound, "UnknownUser").build();
+    /// assert_eq!(err.status_code(), http::StatusCode::NOT_FOUND);
+    ///
+    /// let err = Error::new(42, "Custom::Error".to_string(), "Invalid code".to_string(), Default::default());
+    /// assert_eq!(err.status_code(), http::StatusCode::INTERNAL_SERVER_ERROR);
+    /// ```
+    pub fn status_code(&self) -> StatusCode {
+        match self.code {
+            100..=599 => StatusCode::from_u16(self.code).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR),
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+/// Converts an `Error` into its HTTP status, see [`Error::status_code`](crate::Error::status_code).
+impl From<&crate::Error> for StatusCode {
+    fn from(error: &crate::Error) -> Self {
+        error.status_code()
+    }
+}
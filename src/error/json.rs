@@ -0,0 +1,60 @@
+impl crate::Error {
+    /// Serializes the error in compact JSON, using its serde representation.
+    ///
+    /// # Example
+    /// ```
+    /// use cdumay_core::ErrorBuilder;
+    /// use cdumay_core::kinds::http::NotFound;
+    ///
+    /// let json = ErrorBuilder::new(NotFound, "UnknownUser").build().to_json().unwrap();
+    /// assert!(json.contains(r#""class":"Client::NotFound::UnknownUser""#));
+    /// ```
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(self)
+    }
+
+    /// Serializes the error in indented JSON, with the keys of all objects sorted alphabetically.
+    ///
+    /// The output does not depend on the order in which the details were added nor on the features
+    /// of `serde_json`, which makes it suited for snapshot tests and human-readable logs. Note that
+    /// the `timestamp` feature adds the creation date of the error to the output.
+    ///
+    /// # Example
+    /// ```
+    /// use cdumay_core::ErrorBuilder;
+    /// use cdumay_core::kinds::http::NotFound;
+    /// use serde_value::Value;
+    ///
+    /// let err = ErrorBuilder::new(NotFound, "UnknownUser")
+    ///     .with_detail("user", Value::U64(42))
+    ///     .with_detail("tenant", Value::String("acme".to_string()))
+    ///     .build();
+    /// let json = err.to_json_pretty().unwrap();
+    /// assert!(json.starts_with("{\n  \"class\": \"Client::NotFound::UnknownUser\",\n"));
+    /// assert!(json.find("tenant") < json.find("user"));
+    /// ```
+    pub fn to_json_pretty(&self) -> Result<String, serde_json::Error> {
+        // Maps of `serde_value` are sorted, whether `serde_json` preserves the order or not
+        let value = serde_value::to_value(self).map_err(serde::ser::Error::custom)?;
+        serde_json::to_string_pretty(&value)
+    }
+
+    /// Parses an error serialized in JSON, either using its serde representation or its
+    /// [versioned form](crate::Error::to_versioned_json).
+    ///
+    /// The `code` and the `kind` are kept when present, see [`Error::from_versioned_json`](crate::Error::from_versioned_json).
+    /// Unless the `wire` feature is enabled, they are only written by the versioned form.
+    ///
+    /// # Example
+    /// ```
+    /// use cdumay_core::{Error, ErrorBuilder};
+    /// use cdumay_core::kinds::http::NotFound;
+    ///
+    /// let err = ErrorBuilder::new(NotFound, "UnknownUser").build();
+    /// assert_eq!(Error::from_json_str(&err.to_json().unwrap()).unwrap().class(), err.class());
+    /// assert_eq!(Error::from_json_str(&err.to_versioned_json().unwrap()).unwrap(), err);
+    /// ```
+    pub fn from_json_str(json: &str) -> Result<Self, serde_json::Error> {
+        Self::from_versioned_json(json)
+    }
+}
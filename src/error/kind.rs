@@ -1,3 +1,7 @@
+use std::sync::{PoisonError, RwLock};
+
+static REGISTERED: RwLock<Vec<ErrorKind>> = RwLock::new(Vec::new());
+
 /// Represents a categorized error kind with associated metadata.
 ///
 /// The `ErrorKind` struct defines a specific type of error, providing
@@ -10,7 +14,22 @@
 /// 
 /// let kind = ErrorKind("NotFound", 404, "Not Found");
 /// ```
-#[derive(Debug, Clone, PartialEq)]
+///
+/// Kinds are serialized like [`KindInfo`]. As their strings are `'static`, they are deserialized
+/// only into the [standard kinds](crate::kinds) and the [registered](ErrorKind::register) ones,
+/// other kinds being rejected. Use [`KindInfo`] to deserialize any kind.
+///
+/// ```rust
+/// use cdumay_core::ErrorKind;
+/// use cdumay_core::kinds::http::NotFound;
+///
+/// let json = serde_json::to_string(&NotFound).unwrap();
+/// assert_eq!(json, r#"{"name":"NotFound","code":404,"description":"Not Found","side":"Client"}"#);
+/// assert_eq!(serde_json::from_str::<ErrorKind>(&json).unwrap(), NotFound);
+/// ```
+///
+/// A kind can carry the URL template of its documentation, see [`ErrorKind::with_docs_url`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct ErrorKind(
     /// A unique error identifier (name).
     pub &'static str,
@@ -60,10 +79,27 @@ impl ErrorKind {
         self.2
     }
 
+    /// Attaches the URL template of the documentation of the kind, used by the errors raised from it.
+    ///
+    /// See [`ErrorBuilder::with_docs_url`](crate::ErrorBuilder::with_docs_url) for the supported
+    /// placeholders. Kinds defined using [`define_kinds!`](crate::define_kinds) can set it with the
+    /// `#[docs = "..."]` attribute.
+    ///
+    /// # Example
+    /// ```
+    /// use cdumay_core::{DocumentedKind, ErrorBuilder, ErrorKind};
+    ///
+    /// const QUOTA_EXCEEDED: DocumentedKind = ErrorKind("QuotaExceeded", 429, "Quota exceeded").with_docs_url("https://docs.example.com/{kind}");
+    /// let err = ErrorBuilder::new(QUOTA_EXCEEDED, "DailyQuota").build();
+    /// assert_eq!(err.docs_url(), Some("https://docs.example.com/QuotaExceeded"));
+    /// ```
+    pub const fn with_docs_url(self, template: &'static str) -> DocumentedKind {
+        DocumentedKind(self, template)
+    }
+
     /// Determines whether the error originates from the client or the server.
     ///
-    /// - Errors with codes in the range 0 to 499 are classified as **Client** errors.
-    /// - Errors with codes 500 or higher are classified as **Server** errors.
+    /// See [`Side::from_code`].
     ///
     /// # Example
     /// ```
@@ -75,12 +111,53 @@ impl ErrorKind {
     /// let server_error = ErrorKind("InternalServerError", 500, "Internal Server Error");
     /// assert_eq!(server_error.side(), "Server");
     /// ```
-    pub fn side(&self) -> &'static str {
-        match self.code() {
-            0..=499 => "Client",
-            _ => "Server",
+    pub fn side(&self) -> Side {
+        Side::from_code(self.code())
+    }
+
+    /// Registers a kind defined outside this crate, e.g. using
+    /// [`define_kinds!`](crate::define_kinds), so that it can be deserialized.
+    ///
+    /// # Example
+    /// ```
+    /// use cdumay_core::ErrorKind;
+    ///
+    /// let json = r#"{"name":"QuotaExceeded","code":429,"description":"Quota exceeded"}"#;
+    /// assert!(serde_json::from_str::<ErrorKind>(json).is_err());
+    ///
+    /// ErrorKind("QuotaExceeded", 429, "Quota exceeded").register();
+    /// assert_eq!(serde_json::from_str::<ErrorKind>(json).unwrap().code(), 429);
+    /// ```
+    pub fn register(self) {
+        let mut registered = REGISTERED.write().unwrap_or_else(PoisonError::into_inner);
+        if !registered.contains(&self) {
+            registered.push(self);
         }
     }
+
+    /// Returns the standard or registered kind matching the given fields.
+    fn resolve(name: &str, code: u16, description: &str) -> Option<Self> {
+        let matches = |kind: &&ErrorKind| kind.0 == name && kind.1 == code && kind.2 == description;
+        let mut standard = crate::kinds::http::KINDS.iter().chain(&crate::kinds::grpc::KINDS).chain(&crate::kinds::posix::KINDS);
+        match standard.find(matches) {
+            Some(kind) => Some(kind.clone()),
+            None => REGISTERED.read().unwrap_or_else(PoisonError::into_inner).iter().find(matches).cloned(),
+        }
+    }
+}
+
+impl serde::Serialize for ErrorKind {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        KindInfo::from(self).serialize(serializer)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for ErrorKind {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let info = KindInfo::deserialize(deserializer)?;
+        ErrorKind::resolve(&info.name, info.code, &info.description)
+            .ok_or_else(|| serde::de::Error::custom(format!("unknown error kind `{}` ({})", info.name, info.code)))
+    }
 }
 
 /// Returns the default `ErrorKind`, which represents an internal server error (HTTP 500).
@@ -89,7 +166,286 @@ impl ErrorKind {
 /// It corresponds to the common "Internal Server Error" used in HTTP responses.
 impl Default for ErrorKind {
     fn default() -> Self {
-        ErrorKind("InternalServerError", 500, "Internal Server Error")
+        crate::kinds::http::InternalServerError
+    }
+}
+
+/// A trait for user-defined error kinds.
+///
+/// Besides the [`ErrorKind`] constants, error kinds can be defined using any type implementing
+/// this trait, for instance an enum to benefit from exhaustive matching. Kinds implementing
+/// this trait can be used with `ErrorBuilder::new` and `define_errors!`.
+///
+/// # Example
+/// ```
+/// use cdumay_core::{define_errors, ErrorBuilder, Kind};
+///
+/// #[derive(Debug, Clone, Copy)]
+/// enum UserKind {
+///     NotFound,
+///     Locked,
+/// }
+///
+/// impl Kind for UserKind {
+///     fn name(&self) -> &'static str {
+///         match self {
+///             UserKind::NotFound => "UserNotFound",
+///             UserKind::Locked => "UserLocked",
+///         }
+///     }
+///     fn code(&self) -> u16 {
+///         match self {
+///             UserKind::NotFound => 404,
+///             UserKind::Locked => 423,
+///         }
+///     }
+///     fn description(&self) -> &'static str {
+///         match self {
+///             UserKind::NotFound => "User not found",
+///             UserKind::Locked => "User account is locked",
+///         }
+///     }
+/// }
+///
+/// let err = ErrorBuilder::new(UserKind::Locked, "TooManyAttempts").build();
+/// assert_eq!(err.class(), "Client::UserLocked::TooManyAttempts");
+///
+/// define_errors! {
+///     MissingUser = (UserKind::NotFound),
+///     AccountLocked = (UserKind::Locked, 403),
+/// }
+/// assert_eq!(AccountLocked::new().code(), 403);
+/// ```
+///
+/// The side is derived from the code by default, and can be declared explicitly:
+/// ```
+/// use cdumay_core::{ErrorBuilder, Kind, Side};
+///
+/// struct QueueFull;
+///
+/// impl Kind for QueueFull {
+///     fn name(&self) -> &'static str {
+///         "QueueFull"
+///     }
+///     fn code(&self) -> u16 {
+///         1
+///     }
+///     fn description(&self) -> &'static str {
+///         "Queue is full"
+///     }
+///     fn side(&self) -> Side {
+///         Side::Server
+///     }
+/// }
+///
+/// let err = ErrorBuilder::new(QueueFull, "JobRejected").build();
+/// assert_eq!(err.class(), "Server::QueueFull::JobRejected");
+/// ```
+pub trait Kind {
+    /// Returns the name of the kind.
+    fn name(&self) -> &'static str;
+
+    /// Returns the numerical error code.
+    fn code(&self) -> u16;
+
+    /// Returns the description of the kind.
+    fn description(&self) -> &'static str;
+
+    /// Determines whether the error originates from the client or the server.
+    ///
+    /// Defaults to [`Side::from_code`], override it to declare the side explicitly.
+    fn side(&self) -> Side {
+        Side::from_code(self.code())
+    }
+
+    /// Returns the URL template of the documentation of the kind, if any.
+    ///
+    /// See [`ErrorBuilder::with_docs_url`](crate::ErrorBuilder::with_docs_url) for the supported placeholders.
+    fn docs_url(&self) -> Option<&'static str> {
+        None
+    }
+}
+
+impl Kind for ErrorKind {
+    fn name(&self) -> &'static str {
+        ErrorKind::name(self)
+    }
+
+    fn code(&self) -> u16 {
+        ErrorKind::code(self)
+    }
+
+    fn description(&self) -> &'static str {
+        ErrorKind::description(self)
+    }
+
+    fn side(&self) -> Side {
+        ErrorKind::side(self)
+    }
+}
+
+/// An [`ErrorKind`] carrying the URL template of its documentation, see [`ErrorKind::with_docs_url`].
+///
+/// It dereferences to the `ErrorKind`, and implements [`Kind`] so that the errors raised from it
+/// get their [documentation URL](crate::Error::docs_url).
+///
+/// # Example
+/// ```
+/// use cdumay_core::{DocumentedKind, ErrorKind, Kind};
+///
+/// const QUOTA_EXCEEDED: DocumentedKind = ErrorKind("QuotaExceeded", 429, "Quota exceeded").with_docs_url("https://docs.example.com/{kind}");
+/// assert_eq!(QUOTA_EXCEEDED.code(), 429);
+/// assert_eq!(QUOTA_EXCEEDED.docs_url(), Some("https://docs.example.com/{kind}"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct DocumentedKind(
+    /// The documented kind.
+    pub ErrorKind,
+    /// URL template of the documentation of the kind.
+    pub &'static str,
+);
+
+impl DocumentedKind {
+    /// Registers the kind so that it can be deserialized, see [`ErrorKind::register`].
+    pub fn register(self) {
+        self.0.register()
+    }
+}
+
+impl std::ops::Deref for DocumentedKind {
+    type Target = ErrorKind;
+
+    fn deref(&self) -> &ErrorKind {
+        &self.0
+    }
+}
+
+impl From<DocumentedKind> for ErrorKind {
+    fn from(kind: DocumentedKind) -> Self {
+        kind.0
+    }
+}
+
+impl Kind for DocumentedKind {
+    fn name(&self) -> &'static str {
+        self.0.name()
+    }
+
+    fn code(&self) -> u16 {
+        self.0.code()
+    }
+
+    fn description(&self) -> &'static str {
+        self.0.description()
+    }
+
+    fn side(&self) -> Side {
+        self.0.side()
+    }
+
+    fn docs_url(&self) -> Option<&'static str> {
+        Some(self.1)
+    }
+}
+
+/// Owned description of the kind from which an `Error` was raised, see [`Error::kind`](crate::Error::kind).
+///
+/// Unlike [`ErrorKind`], it can be deserialized, so that the kind of a remote error is kept.
+///
+/// # Example
+/// ```
+/// use cdumay_core::KindInfo;
+/// use cdumay_core::kinds::http::NotFound;
+///
+/// let kind = KindInfo::from(&NotFound);
+/// assert_eq!(kind.name, "NotFound");
+/// assert_eq!(kind.side, "Client");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct KindInfo {
+    /// Name of the kind
+    pub name: String,
+    /// Default code of the kind
+    pub code: u16,
+    /// Description of the kind
+    pub description: String,
+    /// Side of the kind
+    #[serde(default)]
+    pub side: Side,
+}
+
+impl<K: Kind> From<&K> for KindInfo {
+    fn from(kind: &K) -> Self {
+        Self {
+            name: kind.name().to_string(),
+            code: kind.code(),
+            description: kind.description().to_string(),
+            side: kind.side(),
+        }
+    }
+}
+
+/// Side from which an error originates.
+///
+/// # Example
+/// ```
+/// use cdumay_core::Side;
+///
+/// assert_eq!(Side::from_code(404), Side::Client);
+/// assert_eq!(Side::from_code(503).to_string(), "Server");
+/// assert_eq!(Side::from_code(0), "Unknown");
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub enum Side {
+    /// The error is caused by the client
+    Client,
+    /// The error is caused by the server
+    Server,
+    /// The side cannot be determined
+    #[default]
+    Unknown,
+}
+
+impl Side {
+    /// Returns the side matching an HTTP-like status code.
+    ///
+    /// - Codes in the range 400 to 499 are classified as **Client** errors.
+    /// - Codes in the range 500 to 599 are classified as **Server** errors.
+    /// - Other codes are classified as **Unknown**.
+    pub fn from_code(code: u16) -> Self {
+        match code {
+            400..=499 => Side::Client,
+            500..=599 => Side::Server,
+            _ => Side::Unknown,
+        }
+    }
+
+    /// Returns the name of the side, as used in error classes.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Side::Client => "Client",
+            Side::Server => "Server",
+            Side::Unknown => "Unknown",
+        }
     }
 }
 
+impl std::fmt::Display for Side {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl PartialEq<str> for Side {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for Side {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
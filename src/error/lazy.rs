@@ -0,0 +1,41 @@
+use serde_value::Value;
+use std::sync::{Arc, LazyLock};
+
+/// Value computed on first use.
+///
+/// Kept behind a trait object so that errors can be used as keys of maps and sets: the value
+/// never changes once computed.
+trait LazyValue: Send + Sync {
+    fn value(&self) -> &Value;
+}
+
+impl<F: FnOnce() -> Value + Send> LazyValue for LazyLock<Value, F> {
+    fn value(&self) -> &Value {
+        self
+    }
+}
+
+/// A detail computed on first use, see [`ErrorBuilder::with_detail_lazy`](crate::ErrorBuilder::with_detail_lazy).
+///
+/// Clones share the computed value, so that it is computed at most once.
+#[derive(Clone)]
+pub(crate) struct LazyDetail(Arc<dyn LazyValue>);
+
+impl LazyDetail {
+    /// Creates a detail computed by the given function.
+    pub(crate) fn new<F: FnOnce() -> Value + Send + 'static>(compute: F) -> Self {
+        Self(Arc::new(LazyLock::new(compute)))
+    }
+
+    /// Returns the value of the detail, computing it if needed.
+    pub(crate) fn value(&self) -> &Value {
+        self.0.value()
+    }
+}
+
+/// Does not compute the value.
+impl std::fmt::Debug for LazyDetail {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("LazyDetail(..)")
+    }
+}
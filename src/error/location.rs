@@ -0,0 +1,44 @@
+/// Location in the source code where an error was created.
+///
+/// It is obtained using [`here!`](crate::here), which knows the module, or
+/// [`CodeLocation::caller`], which relies on `#[track_caller]`.
+///
+/// # Example
+/// ```
+/// use cdumay_core::{ErrorBuilder, here};
+///
+/// let err = ErrorBuilder::default().with_code_location(here!()).build();
+/// assert!(err.details().contains_key("location"));
+/// assert!(err.details().contains_key("module"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CodeLocation {
+    /// Source file
+    pub file: &'static str,
+    /// Line in the source file
+    pub line: u32,
+    /// Column in the source file
+    pub column: u32,
+    /// Module path, if known
+    pub module: Option<&'static str>,
+}
+
+impl CodeLocation {
+    /// Returns the location of the caller, see `std::panic::Location::caller`.
+    #[track_caller]
+    pub fn caller() -> Self {
+        let location = std::panic::Location::caller();
+        Self {
+            file: location.file(),
+            line: location.line(),
+            column: location.column(),
+            module: None,
+        }
+    }
+}
+
+impl std::fmt::Display for CodeLocation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}:{}", self.file, self.line, self.column)
+    }
+}
@@ -0,0 +1,88 @@
+use ::miette::{Diagnostic, Severity};
+use std::fmt::Display;
+
+/// An `Error` reported as a miette diagnostic.
+///
+/// As `Error` does not implement `std::error::Error`, it is wrapped to implement `Diagnostic`:
+/// - the diagnostic code is the error class,
+/// - the help is the [hint](crate::Error::help) of the error, if any,
+/// - the URL is the [documentation URL](crate::Error::docs_url) of the error, if any,
+/// - the severity is **error** for codes 500 and above, **warning** for codes between 400 and
+///   499 and **advice** otherwise.
+///
+/// # Example
+/// ```
+/// use cdumay_core::{ErrorBuilder, ErrorDiagnostic};
+/// use cdumay_core::kinds::http::NotFound;
+/// use miette::Diagnostic;
+///
+/// let err = ErrorBuilder::new(NotFound, "UnknownConfig").with_help("Run `init` first".to_string()).build();
+/// let diagnostic = ErrorDiagnostic::from(err);
+/// assert_eq!(diagnostic.code().unwrap().to_string(), "Client::NotFound::UnknownConfig");
+/// assert_eq!(diagnostic.help().unwrap().to_string(), "Run `init` first");
+/// assert_eq!(diagnostic.severity(), Some(miette::Severity::Warning));
+///
+/// let report: miette::Report = diagnostic.into_inner().into();
+/// assert_eq!(report.to_string(), "Client::NotFound::UnknownConfig (404) - Not Found");
+/// ```
+#[derive(Debug, Clone)]
+pub struct ErrorDiagnostic(crate::Error);
+
+impl ErrorDiagnostic {
+    /// Returns the wrapped error.
+    pub fn error(&self) -> &crate::Error {
+        &self.0
+    }
+
+    /// Unwraps the error.
+    pub fn into_inner(self) -> crate::Error {
+        self.0
+    }
+}
+
+impl From<crate::Error> for ErrorDiagnostic {
+    fn from(error: crate::Error) -> Self {
+        Self(error)
+    }
+}
+
+impl std::fmt::Display for ErrorDiagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl std::error::Error for ErrorDiagnostic {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.0.source().map(|source| source as &(dyn std::error::Error + 'static))
+    }
+}
+
+impl Diagnostic for ErrorDiagnostic {
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        Some(Box::new(self.0.class_ref()))
+    }
+
+    fn severity(&self) -> Option<Severity> {
+        Some(match self.0.code {
+            500.. => Severity::Error,
+            400..=499 => Severity::Warning,
+            _ => Severity::Advice,
+        })
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.0.help().map(|help| Box::new(help) as Box<dyn Display + 'a>)
+    }
+
+    fn url<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.0.docs_url().map(|url| Box::new(url) as Box<dyn Display + 'a>)
+    }
+}
+
+/// Converts an `Error` into a miette report, see [`ErrorDiagnostic`].
+impl From<crate::Error> for ::miette::Report {
+    fn from(error: crate::Error) -> Self {
+        ::miette::Report::new(ErrorDiagnostic(error))
+    }
+}
@@ -1,10 +1,102 @@
 //! Provides structs to manipulate errors
+#[cfg(feature = "actix-web")]
+mod actix;
+#[cfg(feature = "anyhow")]
+mod anyhow;
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+#[cfg(any(feature = "rmp", feature = "cbor"))]
+mod binary;
 mod builder;
+mod catalog;
+#[cfg(feature = "cbor")]
+mod cbor;
+mod cmp;
 mod convert;
+mod docs;
+mod ecs;
+#[allow(clippy::module_inception)]
 mod error;
+mod ext;
+#[cfg(feature = "eyre")]
+mod eyre;
+mod fingerprint;
+mod format;
+#[cfg(feature = "gelf")]
+mod gelf;
+#[cfg(feature = "http")]
+mod http;
+#[cfg(feature = "serde-json")]
+mod json;
 mod kind;
+mod lazy;
+mod location;
+#[cfg(feature = "miette")]
+mod miette;
+mod multi;
+#[cfg(feature = "rmp")]
+mod msgpack;
+#[cfg(feature = "napi")]
+mod napi;
+#[cfg(feature = "opentelemetry")]
+mod opentelemetry;
+mod panic;
+#[cfg(feature = "pyo3")]
+mod pyo3;
+mod redact;
+mod registry;
+#[cfg(feature = "reqwest")]
+mod reqwest;
+mod response;
+#[cfg(feature = "sentry")]
+mod sentry;
+#[cfg(feature = "snafu")]
+mod snafu;
+mod syslog;
+mod template;
+#[cfg(feature = "tower")]
+mod tower;
+#[cfg(feature = "tracing")]
+mod tracing;
+#[cfg(feature = "serde-json")]
+mod versioned;
+#[cfg(feature = "uniffi")]
+mod uniffi;
+#[cfg(feature = "warp")]
+mod warp;
+#[cfg(feature = "wasm")]
+mod wasm;
 
+#[cfg(feature = "actix-web")]
+pub use actix::{RequestId, attach_request_id, catch_panic_middleware, json_config, negotiate_error_format, path_config, query_config};
 pub use builder::ErrorBuilder;
+pub use catalog::{CatalogError, CatalogKind, ErrorCatalog};
+pub use cmp::ErrorKey;
 pub use convert::ErrorConverter;
+pub use docs::set_docs_url_template;
 pub use error::Error;
-pub use kind::ErrorKind;
+pub use ext::ResultExt;
+#[cfg(feature = "eyre")]
+pub use eyre::{IntoEyre, set_eyre_report};
+pub use fingerprint::set_fingerprint_keys;
+pub use format::{ErrorFormat, ProblemDetails};
+pub use kind::{DocumentedKind, ErrorKind, Kind, KindInfo, Side};
+pub(crate) use lazy::LazyDetail;
+pub use location::CodeLocation;
+#[cfg(feature = "miette")]
+pub use miette::ErrorDiagnostic;
+pub use multi::MultiError;
+pub use panic::catch_panic;
+#[cfg(feature = "pyo3")]
+pub use pyo3::PyCdumayError;
+pub use redact::Redactor;
+pub use registry::ConverterRegistry;
+pub use response::{ErrorHeadersConfig, ErrorResponseConfig};
+pub use syslog::SyslogSeverity;
+pub use template::ErrorTemplate;
+#[cfg(feature = "tower")]
+pub use tower::{ErrorMapping, ErrorMappingFuture, ErrorMappingLayer};
+#[cfg(feature = "uniffi")]
+pub use uniffi::CdumayError;
+#[cfg(feature = "warp")]
+pub use warp::handle_cdumay_rejection;
@@ -0,0 +1,30 @@
+use crate::error::binary::{BinaryError, OwnedBinaryError};
+
+impl crate::Error {
+    /// Encodes the error in MessagePack, for compact transport between services.
+    ///
+    /// Unlike the serde representation, the layout is versioned and always includes the `code`.
+    /// The source is not encoded, and integer details are decoded using the smallest fitting type.
+    ///
+    /// # Example
+    /// ```
+    /// use cdumay_core::{Error, ErrorBuilder};
+    /// use cdumay_core::kinds::http::NotFound;
+    ///
+    /// let err = ErrorBuilder::new(NotFound, "UnknownUser").with_request_id("req-1".to_string()).build();
+    /// let decoded = Error::from_msgpack(&err.to_msgpack().unwrap()).unwrap();
+    /// assert_eq!(decoded.code(), 404);
+    /// assert_eq!(decoded, err);
+    /// ```
+    pub fn to_msgpack(&self) -> std::result::Result<Vec<u8>, rmp_serde::encode::Error> {
+        rmp_serde::to_vec(&BinaryError::from(self))
+    }
+
+    /// Decodes an error encoded using [`Error::to_msgpack`].
+    ///
+    /// Fails if the data is not a valid error, or was written using an unsupported layout version.
+    pub fn from_msgpack(data: &[u8]) -> std::result::Result<Self, rmp_serde::decode::Error> {
+        let decoded: OwnedBinaryError = rmp_serde::from_slice(data)?;
+        Self::try_from(decoded).map_err(<rmp_serde::decode::Error as serde::de::Error>::custom)
+    }
+}
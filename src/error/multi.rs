@@ -0,0 +1,167 @@
+/// A collection of `Error` values, e.g. all the validation failures of a form.
+///
+/// `MultiError` serializes to an array of errors and can be converted into a single
+/// `Error` whose details contain the sub-errors under the `errors` key.
+///
+/// # Example
+/// ```
+/// use cdumay_core::{Error, ErrorBuilder, ErrorKind, MultiError};
+///
+/// let kind = ErrorKind("ValidationError", 400, "Invalid input");
+/// let errors: MultiError = ["username", "email"]
+///     .iter()
+///     .map(|field| ErrorBuilder::new(kind.clone(), "MissingField").with_message(format!("{} is required", field)).build())
+///     .collect();
+/// assert_eq!(errors.len(), 2);
+///
+/// let err: Error = errors.into();
+/// assert_eq!(err.code(), 400);
+/// assert!(err.details().contains_key("errors"));
+/// ```
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize, PartialEq)]
+#[serde(transparent)]
+pub struct MultiError(Vec<crate::Error>);
+
+impl MultiError {
+    /// Creates an empty `MultiError`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an error to the collection.
+    pub fn push(&mut self, error: crate::Error) {
+        self.0.push(error);
+    }
+
+    /// Returns the number of collected errors.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if no error was collected.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns an iterator over the collected errors.
+    pub fn iter(&self) -> std::slice::Iter<'_, crate::Error> {
+        self.0.iter()
+    }
+
+    /// Returns the collected errors.
+    pub fn errors(&self) -> &[crate::Error] {
+        &self.0
+    }
+
+    /// Returns the status code of the aggregated error.
+    ///
+    /// This is the highest code among the collected errors, so that server errors prevail
+    /// over client errors. Defaults to `500` when the collection is empty.
+    pub fn code(&self) -> u16 {
+        self.0.iter().map(|error| error.code()).max().unwrap_or(500)
+    }
+
+    /// Returns `Ok(())` if no error was collected, the aggregated `Error` otherwise.
+    ///
+    /// # Example
+    /// ```
+    /// use cdumay_core::MultiError;
+    ///
+    /// assert!(MultiError::new().into_result().is_ok());
+    /// ```
+    pub fn into_result(self) -> crate::Result<()> {
+        match self.is_empty() {
+            true => Ok(()),
+            false => Err(self.into()),
+        }
+    }
+}
+
+impl FromIterator<crate::Error> for MultiError {
+    fn from_iter<I: IntoIterator<Item = crate::Error>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl Extend<crate::Error> for MultiError {
+    fn extend<I: IntoIterator<Item = crate::Error>>(&mut self, iter: I) {
+        self.0.extend(iter)
+    }
+}
+
+impl IntoIterator for MultiError {
+    type Item = crate::Error;
+    type IntoIter = std::vec::IntoIter<crate::Error>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a MultiError {
+    type Item = &'a crate::Error;
+    type IntoIter = std::slice::Iter<'a, crate::Error>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+/// Converts a `MultiError` into a single `Error` of kind `MultipleErrors`.
+///
+/// The sub-errors are stored in the details under the `errors` key.
+impl From<MultiError> for crate::Error {
+    fn from(errors: MultiError) -> Self {
+        let code = errors.code();
+        let mut details = std::collections::BTreeMap::new();
+        details.insert("errors".to_string(), serde_value::to_value(&errors).unwrap_or(serde_value::Value::Unit));
+        crate::ErrorBuilder::new(crate::ErrorKind("MultipleErrors", code, "Multiple errors occurred"), "MultiError")
+            .with_message(format!("{} error(s) occurred", errors.len()))
+            .with_details(details)
+            .build()
+    }
+}
+
+impl std::error::Error for MultiError {}
+
+/// Implements the `Display` trait for `MultiError`.
+///
+/// Each collected error is displayed on its own line.
+impl std::fmt::Display for MultiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (idx, error) in self.0.iter().enumerate() {
+            if idx > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}", error)?;
+        }
+        Ok(())
+    }
+}
+
+/// Implements the `actix_web::ResponseError` trait for `MultiError`.
+///
+/// The response uses the aggregated status code and the JSON array of errors as body. As for a
+/// single `Error`, each error is [finalized](crate::Error::finalize), then redacted using the global
+/// [`ErrorResponseConfig`](crate::ErrorResponseConfig). The headers describing the errors, set
+/// according to the global [`ErrorHeadersConfig`](crate::ErrorHeadersConfig), are repeated for
+/// each error, but the `Retry-After` header which is taken from the first error carrying one.
+#[cfg(feature = "actix-web")]
+impl actix_web::ResponseError for MultiError {
+    fn error_response(&self) -> actix_web::HttpResponse {
+        let errors: Vec<crate::Error> = self.iter().map(|error| error.clone().finalize()).collect();
+        let mut response =
+            actix_web::HttpResponse::build(actix_web::http::StatusCode::from_u16(self.code()).unwrap_or(actix_web::http::StatusCode::INTERNAL_SERVER_ERROR));
+        let mut retry_after = false;
+        for (name, value) in errors.iter().flat_map(super::error::actix_headers) {
+            if name != actix_web::http::header::RETRY_AFTER {
+                response.append_header((name, value));
+            } else if !retry_after {
+                retry_after = true;
+                response.insert_header((name, value));
+            }
+        }
+        let config = crate::ErrorResponseConfig::global();
+        response.json(errors.iter().map(|error| config.apply(error)).collect::<Vec<_>>())
+    }
+}
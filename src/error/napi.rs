@@ -0,0 +1,51 @@
+use ::napi::{Env, JsObject, Status};
+
+/// Converts an `Error` into a `napi::Error` whose reason is the error display, e.g.
+/// `Client::NotFound::UnknownUser (404) - Not Found`.
+///
+/// Returning it from a `#[napi]` function throws a plain JS `Error`: use
+/// [`Error::throw_js`](crate::Error::throw_js) to keep the structured properties.
+///
+/// # Example
+/// ```
+/// use cdumay_core::ErrorBuilder;
+/// use cdumay_core::kinds::http::NotFound;
+///
+/// let err: napi::Error = ErrorBuilder::new(NotFound, "UnknownUser").build().into();
+/// assert_eq!(err.reason, "Client::NotFound::UnknownUser (404) - Not Found");
+/// ```
+impl From<crate::Error> for ::napi::Error {
+    fn from(error: crate::Error) -> Self {
+        ::napi::Error::new(Status::GenericFailure, error.to_string())
+    }
+}
+
+impl crate::Error {
+    /// Creates a JS `Error` whose `name` is the error class, carrying the `class`, `code`,
+    /// `message` and `details` properties.
+    pub fn to_js_error(&self, env: &Env) -> ::napi::Result<JsObject> {
+        let mut object = env.create_error(::napi::Error::new(Status::GenericFailure, &self.message))?;
+        object.set_named_property("name", env.create_string(&self.class)?)?;
+        object.set_named_property("class", env.create_string(&self.class)?)?;
+        object.set_named_property("code", env.create_uint32(u32::from(self.code))?)?;
+        object.set_named_property("details", env.to_js_value(&self.resolved_details())?)?;
+        Ok(object)
+    }
+
+    /// Throws the error as a JS exception created by [`Error::to_js_error`](crate::Error::to_js_error).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use napi::{CallContext, JsUndefined};
+    /// use cdumay_core::ErrorBuilder;
+    /// use cdumay_core::kinds::http::NotFound;
+    ///
+    /// fn get_user(ctx: CallContext) -> napi::Result<JsUndefined> {
+    ///     ErrorBuilder::new(NotFound, "UnknownUser").build().throw_js(ctx.env)?;
+    ///     ctx.env.get_undefined()
+    /// }
+    /// ```
+    pub fn throw_js(&self, env: &Env) -> ::napi::Result<()> {
+        env.throw(self.to_js_error(env)?)
+    }
+}
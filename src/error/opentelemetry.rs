@@ -0,0 +1,75 @@
+use ::opentelemetry::trace::{Span, SpanRef, Status};
+use ::opentelemetry::{KeyValue, Value};
+
+/// Converts a detail value into an OpenTelemetry attribute value.
+///
+/// Scalars keep their type, other values are recorded using their debug representation.
+fn to_otel_value(value: &serde_value::Value) -> Value {
+    match value {
+        serde_value::Value::Bool(value) => Value::Bool(*value),
+        serde_value::Value::U8(value) => Value::I64(*value as i64),
+        serde_value::Value::U16(value) => Value::I64(*value as i64),
+        serde_value::Value::U32(value) => Value::I64(*value as i64),
+        serde_value::Value::U64(value) => i64::try_from(*value).map(Value::I64).unwrap_or_else(|_| Value::from(value.to_string())),
+        serde_value::Value::I8(value) => Value::I64(*value as i64),
+        serde_value::Value::I16(value) => Value::I64(*value as i64),
+        serde_value::Value::I32(value) => Value::I64(*value as i64),
+        serde_value::Value::I64(value) => Value::I64(*value),
+        serde_value::Value::F32(value) => Value::F64(*value as f64),
+        serde_value::Value::F64(value) => Value::F64(*value),
+        serde_value::Value::String(value) => Value::from(value.clone()),
+        other => Value::from(format!("{:?}", other)),
+    }
+}
+
+impl crate::Error {
+    /// Returns the OpenTelemetry attributes describing the error.
+    ///
+    /// - `exception.type`: the error class,
+    /// - `exception.message`: the error message,
+    /// - `error.class`: the error class,
+    /// - `error.code`: the error code,
+    /// - `error.details.<key>`: one attribute per detail, scrubbed using the global [`Redactor`](crate::Redactor).
+    pub fn otel_attributes(&self) -> Vec<KeyValue> {
+        let mut attributes = vec![
+            KeyValue::new("exception.type", self.class()),
+            KeyValue::new("exception.message", self.message()),
+            KeyValue::new("error.class", self.class()),
+            KeyValue::new("error.code", self.code() as i64),
+        ];
+        attributes.extend(
+            self.redacted_details()
+                .iter()
+                .map(|(key, value)| KeyValue::new(format!("error.details.{}", key), to_otel_value(value))),
+        );
+        attributes
+    }
+
+    /// Records the error attributes on the given span and marks its status as error.
+    ///
+    /// # Example
+    /// ```
+    /// use cdumay_core::ErrorBuilder;
+    /// use opentelemetry::trace::{Tracer, TracerProvider};
+    ///
+    /// let tracer = opentelemetry::trace::noop::NoopTracerProvider::new().tracer("example");
+    /// let mut span = tracer.start("handler");
+    /// ErrorBuilder::default().build().record_on_span(&mut span);
+    /// ```
+    pub fn record_on_span<S: Span>(&self, span: &mut S) {
+        span.set_attributes(self.otel_attributes());
+        span.set_status(Status::error(self.message()));
+    }
+
+    /// Records the error attributes on the active span of the current context and marks
+    /// its status as error.
+    ///
+    /// This also works with spans created by `tracing-opentelemetry`, once the OpenTelemetry
+    /// context has been attached.
+    pub fn record_on_active_span(&self) {
+        ::opentelemetry::trace::get_active_span(|span: SpanRef| {
+            span.set_attributes(self.otel_attributes());
+            span.set_status(Status::error(self.message()));
+        })
+    }
+}
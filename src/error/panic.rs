@@ -0,0 +1,79 @@
+use std::cell::RefCell;
+use std::sync::Once;
+
+thread_local! {
+    /// Location of the last panic which occurred on the current thread.
+    static LAST_PANIC_LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+static HOOK: Once = Once::new();
+
+/// Installs a panic hook recording the panic location, chained with the previous hook.
+fn install_hook() {
+    HOOK.call_once(|| {
+        let previous = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            let location = info.location().map(|location| location.to_string());
+            LAST_PANIC_LOCATION.with(|last| *last.borrow_mut() = location);
+            previous(info);
+        }));
+    });
+}
+
+/// Builds a `500 Internal Server Error` from a panic payload.
+///
+/// The panic message is stored in the details under the `panic` key, and its location (if
+/// known) under the `location` key.
+pub(crate) fn error_from_panic(payload: Box<dyn std::any::Any + Send>) -> crate::Error {
+    let message = match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => match payload.downcast::<&'static str>() {
+            Ok(message) => message.to_string(),
+            Err(_) => "Box<dyn Any>".to_string(),
+        },
+    };
+    let mut details = std::collections::BTreeMap::new();
+    details.insert("panic".to_string(), serde_value::Value::String(message));
+    if let Some(location) = LAST_PANIC_LOCATION.with(|last| last.borrow_mut().take()) {
+        details.insert("location".to_string(), serde_value::Value::String(location));
+    }
+    crate::ErrorBuilder::new(crate::kinds::http::InternalServerError, "Panic").with_details(details).build()
+}
+
+/// Runs `f`, converting a panic into a `500 Internal Server Error`.
+///
+/// The panic message is stored in the details under the `panic` key, and its location under
+/// the `location` key. The panic is still reported by the panic hook.
+///
+/// # Example
+/// ```
+/// use cdumay_core::catch_panic;
+///
+/// assert_eq!(catch_panic(|| 42).unwrap(), 42);
+///
+/// let err = catch_panic(|| -> u32 { panic!("boom") }).unwrap_err();
+/// assert_eq!(err.code(), 500);
+/// assert_eq!(err.class(), "Server::InternalServerError::Panic");
+/// assert_eq!(err.details()["panic"], serde_value::Value::String("boom".to_string()));
+/// ```
+pub fn catch_panic<F: FnOnce() -> T + std::panic::UnwindSafe, T>(f: F) -> crate::Result<T> {
+    install_hook();
+    std::panic::catch_unwind(f).map_err(error_from_panic)
+}
+
+/// Polls the inner future, converting a panic into a `500 Internal Server Error`.
+#[cfg(feature = "actix-web")]
+pub(crate) struct CatchPanic<F: Future>(pub(crate) std::pin::Pin<Box<F>>);
+
+#[cfg(feature = "actix-web")]
+impl<F: Future> Future for CatchPanic<F> {
+    type Output = crate::Result<F::Output>;
+
+    fn poll(mut self: std::pin::Pin<&mut Self>, cx: &mut std::task::Context<'_>) -> std::task::Poll<Self::Output> {
+        install_hook();
+        match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| self.0.as_mut().poll(cx))) {
+            Ok(poll) => poll.map(Ok),
+            Err(payload) => std::task::Poll::Ready(Err(error_from_panic(payload))),
+        }
+    }
+}
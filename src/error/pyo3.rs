@@ -0,0 +1,195 @@
+use ::pyo3::exceptions::PyException;
+use ::pyo3::prelude::*;
+use ::pyo3::types::{PyBool, PyBytes, PyDict, PyFloat, PyInt, PyList, PyString, PyTuple};
+use serde_value::Value;
+use std::collections::BTreeMap;
+
+/// Python exception raised from an `Error`, exposed as `CdumayError`.
+///
+/// Its `message`, `class`, `code` and `details` attributes mirror the error fields. Register it
+/// in the extension module using [`PyCdumayError::register`] so that Python code can catch it.
+///
+/// # Example
+/// ```
+/// use pyo3::prelude::*;
+/// use cdumay_core::{Error, ErrorBuilder, PyCdumayError};
+/// use cdumay_core::kinds::http::NotFound;
+///
+/// Python::initialize();
+/// Python::attach(|py| {
+///     let err: PyErr = ErrorBuilder::new(NotFound, "UnknownUser").build().into();
+///     assert!(err.is_instance_of::<PyCdumayError>(py));
+///     assert_eq!(err.value(py).getattr("code").unwrap().extract::<u16>().unwrap(), 404);
+///
+///     let err: Error = err.into();
+///     assert_eq!(err.class(), "Client::NotFound::UnknownUser");
+/// });
+/// ```
+#[pyclass(name = "CdumayError", module = "cdumay_core", extends = PyException, frozen)]
+pub struct PyCdumayError {
+    /// Human-readable message
+    #[pyo3(get)]
+    message: String,
+    /// Error class
+    #[pyo3(get)]
+    class: String,
+    /// Error code
+    #[pyo3(get)]
+    code: u16,
+    /// Details, as a dictionary
+    #[pyo3(get)]
+    details: Py<PyDict>,
+}
+
+#[pymethods]
+impl PyCdumayError {
+    #[new]
+    #[pyo3(signature = (message, class, code, details = None))]
+    fn new(py: Python<'_>, message: String, class: String, code: u16, details: Option<Py<PyDict>>) -> Self {
+        Self {
+            message,
+            class,
+            code,
+            details: details.unwrap_or_else(|| PyDict::new(py).unbind()),
+        }
+    }
+
+    fn __str__(&self) -> String {
+        format!("{} ({}) - {}", self.class, self.code, self.message)
+    }
+}
+
+impl PyCdumayError {
+    /// Adds the `CdumayError` exception class to the given module.
+    pub fn register(module: &Bound<'_, PyModule>) -> PyResult<()> {
+        module.add_class::<Self>()
+    }
+}
+
+/// Converts a detail value into a Python object.
+fn to_python<'py>(py: Python<'py>, value: &Value) -> PyResult<Bound<'py, PyAny>> {
+    Ok(match value {
+        Value::Bool(value) => PyBool::new(py, *value).to_owned().into_any(),
+        Value::U8(value) => value.into_pyobject(py)?.into_any(),
+        Value::U16(value) => value.into_pyobject(py)?.into_any(),
+        Value::U32(value) => value.into_pyobject(py)?.into_any(),
+        Value::U64(value) => value.into_pyobject(py)?.into_any(),
+        Value::I8(value) => value.into_pyobject(py)?.into_any(),
+        Value::I16(value) => value.into_pyobject(py)?.into_any(),
+        Value::I32(value) => value.into_pyobject(py)?.into_any(),
+        Value::I64(value) => value.into_pyobject(py)?.into_any(),
+        Value::F32(value) => value.into_pyobject(py)?.into_any(),
+        Value::F64(value) => value.into_pyobject(py)?.into_any(),
+        Value::Char(value) => value.into_pyobject(py)?.into_any(),
+        Value::String(value) => PyString::new(py, value).into_any(),
+        Value::Bytes(value) => PyBytes::new(py, value).into_any(),
+        Value::Unit | Value::Option(None) => py.None().into_bound(py),
+        Value::Option(Some(value)) | Value::Newtype(value) => to_python(py, value)?,
+        Value::Seq(values) => PyList::new(py, values.iter().map(|value| to_python(py, value)).collect::<PyResult<Vec<_>>>()?)?.into_any(),
+        Value::Map(map) => {
+            let dict = PyDict::new(py);
+            for (key, value) in map {
+                dict.set_item(to_python(py, key)?, to_python(py, value)?)?;
+            }
+            dict.into_any()
+        }
+    })
+}
+
+/// Converts a Python object into a detail value, unsupported objects being converted using `str()`.
+fn from_python(object: &Bound<'_, PyAny>) -> PyResult<Value> {
+    if object.is_none() {
+        Ok(Value::Unit)
+    } else if let Ok(value) = object.cast::<PyBool>() {
+        Ok(Value::Bool(value.is_true()))
+    } else if object.is_instance_of::<PyInt>() {
+        match object.extract::<i64>() {
+            Ok(value) => Ok(Value::I64(value)),
+            Err(_) => object
+                .extract::<u64>()
+                .map(Value::U64)
+                .or_else(|_| Ok(Value::String(object.str()?.to_string()))),
+        }
+    } else if object.is_instance_of::<PyFloat>() {
+        object.extract::<f64>().map(Value::F64)
+    } else if let Ok(value) = object.cast::<PyString>() {
+        Ok(Value::String(value.to_string()))
+    } else if let Ok(value) = object.cast::<PyBytes>() {
+        Ok(Value::Bytes(value.as_bytes().to_vec()))
+    } else if let Ok(dict) = object.cast::<PyDict>() {
+        dict.iter()
+            .map(|(key, value)| Ok((from_python(&key)?, from_python(&value)?)))
+            .collect::<PyResult<_>>()
+            .map(Value::Map)
+    } else if object.is_instance_of::<PyList>() || object.is_instance_of::<PyTuple>() {
+        object
+            .try_iter()?
+            .map(|item| from_python(&item?))
+            .collect::<PyResult<_>>()
+            .map(Value::Seq)
+    } else {
+        Ok(Value::String(object.str()?.to_string()))
+    }
+}
+
+/// Arguments of the `CdumayError` raised from an `Error`, converted once the GIL is held.
+struct ErrorArguments(crate::Error);
+
+impl ::pyo3::PyErrArguments for ErrorArguments {
+    fn arguments(self, py: Python<'_>) -> Py<PyAny> {
+        let details = PyDict::new(py);
+        for (key, value) in self.0.resolved_details().iter() {
+            // Values which cannot be converted are sent using their debug representation
+            let value = to_python(py, value).unwrap_or_else(|_| PyString::new(py, &format!("{:?}", value)).into_any());
+            let _ = details.set_item(key, value);
+        }
+        (self.0.message(), self.0.class(), self.0.code, details)
+            .into_pyobject(py)
+            .map(|arguments| arguments.into_any().unbind())
+            .unwrap_or_else(|_| py.None())
+    }
+}
+
+/// Raises a `CdumayError` carrying the class, code, message and details of the error.
+impl From<crate::Error> for PyErr {
+    fn from(error: crate::Error) -> Self {
+        PyErr::new::<PyCdumayError, _>(ErrorArguments(error))
+    }
+}
+
+/// Converts a Python exception.
+///
+/// A `CdumayError` gives back the original error (except its source), any other exception
+/// gives a `500 Internal Server Error` named after the exception type, with `str(exception)`
+/// as message and the Python exception kept as source.
+impl From<PyErr> for crate::Error {
+    fn from(error: PyErr) -> Self {
+        Python::attach(|py| {
+            let value = error.value(py);
+            if let Ok(exception) = value.cast::<PyCdumayError>() {
+                let exception = exception.get();
+                let details = match from_python(exception.details.bind(py)) {
+                    Ok(Value::Map(map)) => map
+                        .into_iter()
+                        .filter_map(|(key, value)| match key {
+                            Value::String(key) => Some((key, value)),
+                            _ => None,
+                        })
+                        .collect(),
+                    _ => BTreeMap::new(),
+                };
+                return crate::Error::new(exception.code, exception.class.clone(), exception.message.clone(), details);
+            }
+            let name = error
+                .get_type(py)
+                .name()
+                .map(|name| name.to_string())
+                .unwrap_or_else(|_| "PythonError".to_string());
+            let message = value.str().map(|message| message.to_string()).unwrap_or_default();
+            crate::ErrorBuilder::new(crate::kinds::http::InternalServerError, &name)
+                .with_message(message)
+                .with_source(error)
+                .build()
+        })
+    }
+}
@@ -0,0 +1,177 @@
+use serde_value::Value;
+use std::collections::BTreeMap;
+use std::sync::{PoisonError, RwLock};
+
+static REDACTOR: RwLock<Option<Redactor>> = RwLock::new(None);
+
+/// Replacement of the redacted values.
+pub(crate) const REDACTED: &str = "[REDACTED]";
+
+/// Scrubs sensitive data from the details of an `Error`.
+///
+/// A detail is redacted when its key matches one of the key patterns (case-insensitive, `*`
+/// matching any sequence of characters), or when it is a string starting with one of the value
+/// prefixes (e.g. `Bearer `). Nested maps and sequences are scrubbed recursively.
+///
+/// The global redactor is applied to the details before they are sent in HTTP responses,
+/// logged by the `tracing`, `sentry` and `opentelemetry` integrations, or exported using
+/// [`Error::to_ecs`](crate::Error::to_ecs). The `Error` itself is left untouched.
+///
+/// By default, the key patterns are `password`, `passwd`, `token`, `*_token`, `secret`,
+/// `*_secret`, `api_key` and `authorization`, and the value prefixes are `Bearer ` and `Basic `.
+///
+/// # Example
+/// ```
+/// use serde_value::Value;
+/// use cdumay_core::{ErrorBuilder, Redactor};
+///
+/// let err = ErrorBuilder::default()
+///     .with_detail("password", Value::String("hunter2".to_string()))
+///     .with_detail("client_secret", Value::String("s3cr3t".to_string()))
+///     .with_detail("user", Value::String("john".to_string()))
+///     .build();
+///
+/// let details = Redactor::default().redact(&err).details();
+/// assert_eq!(details["password"], Value::String("[REDACTED]".to_string()));
+/// assert_eq!(details["client_secret"], Value::String("[REDACTED]".to_string()));
+/// assert_eq!(details["user"], Value::String("john".to_string()));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Redactor {
+    key_patterns: Vec<String>,
+    value_prefixes: Vec<String>,
+}
+
+impl Redactor {
+    /// Creates a redactor which redacts nothing.
+    pub fn none() -> Self {
+        Self {
+            key_patterns: Vec::new(),
+            value_prefixes: Vec::new(),
+        }
+    }
+
+    /// Adds a key pattern, `*` matching any sequence of characters.
+    pub fn with_key_pattern<P: Into<String>>(mut self, pattern: P) -> Self {
+        self.key_patterns.push(pattern.into().to_lowercase());
+        self
+    }
+
+    /// Adds a prefix of the string values to redact, whatever their key.
+    pub fn with_value_prefix<P: Into<String>>(mut self, prefix: P) -> Self {
+        self.value_prefixes.push(prefix.into());
+        self
+    }
+
+    /// Returns the global redactor.
+    pub fn global() -> Self {
+        REDACTOR.read().unwrap_or_else(PoisonError::into_inner).clone().unwrap_or_default()
+    }
+
+    /// Replaces the global redactor.
+    pub fn set_global(self) {
+        *REDACTOR.write().unwrap_or_else(PoisonError::into_inner) = Some(self);
+    }
+
+    /// Returns whether the given key must be redacted.
+    pub fn is_sensitive_key(&self, key: &str) -> bool {
+        let key = key.to_lowercase();
+        self.key_patterns.iter().any(|pattern| glob_match(pattern.as_bytes(), key.as_bytes()))
+    }
+
+    /// Scrubs the given details in place.
+    pub fn redact_details(&self, details: &mut BTreeMap<String, Value>) {
+        for (key, value) in details.iter_mut() {
+            match self.is_sensitive_key(key) {
+                true => *value = Value::String(REDACTED.to_string()),
+                false => self.redact_value(value),
+            }
+        }
+    }
+
+    /// Returns a copy of the error with its details scrubbed.
+    ///
+    /// Lazy details are scrubbed once computed.
+    pub fn redact(&self, error: &crate::Error) -> crate::Error {
+        let mut error = error.clone();
+        self.redact_error(&mut error);
+        error
+    }
+
+    /// Scrubs the details of the given error in place, wrapping the lazy ones so that they stay lazy.
+    fn redact_error(&self, error: &mut crate::Error) {
+        self.redact_details(error.details_mut());
+        if error.metadata.lazy_details.is_empty() {
+            return;
+        }
+        let lazy_details = std::mem::take(&mut error.metadata_mut().lazy_details);
+        for (key, lazy) in lazy_details {
+            if self.is_sensitive_key(&key) {
+                error.details_mut().insert(key, Value::String(REDACTED.to_string()));
+                continue;
+            }
+            let redactor = self.clone();
+            let lazy = crate::error::LazyDetail::new(move || {
+                let mut value = lazy.value().clone();
+                redactor.redact_value(&mut value);
+                value
+            });
+            error.metadata_mut().lazy_details.insert(key, lazy);
+        }
+    }
+
+    fn redact_value(&self, value: &mut Value) {
+        match value {
+            Value::String(text) if self.value_prefixes.iter().any(|prefix| text.starts_with(prefix.as_str())) => {
+                *value = Value::String(REDACTED.to_string())
+            }
+            Value::Map(map) => {
+                for (key, value) in map.iter_mut() {
+                    match key {
+                        Value::String(key) if self.is_sensitive_key(key) => *value = Value::String(REDACTED.to_string()),
+                        _ => self.redact_value(value),
+                    }
+                }
+            }
+            Value::Seq(values) => values.iter_mut().for_each(|value| self.redact_value(value)),
+            Value::Option(Some(value)) | Value::Newtype(value) => self.redact_value(value),
+            _ => {}
+        }
+    }
+}
+
+impl Default for Redactor {
+    fn default() -> Self {
+        ["password", "passwd", "token", "*_token", "secret", "*_secret", "api_key", "authorization"]
+            .into_iter()
+            .fold(Self::none(), Self::with_key_pattern)
+            .with_value_prefix("Bearer ")
+            .with_value_prefix("Basic ")
+    }
+}
+
+/// Allows to register a `Redactor` in the [error pipeline](crate::pipeline).
+impl crate::pipeline::ErrorTransformer for Redactor {
+    fn transform(&self, mut error: crate::Error) -> crate::Error {
+        self.redact_error(&mut error);
+        error
+    }
+}
+
+impl crate::Error {
+    /// Returns a copy of the details scrubbed using the global [`Redactor`].
+    pub fn redacted_details(&self) -> BTreeMap<String, Value> {
+        let mut details = self.details();
+        Redactor::global().redact_details(&mut details);
+        details
+    }
+}
+
+/// Matches a text against a pattern where `*` matches any sequence of characters.
+fn glob_match(pattern: &[u8], text: &[u8]) -> bool {
+    match pattern.split_first() {
+        None => text.is_empty(),
+        Some((b'*', rest)) => (0..=text.len()).any(|start| glob_match(rest, &text[start..])),
+        Some((c, rest)) => text.split_first().is_some_and(|(t, text)| t == c && glob_match(rest, text)),
+    }
+}
@@ -0,0 +1,98 @@
+use std::collections::BTreeMap;
+
+type Conversion = Box<dyn Fn(&(dyn std::error::Error + 'static), &BTreeMap<String, serde_value::Value>) -> Option<crate::Error> + Send + Sync>;
+
+/// A set of [`ErrorConverter`](crate::ErrorConverter) applied to errors whose type is only known at runtime.
+///
+/// [`ConverterRegistry::convert`] walks the error and its sources, and uses the first converter
+/// registered for the type of one of them. This allows a single catch-all conversion at the
+/// application boundary.
+///
+/// # Example
+/// ```
+/// use std::collections::BTreeMap;
+/// use serde_value::Value;
+/// use cdumay_core::{ConverterRegistry, Error, ErrorBuilder, ErrorConverter};
+/// use cdumay_core::kinds::http::BadRequest;
+///
+/// struct ParseIntConverter;
+///
+/// impl ErrorConverter for ParseIntConverter {
+///     type Error = std::num::ParseIntError;
+///
+///     fn convert(_: &Self::Error, text: String, context: BTreeMap<String, Value>) -> Error {
+///         ErrorBuilder::new(BadRequest, "ParseIntError").with_message(text).with_details(context).build()
+///     }
+/// }
+///
+/// let registry = ConverterRegistry::new().with_converter::<ParseIntConverter>();
+///
+/// let error: Box<dyn std::error::Error> = Box::new("foo".parse::<u32>().unwrap_err());
+/// let err = registry.convert(error.as_ref(), BTreeMap::new());
+/// assert_eq!(err.class(), "Client::BadRequest::ParseIntError");
+///
+/// let error: Box<dyn std::error::Error> = "unexpected".into();
+/// let err = registry.convert(error.as_ref(), BTreeMap::new());
+/// assert_eq!(err.class(), "Server::InternalServerError::UnknownError");
+/// ```
+#[derive(Default)]
+pub struct ConverterRegistry {
+    conversions: Vec<Conversion>,
+}
+
+impl ConverterRegistry {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the converter `C`, tried after the converters already registered.
+    pub fn register<C: crate::ErrorConverter + 'static>(&mut self)
+    where
+        C::Error: 'static,
+    {
+        self.conversions.push(Box::new(|error, context| {
+            error.downcast_ref::<C::Error>().map(|error| C::convert_error(error, None, context.clone()))
+        }));
+    }
+
+    /// Registers the converter `C` and returns the registry.
+    pub fn with_converter<C: crate::ErrorConverter + 'static>(mut self) -> Self
+    where
+        C::Error: 'static,
+    {
+        self.register::<C>();
+        self
+    }
+
+    /// Returns the number of registered converters.
+    pub fn len(&self) -> usize {
+        self.conversions.len()
+    }
+
+    /// Returns `true` if no converter is registered.
+    pub fn is_empty(&self) -> bool {
+        self.conversions.is_empty()
+    }
+
+    /// Converts the error using the registered converters.
+    ///
+    /// If no converter matches, a `500 Internal Server Error` named `UnknownError` is returned,
+    /// with the error string as message.
+    pub fn convert(&self, error: &(dyn std::error::Error + 'static), context: BTreeMap<String, serde_value::Value>) -> crate::Error {
+        let mut current = Some(error);
+        while let Some(error) = current {
+            if let Some(converted) = self.conversions.iter().find_map(|conversion| conversion(error, &context)) {
+                return converted;
+            }
+            current = error.source();
+        }
+        crate::ErrorBuilder::default().with_message(error.to_string()).with_details(context).build()
+    }
+}
+
+impl std::fmt::Debug for ConverterRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ConverterRegistry").field("converters", &self.conversions.len()).finish()
+    }
+}
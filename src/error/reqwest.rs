@@ -0,0 +1,48 @@
+use crate::Error;
+
+impl Error {
+    /// Builds an `Error` from the response of another service.
+    ///
+    /// The body is parsed as a serialized `Error`, so that errors are propagated across service
+    /// boundaries. The code is always taken from the response status. If the body is not a
+    /// serialized `Error`, an error of the kind matching the status
+    /// (see [`kind_from_code`](crate::kinds::http::kind_from_code)) is built, named
+    /// `RemoteError`, with the body as message.
+    ///
+    /// # Example
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use cdumay_core::converters::ReqwestErrorConverter;
+    /// use cdumay_core::{Error, ResultExt};
+    ///
+    /// async fn fetch(url: &str) -> cdumay_core::Result<String> {
+    ///     let response = reqwest::get(url).await.convert_with::<ReqwestErrorConverter>(BTreeMap::new())?;
+    ///     if !response.status().is_success() {
+    ///         return Err(Error::from_response(response).await);
+    ///     }
+    ///     response.text().await.convert_with::<ReqwestErrorConverter>(BTreeMap::new())
+    /// }
+    /// ```
+    pub async fn from_response(response: ::reqwest::Response) -> Self {
+        let code = response.status().as_u16();
+        let body = response.bytes().await.unwrap_or_default();
+        match ::serde_json::from_slice::<Error>(&body) {
+            Ok(mut error) => {
+                error.code = code;
+                error
+            }
+            Err(_) => {
+                let message = match String::from_utf8_lossy(&body).trim() {
+                    "" => crate::kinds::http::kind_from_code(code).description().to_string(),
+                    text => text.to_string(),
+                };
+                let mut details = std::collections::BTreeMap::new();
+                details.insert("status".to_string(), serde_value::Value::U16(code));
+                crate::ErrorBuilder::new(crate::kinds::http::kind_from_code(code), "RemoteError")
+                    .with_message(message)
+                    .with_details(details)
+                    .build()
+            }
+        }
+    }
+}
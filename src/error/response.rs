@@ -0,0 +1,221 @@
+use std::sync::{PoisonError, RwLock};
+
+static RESPONSE_CONFIG: RwLock<ErrorResponseConfig> = RwLock::new(ErrorResponseConfig::new());
+static HEADERS_CONFIG: RwLock<Option<ErrorHeadersConfig>> = RwLock::new(None);
+
+/// Controls which parts of an `Error` are exposed in HTTP response bodies.
+///
+/// The configuration is global and consumed by the HTTP responders (e.g. the `actix-web`
+/// `ResponseError` implementation). It only affects the response body: the `Error` itself
+/// is left untouched, so the full payload remains available for logging.
+///
+/// By default, everything is exposed.
+///
+/// # Example
+/// ```
+/// use cdumay_core::{ErrorBuilder, ErrorResponseConfig};
+///
+/// let config = ErrorResponseConfig {
+///     expose_details: false,
+///     expose_message_for_5xx: false,
+/// };
+///
+/// let err = ErrorBuilder::default().with_message("Database password rejected".to_string()).build();
+/// let body = config.apply(&err);
+/// assert_eq!(body.message(), "Internal Server Error");
+/// assert!(body.details().is_empty());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ErrorResponseConfig {
+    /// Whether the error details are included in the response body.
+    pub expose_details: bool,
+    /// Whether the message of server errors (code 500 and above) is included in the response body.
+    ///
+    /// When disabled, it is replaced by a generic `Internal Server Error` message.
+    pub expose_message_for_5xx: bool,
+}
+
+impl ErrorResponseConfig {
+    /// Creates a configuration exposing everything.
+    pub const fn new() -> Self {
+        Self {
+            expose_details: true,
+            expose_message_for_5xx: true,
+        }
+    }
+
+    /// Returns the global configuration used by the HTTP responders.
+    pub fn global() -> Self {
+        RESPONSE_CONFIG.read().unwrap_or_else(PoisonError::into_inner).clone()
+    }
+
+    /// Replaces the global configuration used by the HTTP responders.
+    pub fn set_global(self) {
+        *RESPONSE_CONFIG.write().unwrap_or_else(PoisonError::into_inner) = self;
+    }
+
+    /// Returns a copy of the error with the hidden parts removed.
+    ///
+    /// The details are scrubbed using the global [`Redactor`](crate::Redactor).
+    pub fn apply(&self, error: &crate::Error) -> crate::Error {
+        let mut error = crate::Redactor::global().redact(error);
+        if !self.expose_message_for_5xx && error.code >= 500 {
+            error.message = "Internal Server Error".into();
+        }
+        if !self.expose_details {
+            error.details = Default::default();
+            if !error.metadata.lazy_details.is_empty() {
+                error.metadata_mut().lazy_details.clear();
+            }
+        }
+        error
+    }
+}
+
+impl Default for ErrorResponseConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Controls the HTTP headers describing an `Error`, set by the HTTP responders.
+///
+/// By default, the `X-Error-Class` and `X-Error-Code` headers are set, as well as the
+/// `Retry-After` header when the error details contain a `retry_after` value (in seconds or as
+/// an HTTP date).
+///
+/// # Example
+/// ```
+/// use cdumay_core::{ErrorBuilder, ErrorHeadersConfig};
+/// use serde_value::Value;
+///
+/// let config = ErrorHeadersConfig::new().with_class_header("X-Problem-Class").without_code_header();
+///
+/// let err = ErrorBuilder::new(cdumay_core::kinds::http::TooManyRequests, "QuotaExceeded")
+///     .with_detail("retry_after", Value::U64(30))
+///     .build();
+/// assert_eq!(
+///     config.headers(&err),
+///     vec![
+///         ("X-Problem-Class".to_string(), "Client::TooManyRequests::QuotaExceeded".to_string()),
+///         ("Retry-After".to_string(), "30".to_string()),
+///     ]
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ErrorHeadersConfig {
+    class_header: Option<String>,
+    code_header: Option<String>,
+    retry_after: bool,
+}
+
+impl ErrorHeadersConfig {
+    /// Creates a configuration setting all the headers.
+    pub fn new() -> Self {
+        Self {
+            class_header: Some("X-Error-Class".to_string()),
+            code_header: Some("X-Error-Code".to_string()),
+            retry_after: true,
+        }
+    }
+
+    /// Creates a configuration setting no header.
+    pub fn disabled() -> Self {
+        Self {
+            class_header: None,
+            code_header: None,
+            retry_after: false,
+        }
+    }
+
+    /// Sets the name of the header carrying the error class.
+    pub fn with_class_header<H: Into<String>>(mut self, name: H) -> Self {
+        self.class_header = Some(name.into());
+        self
+    }
+
+    /// Disables the header carrying the error class.
+    pub fn without_class_header(mut self) -> Self {
+        self.class_header = None;
+        self
+    }
+
+    /// Sets the name of the header carrying the error code.
+    pub fn with_code_header<H: Into<String>>(mut self, name: H) -> Self {
+        self.code_header = Some(name.into());
+        self
+    }
+
+    /// Disables the header carrying the error code.
+    pub fn without_code_header(mut self) -> Self {
+        self.code_header = None;
+        self
+    }
+
+    /// Enables or disables the `Retry-After` header.
+    pub fn with_retry_after(mut self, enabled: bool) -> Self {
+        self.retry_after = enabled;
+        self
+    }
+
+    /// Returns the global configuration used by the HTTP responders.
+    pub fn global() -> Self {
+        HEADERS_CONFIG.read().unwrap_or_else(PoisonError::into_inner).clone().unwrap_or_default()
+    }
+
+    /// Replaces the global configuration used by the HTTP responders.
+    pub fn set_global(self) {
+        *HEADERS_CONFIG.write().unwrap_or_else(PoisonError::into_inner) = Some(self);
+    }
+
+    /// Returns the headers describing the given error, as `(name, value)` pairs.
+    pub fn headers(&self, error: &crate::Error) -> Vec<(String, String)> {
+        let mut headers = Vec::new();
+        if let Some(name) = &self.class_header {
+            headers.push((name.clone(), error.class.to_string()));
+        }
+        if let Some(name) = &self.code_header {
+            headers.push((name.clone(), error.code.to_string()));
+        }
+        let retry_after = match error.resolved_details().get("retry_after") {
+            Some(serde_value::Value::String(value)) if is_retry_after(value) => Some(value.clone()),
+            Some(serde_value::Value::String(_)) => None,
+            Some(value) => value.clone().deserialize_into::<u64>().ok().map(|seconds| seconds.to_string()),
+            None => None,
+        };
+        if let (true, Some(retry_after)) = (self.retry_after, retry_after) {
+            headers.push(("Retry-After".to_string(), retry_after));
+        }
+        headers
+    }
+}
+
+/// Returns whether a string is a valid `Retry-After` value, i.e. a number of seconds or an
+/// HTTP date in the IMF-fixdate format (e.g. `Sun, 06 Nov 1994 08:49:37 GMT`).
+fn is_retry_after(value: &str) -> bool {
+    const DAYS: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+    const MONTHS: [&str; 12] = ["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"];
+    let digits = |part: &str| !part.is_empty() && part.bytes().all(|byte| byte.is_ascii_digit());
+    if digits(value) {
+        return true;
+    }
+    let parts: Vec<&str> = value.split(' ').collect();
+    let [day, date, month, year, time, "GMT"] = parts[..] else {
+        return false;
+    };
+    let time: Vec<&str> = time.split(':').collect();
+    day.strip_suffix(',').is_some_and(|day| DAYS.contains(&day))
+        && date.len() == 2
+        && digits(date)
+        && MONTHS.contains(&month)
+        && year.len() == 4
+        && digits(year)
+        && time.len() == 3
+        && time.iter().all(|part| part.len() == 2 && digits(part))
+}
+
+impl Default for ErrorHeadersConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
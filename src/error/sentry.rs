@@ -0,0 +1,76 @@
+use sentry_core::protocol::{Event, Exception, Level, Value};
+
+/// Converts an `Error` into a Sentry event.
+///
+/// - the class is used as fingerprint and exception type,
+/// - the code and class are added as tags,
+/// - the details, scrubbed using the global [`Redactor`](crate::Redactor), are added as extra data,
+///   as well as the context frames (if any) under the `context` key,
+/// - the level is **error** for codes 500 and above, **warning** for codes between 400 and 499
+///   and **info** otherwise.
+///
+/// # Example
+/// ```
+/// use cdumay_core::{ErrorBuilder, ErrorKind};
+/// use sentry_core::protocol::{Event, Level};
+///
+/// let err = ErrorBuilder::new(ErrorKind("NotFound", 404, "Not Found"), "UserNotFound").build();
+/// let event: Event = (&err).into();
+/// assert_eq!(event.level, Level::Warning);
+/// assert_eq!(event.tags.get("code").map(String::as_str), Some("404"));
+/// ```
+impl From<&crate::Error> for Event<'static> {
+    fn from(error: &crate::Error) -> Self {
+        let level = match error.code() {
+            500.. => Level::Error,
+            400..=499 => Level::Warning,
+            _ => Level::Info,
+        };
+        let mut extra: std::collections::BTreeMap<String, Value> = error
+            .redacted_details()
+            .into_iter()
+            .filter_map(|(key, value)| value.deserialize_into::<Value>().ok().map(|value| (key, value)))
+            .collect();
+        if !error.context().is_empty() {
+            extra.insert("context".to_string(), error.context().iter().cloned().map(Value::String).collect());
+        }
+        let mut tags = std::collections::BTreeMap::new();
+        tags.insert("code".to_string(), error.code().to_string());
+        tags.insert("class".to_string(), error.class());
+        Event {
+            level,
+            fingerprint: vec![error.class().into()].into(),
+            message: Some(error.message()),
+            exception: vec![Exception {
+                ty: error.class(),
+                value: Some(error.message()),
+                ..Default::default()
+            }]
+            .into(),
+            tags,
+            extra,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<crate::Error> for Event<'static> {
+    fn from(error: crate::Error) -> Self {
+        (&error).into()
+    }
+}
+
+impl crate::Error {
+    /// Sends the error as a Sentry event to the current hub and returns the event ID.
+    ///
+    /// # Example
+    /// ```
+    /// use cdumay_core::ErrorBuilder;
+    ///
+    /// let err = ErrorBuilder::default().build();
+    /// let _event_id = err.capture();
+    /// ```
+    pub fn capture(&self) -> sentry_core::types::Uuid {
+        sentry_core::capture_event(self.into())
+    }
+}
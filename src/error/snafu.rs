@@ -0,0 +1,96 @@
+use serde_value::Value;
+use std::collections::BTreeMap;
+
+impl crate::Error {
+    /// Converts a snafu error into an `Error` of the given kind, to migrate a snafu codebase gradually.
+    ///
+    /// The error is named after its serialized variant (or its type for struct errors), its display is used as
+    /// message, and the fields of its context selector are stored in the details using its `Serialize`
+    /// implementation, the source being usually skipped with `#[serde(skip)]`. The chain of causes,
+    /// cleaned from the messages repeated by snafu, is stored under the `origin_chain` key and the
+    /// snafu error is kept as source.
+    ///
+    /// # Example
+    /// ```
+    /// use cdumay_core::Error;
+    /// use cdumay_core::kinds::http::{InternalServerError, NotFound};
+    /// use snafu::{ResultExt, Snafu};
+    ///
+    /// #[derive(Debug, Snafu, serde::Serialize)]
+    /// enum ConfigError {
+    ///     #[snafu(display("Cannot read {path}"))]
+    ///     ReadConfig {
+    ///         path: String,
+    ///         #[serde(skip)]
+    ///         source: std::io::Error,
+    ///     },
+    ///     #[snafu(display("Unknown profile {name}"))]
+    ///     UnknownProfile { name: String },
+    /// }
+    ///
+    /// impl From<ConfigError> for Error {
+    ///     fn from(error: ConfigError) -> Self {
+    ///         let kind = match error {
+    ///             ConfigError::UnknownProfile { .. } => NotFound,
+    ///             _ => InternalServerError,
+    ///         };
+    ///         Error::from_snafu(kind, error)
+    ///     }
+    /// }
+    ///
+    /// fn load(path: &str) -> cdumay_core::Result<String> {
+    ///     Ok(std::fs::read_to_string(path).context(ReadConfigSnafu { path })?)
+    /// }
+    ///
+    /// let err = load("/nonexistent/app.toml").unwrap_err();
+    /// assert_eq!(err.class(), "Server::InternalServerError::ReadConfig");
+    /// assert_eq!(err.message(), "Cannot read /nonexistent/app.toml");
+    /// assert_eq!(err.details()["path"], serde_value::Value::String("/nonexistent/app.toml".to_string()));
+    /// ```
+    pub fn from_snafu<K, E>(kind: K, error: E) -> Self
+    where
+        K: crate::Kind,
+        E: std::error::Error + serde::Serialize + Send + Sync + 'static,
+    {
+        let (name, details) = match serde_value::to_value(&error) {
+            // Unit variants are serialized as their name
+            Ok(Value::String(variant)) => (variant, BTreeMap::new()),
+            // Other variants are serialized as a map holding their fields under the variant name, which
+            // unlike field names is in upper camel case
+            Ok(Value::Map(mut map)) => match map.pop_first() {
+                Some((Value::String(variant), value)) if map.is_empty() && variant.starts_with(|c: char| c.is_ascii_uppercase()) => match value {
+                    Value::Map(fields) => (variant, fields),
+                    _ => (variant, BTreeMap::new()),
+                },
+                Some((key, value)) => {
+                    map.insert(key, value);
+                    (type_name::<E>(), map)
+                }
+                None => (type_name::<E>(), map),
+            },
+            _ => (type_name::<E>(), BTreeMap::new()),
+        };
+        let mut details = details
+            .into_iter()
+            .filter_map(|(key, value)| match key {
+                Value::String(key) => Some((key, value)),
+                _ => None,
+            })
+            .collect::<BTreeMap<_, _>>();
+
+        let chain = ::snafu::CleanedErrorText::new(&error).map(|(_, text, _)| Value::String(text)).collect();
+        details.insert("origin_chain".to_string(), Value::Seq(chain));
+        crate::ErrorBuilder::new(kind, &name)
+            .with_message(error.to_string())
+            .with_details(details)
+            .with_source(error)
+            .build()
+    }
+}
+
+/// Returns the name of the type, without its path and generic parameters.
+fn type_name<T>() -> String {
+    let name = std::any::type_name::<T>();
+    let name = name.split('<').next().unwrap_or(name);
+    name.rsplit("::").next().unwrap_or(name).to_string()
+}
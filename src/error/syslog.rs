@@ -0,0 +1,152 @@
+/// Severity levels of [RFC 5424](https://www.rfc-editor.org/rfc/rfc5424#section-6.2.1).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum SyslogSeverity {
+    /// System is unusable
+    Emergency = 0,
+    /// Action must be taken immediately
+    Alert = 1,
+    /// Critical conditions
+    Critical = 2,
+    /// Error conditions
+    Error = 3,
+    /// Warning conditions
+    Warning = 4,
+    /// Normal but significant condition
+    Notice = 5,
+    /// Informational messages
+    Informational = 6,
+    /// Debug-level messages
+    Debug = 7,
+}
+
+impl SyslogSeverity {
+    /// Returns the numerical code of the severity.
+    pub fn code(self) -> u8 {
+        self as u8
+    }
+
+    /// Returns the PRI value of a message with this severity, sent from the given facility.
+    ///
+    /// # Example
+    /// ```
+    /// use cdumay_core::SyslogSeverity;
+    ///
+    /// // local0
+    /// assert_eq!(SyslogSeverity::Error.priority(16), 131);
+    /// ```
+    pub fn priority(self, facility: u8) -> u8 {
+        facility * 8 + self.code()
+    }
+
+    /// Returns the keyword of the severity, as used by `syslog(3)`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Emergency => "emerg",
+            Self::Alert => "alert",
+            Self::Critical => "crit",
+            Self::Error => "err",
+            Self::Warning => "warning",
+            Self::Notice => "notice",
+            Self::Informational => "info",
+            Self::Debug => "debug",
+        }
+    }
+}
+
+impl std::fmt::Display for SyslogSeverity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Returns a valid SD-NAME: printable ASCII without `=`, space, `]` and `"`, up to 32 characters.
+fn sd_name(name: &str) -> String {
+    name.chars()
+        .map(|c| if c.is_ascii_graphic() && !matches!(c, '=' | ']' | '"') { c } else { '_' })
+        .take(32)
+        .collect()
+}
+
+/// Escapes a PARAM-VALUE: `"`, `\` and `]` are preceded by a backslash.
+fn sd_value(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        if matches!(c, '"' | '\\' | ']') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Formats a detail value as a PARAM-VALUE, strings being sent without quotes.
+fn detail_value(value: &serde_value::Value) -> String {
+    match value {
+        serde_value::Value::String(value) => value.clone(),
+        serde_value::Value::Bool(value) => value.to_string(),
+        serde_value::Value::U8(value) => value.to_string(),
+        serde_value::Value::U16(value) => value.to_string(),
+        serde_value::Value::U32(value) => value.to_string(),
+        serde_value::Value::U64(value) => value.to_string(),
+        serde_value::Value::I8(value) => value.to_string(),
+        serde_value::Value::I16(value) => value.to_string(),
+        serde_value::Value::I32(value) => value.to_string(),
+        serde_value::Value::I64(value) => value.to_string(),
+        serde_value::Value::F32(value) => value.to_string(),
+        serde_value::Value::F64(value) => value.to_string(),
+        other => format!("{:?}", other),
+    }
+}
+
+impl crate::Error {
+    /// Returns the syslog severity of the error: **error** for codes 500 and above, **warning**
+    /// for codes between 400 and 499 and **informational** otherwise.
+    ///
+    /// # Example
+    /// ```
+    /// use cdumay_core::{ErrorBuilder, SyslogSeverity};
+    /// use cdumay_core::kinds::http::NotFound;
+    ///
+    /// assert_eq!(ErrorBuilder::new(NotFound, "UnknownUser").build().syslog_severity(), SyslogSeverity::Warning);
+    /// ```
+    pub fn syslog_severity(&self) -> SyslogSeverity {
+        match self.code {
+            500.. => SyslogSeverity::Error,
+            400..=499 => SyslogSeverity::Warning,
+            _ => SyslogSeverity::Informational,
+        }
+    }
+
+    /// Returns the error as an RFC 5424 STRUCTURED-DATA element identified by `sd_id`.
+    ///
+    /// The element holds the `class`, the `code`, the `request_id` when known, and the details
+    /// scrubbed using the global [`Redactor`](crate::Redactor). Names are sanitized and values
+    /// escaped as required by the RFC.
+    ///
+    /// # Example
+    /// ```
+    /// use serde_value::Value;
+    /// use cdumay_core::ErrorBuilder;
+    /// use cdumay_core::kinds::http::NotFound;
+    ///
+    /// let err = ErrorBuilder::new(NotFound, "UnknownUser").with_detail("user", Value::String("john".to_string())).build();
+    /// assert_eq!(
+    ///     err.syslog_structured_data("error@32473"),
+    ///     r#"[error@32473 class="Client::NotFound::UnknownUser" code="404" user="john"]"#
+    /// );
+    /// ```
+    pub fn syslog_structured_data(&self, sd_id: &str) -> String {
+        let mut params = vec![("class".to_string(), self.class.to_string()), ("code".to_string(), self.code.to_string())];
+        if let Some(request_id) = self.request_id() {
+            params.push(("request_id".to_string(), request_id.to_string()));
+        }
+        params.extend(self.redacted_details().iter().map(|(key, value)| (sd_name(key), detail_value(value))));
+
+        let mut data = format!("[{}", sd_name(sd_id));
+        for (name, value) in params {
+            data.push_str(&format!(" {}=\"{}\"", name, sd_value(&value)));
+        }
+        data.push(']');
+        data
+    }
+}
@@ -0,0 +1,81 @@
+use std::collections::BTreeMap;
+
+/// A preset of kind, name and base details from which errors are instantiated.
+///
+/// Templates centralize per-domain defaults. They can be declared as constants using
+/// [`ErrorTemplate::with_static_details`], or built at runtime (e.g. stored in the application
+/// state) using [`ErrorTemplate::with_detail`].
+///
+/// # Example
+/// ```
+/// use serde_value::Value;
+/// use cdumay_core::ErrorTemplate;
+/// use cdumay_core::kinds::http::PaymentRequired;
+///
+/// const PAYMENT_DECLINED: ErrorTemplate = ErrorTemplate::new(PaymentRequired, "PaymentDeclined")
+///     .with_static_details(&[("service", "billing")]);
+///
+/// let err = PAYMENT_DECLINED.instantiate().with_message("Card expired".to_string()).build();
+/// assert_eq!(err.class(), "Client::PaymentRequired::PaymentDeclined");
+/// assert_eq!(err.message(), "Card expired");
+/// assert_eq!(err.details().get("service"), Some(&Value::String("billing".to_string())));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ErrorTemplate {
+    kind: crate::ErrorKind,
+    name: &'static str,
+    static_details: &'static [(&'static str, &'static str)],
+    details: BTreeMap<String, serde_value::Value>,
+}
+
+impl ErrorTemplate {
+    /// Creates a template of the given kind and name, without details.
+    pub const fn new(kind: crate::ErrorKind, name: &'static str) -> Self {
+        Self {
+            kind,
+            name,
+            static_details: &[],
+            details: BTreeMap::new(),
+        }
+    }
+
+    /// Sets string details, usable in constants.
+    pub const fn with_static_details(mut self, details: &'static [(&'static str, &'static str)]) -> Self {
+        self.static_details = details;
+        self
+    }
+
+    /// Adds a detail, taking precedence over the static details with the same key.
+    pub fn with_detail<K: Into<String>>(mut self, key: K, value: serde_value::Value) -> Self {
+        self.details.insert(key.into(), value);
+        self
+    }
+
+    /// Returns the kind of the instantiated errors.
+    pub fn kind(&self) -> &crate::ErrorKind {
+        &self.kind
+    }
+
+    /// Returns the name of the instantiated errors.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the base details of the instantiated errors.
+    pub fn details(&self) -> BTreeMap<String, serde_value::Value> {
+        let mut details: BTreeMap<String, serde_value::Value> = self
+            .static_details
+            .iter()
+            .map(|(key, value)| (key.to_string(), serde_value::Value::String(value.to_string())))
+            .collect();
+        details.extend(self.details.clone());
+        details
+    }
+
+    /// Returns an [`ErrorBuilder`](crate::ErrorBuilder) pre-populated with the template.
+    ///
+    /// Further details can be added using [`ErrorBuilder::with_detail`](crate::ErrorBuilder::with_detail).
+    pub fn instantiate(&self) -> crate::ErrorBuilder {
+        crate::ErrorBuilder::new(self.kind.clone(), self.name).with_details(self.details())
+    }
+}
@@ -0,0 +1,187 @@
+use std::convert::Infallible;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// Layer converting the errors of the wrapped service into structured HTTP responses.
+///
+/// The errors must implement `Into<Error>`. They are rendered according to the `Accept` header of
+/// the request (see [`ErrorFormat`](crate::ErrorFormat)) using [`Error::to_http_response`](crate::Error::to_http_response),
+/// so the resulting service never fails. The response body type must implement `From<String>`,
+/// which is the case of the bodies of axum, hyper (`Full`) and tonic.
+///
+/// # Example
+/// ```
+/// use std::task::{Context, Poll};
+/// use cdumay_core::{Error, ErrorBuilder, ErrorMappingLayer};
+/// use tower_layer::Layer;
+/// use tower_service::Service;
+///
+/// struct Handler;
+///
+/// impl Service<http::Request<String>> for Handler {
+///     type Response = http::Response<String>;
+///     type Error = Error;
+///     type Future = std::future::Ready<Result<Self::Response, Self::Error>>;
+///
+///     fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+///         Poll::Ready(Ok(()))
+///     }
+///
+///     fn call(&mut self, _: http::Request<String>) -> Self::Future {
+///         std::future::ready(Err(ErrorBuilder::new(cdumay_core::kinds::http::NotFound, "UnknownUser").build()))
+///     }
+/// }
+///
+/// # let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+/// # runtime.block_on(async {
+/// let mut service = ErrorMappingLayer.layer(Handler);
+/// let response = service.call(http::Request::new(String::new())).await.unwrap();
+/// assert_eq!(response.status(), http::StatusCode::NOT_FOUND);
+/// # });
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ErrorMappingLayer;
+
+impl<S> tower_layer::Layer<S> for ErrorMappingLayer {
+    type Service = ErrorMapping<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        ErrorMapping { inner, ready_error: None }
+    }
+}
+
+/// Service converting the errors of the wrapped service into structured HTTP responses, see [`ErrorMappingLayer`].
+#[derive(Debug, Clone)]
+pub struct ErrorMapping<S> {
+    inner: S,
+    /// Error returned by the wrapped service when polled for readiness, rendered by the next call.
+    ready_error: Option<crate::Error>,
+}
+
+impl<S> ErrorMapping<S> {
+    /// Wraps the given service.
+    pub fn new(inner: S) -> Self {
+        Self { inner, ready_error: None }
+    }
+
+    /// Returns the wrapped service.
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
+impl<S, ReqBody, ResBody> tower_service::Service<http::Request<ReqBody>> for ErrorMapping<S>
+where
+    S: tower_service::Service<http::Request<ReqBody>, Response = http::Response<ResBody>>,
+    S::Error: Into<crate::Error>,
+    ResBody: From<String>,
+{
+    type Response = http::Response<ResBody>;
+    type Error = Infallible;
+    type Future = ErrorMappingFuture<S::Future>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        if self.ready_error.is_some() {
+            return Poll::Ready(Ok(()));
+        }
+        self.inner.poll_ready(cx).map(|ready| {
+            if let Err(error) = ready {
+                self.ready_error = Some(error.into());
+            }
+            Ok(())
+        })
+    }
+
+    fn call(&mut self, request: http::Request<ReqBody>) -> Self::Future {
+        let format = request
+            .headers()
+            .get(http::header::ACCEPT)
+            .and_then(|accept| accept.to_str().ok())
+            .map(crate::ErrorFormat::from_accept)
+            .unwrap_or_default();
+        match self.ready_error.take() {
+            Some(error) => ErrorMappingFuture {
+                inner: None,
+                error: Some(error),
+                format,
+            },
+            None => ErrorMappingFuture {
+                inner: Some(Box::pin(self.inner.call(request))),
+                error: None,
+                format,
+            },
+        }
+    }
+}
+
+/// Future of [`ErrorMapping`], rendering the error of the wrapped service.
+pub struct ErrorMappingFuture<F> {
+    inner: Option<Pin<Box<F>>>,
+    error: Option<crate::Error>,
+    format: crate::ErrorFormat,
+}
+
+impl<F, ResBody, E> Future for ErrorMappingFuture<F>
+where
+    F: Future<Output = Result<http::Response<ResBody>, E>>,
+    E: Into<crate::Error>,
+    ResBody: From<String>,
+{
+    type Output = Result<http::Response<ResBody>, Infallible>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if let Some(error) = self.error.take() {
+            return Poll::Ready(Ok(error.to_http_response(self.format)));
+        }
+        let format = self.format;
+        match self.inner.as_mut() {
+            Some(inner) => inner
+                .as_mut()
+                .poll(cx)
+                .map(|result| Ok(result.unwrap_or_else(|error| error.into().to_http_response(format)))),
+            None => panic!("ErrorMappingFuture polled after completion"),
+        }
+    }
+}
+
+impl crate::Error {
+    /// Renders the error as an HTTP response in the given format.
+    ///
+    /// The error is first [finalized](crate::Error::finalize). The response uses the
+    /// [status code](crate::Error::status_code) of the error and its body is redacted using the
+    /// global [`ErrorResponseConfig`](crate::ErrorResponseConfig). The headers describing the error
+    /// are set according to the global [`ErrorHeadersConfig`](crate::ErrorHeadersConfig).
+    ///
+    /// # Example
+    /// ```
+    /// use cdumay_core::{ErrorBuilder, ErrorFormat};
+    ///
+    /// let err = ErrorBuilder::new(cdumay_core::kinds::http::NotFound, "UnknownUser").build();
+    /// let response: http::Response<String> = err.to_http_response(ErrorFormat::Text);
+    /// assert_eq!(response.status(), http::StatusCode::NOT_FOUND);
+    /// assert_eq!(response.body(), "Client::NotFound::UnknownUser (404) - Not Found");
+    /// ```
+    pub fn to_http_response<B: From<String>>(&self, format: crate::ErrorFormat) -> http::Response<B> {
+        let error = self.clone().finalize();
+        let exposed = crate::ErrorResponseConfig::global().apply(&error);
+        let body = match format {
+            crate::ErrorFormat::Json => serde_json::to_string(&exposed).ok(),
+            crate::ErrorFormat::ProblemJson => serde_json::to_string(&crate::ProblemDetails::from(&exposed)).ok(),
+            crate::ErrorFormat::Text => Some(exposed.to_string()),
+            #[cfg(feature = "xml")]
+            crate::ErrorFormat::Xml => quick_xml::se::to_string_with_root("error", &exposed).ok(),
+        };
+        let mut response = http::Response::new(B::from(body.unwrap_or_default()));
+        *response.status_mut() = error.status_code();
+        response
+            .headers_mut()
+            .insert(http::header::CONTENT_TYPE, http::HeaderValue::from_static(format.content_type()));
+        for (name, value) in crate::ErrorHeadersConfig::global().headers(&error) {
+            if let (Ok(name), Ok(value)) = (http::HeaderName::try_from(name), http::HeaderValue::try_from(value)) {
+                response.headers_mut().insert(name, value);
+            }
+        }
+        response
+    }
+}
@@ -0,0 +1,28 @@
+/// Emits `Error` values as structured `tracing` events.
+///
+/// The event level depends on the error code:
+/// - **ERROR** for codes 500 and above (server-side failures),
+/// - **WARN** for codes between 400 and 499 (client-side failures),
+/// - **INFO** for any other code.
+///
+/// The class, code, message and details are recorded as event fields, the details being scrubbed
+/// using the global [`Redactor`](crate::Redactor).
+impl crate::Error {
+    /// Records the error as a `tracing` event at a severity-appropriate level.
+    ///
+    /// # Example
+    /// ```
+    /// use cdumay_core::{ErrorBuilder, ErrorKind};
+    ///
+    /// let err = ErrorBuilder::new(ErrorKind("NotFound", 404, "Not Found"), "UserNotFound").build();
+    /// err.trace();
+    /// ```
+    pub fn trace(&self) {
+        let (class, code, message, details) = (self.class_ref(), self.code(), self.message_ref(), self.redacted_details());
+        match code {
+            500.. => ::tracing::error!(class, code, message, details = ?details, "{}", self),
+            400..=499 => ::tracing::warn!(class, code, message, details = ?details, "{}", self),
+            _ => ::tracing::info!(class, code, message, details = ?details, "{}", self),
+        }
+    }
+}
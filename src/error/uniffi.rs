@@ -0,0 +1,88 @@
+use std::collections::HashMap;
+
+/// Error exposed to the languages supported by UniFFI, e.g. as `CdumayException.Error` in Kotlin
+/// or `CdumayError.Error` in Swift.
+///
+/// Details are JSON-encoded, as UniFFI records cannot hold arbitrary values.
+///
+/// # Example
+///
+/// Not compiled, as the scaffolding of the example crate would clash with the one of `cdumay_core`.
+/// ```ignore
+/// use cdumay_core::{CdumayError, ErrorBuilder};
+/// use cdumay_core::kinds::http::NotFound;
+///
+/// uniffi::setup_scaffolding!();
+///
+/// #[uniffi::export]
+/// fn get_user(id: u64) -> Result<String, CdumayError> {
+///     Err(ErrorBuilder::new(NotFound, "UnknownUser").with_detail("id", serde_value::Value::U64(id)).build().into())
+/// }
+///
+/// fn main() {
+///     let CdumayError::Error { code, details, .. } = get_user(42).unwrap_err();
+///     assert_eq!(code, 404);
+///     assert_eq!(details["id"], "42");
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, uniffi::Error)]
+pub enum CdumayError {
+    /// An error with its class, code, message and details
+    Error {
+        /// Error class
+        error_class: String,
+        /// Error code
+        code: u16,
+        /// Human-readable message
+        message: String,
+        /// Details, encoded in JSON
+        details: HashMap<String, String>,
+    },
+}
+
+impl std::fmt::Display for CdumayError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Self::Error {
+            error_class, code, message, ..
+        } = self;
+        write!(f, "{} ({}) - {}", error_class, code, message)
+    }
+}
+
+impl std::error::Error for CdumayError {}
+
+impl From<crate::Error> for CdumayError {
+    fn from(error: crate::Error) -> Self {
+        let details = error
+            .resolved_details()
+            .iter()
+            .map(|(key, value)| (key.clone(), serde_json::to_string(value).unwrap_or_else(|_| format!("{:?}", value))))
+            .collect();
+        Self::Error {
+            error_class: error.class.to_string(),
+            code: error.code,
+            message: error.message.to_string(),
+            details,
+        }
+    }
+}
+
+/// Converts an error received from foreign code, details which are not valid JSON being kept as strings.
+impl From<CdumayError> for crate::Error {
+    fn from(error: CdumayError) -> Self {
+        let CdumayError::Error {
+            error_class,
+            code,
+            message,
+            details,
+        } = error;
+        let details = details
+            .into_iter()
+            .map(|(key, value)| {
+                let value = serde_json::from_str(&value).unwrap_or(serde_value::Value::String(value));
+                (key, value)
+            })
+            .collect();
+        crate::Error::new(code, error_class, message, details)
+    }
+}
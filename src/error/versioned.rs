@@ -0,0 +1,80 @@
+impl crate::Error {
+    /// Version of the layout written by [`Error::to_versioned_json`](crate::Error::to_versioned_json).
+    ///
+    /// - `1`: unversioned payloads, in which the `code` may be missing
+    /// - `2`: the `code` is always present
+    pub const SCHEMA_VERSION: u32 = 2;
+
+    /// Serializes the error in JSON, with its `schema_version`, its `code` and its `kind` (if any).
+    ///
+    /// Use [`Error::from_versioned_json`] to parse it, including on services built against
+    /// another version of this crate.
+    ///
+    /// # Example
+    /// ```
+    /// use cdumay_core::{Error, ErrorBuilder};
+    /// use cdumay_core::kinds::http::NotFound;
+    ///
+    /// let err = ErrorBuilder::new(NotFound, "UnknownUser").build();
+    /// let json = err.to_versioned_json().unwrap();
+    /// assert!(json.contains(r#""schema_version":2"#));
+    /// assert_eq!(Error::from_versioned_json(&json).unwrap(), err);
+    /// ```
+    pub fn to_versioned_json(&self) -> Result<String, serde_json::Error> {
+        let mut value = serde_json::to_value(self)?;
+        if let Some(object) = value.as_object_mut() {
+            object.insert("code".to_string(), self.code.into());
+            if let Some(kind) = &self.metadata.kind {
+                object.insert("kind".to_string(), serde_json::to_value(kind)?);
+            }
+            object.insert("schema_version".to_string(), Self::SCHEMA_VERSION.into());
+        }
+        serde_json::to_string(&value)
+    }
+
+    /// Parses an error serialized in JSON, upgrading payloads written using an older schema.
+    ///
+    /// Payloads without `schema_version` are considered as version `1`: a missing `code` is
+    /// taken from the `kind` when known. Payloads written using a newer schema are rejected.
+    ///
+    /// # Example
+    /// ```
+    /// use cdumay_core::Error;
+    ///
+    /// let legacy = r#"{"class":"Client::NotFound::UnknownUser","message":"Not Found","details":{},"kind":{"name":"NotFound","code":404,"description":"Not Found"}}"#;
+    /// let err = Error::from_versioned_json(legacy).unwrap();
+    /// assert_eq!(err.code(), 404);
+    /// ```
+    pub fn from_versioned_json(json: &str) -> Result<Self, serde_json::Error> {
+        let mut value: serde_json::Value = serde_json::from_str(json)?;
+        let object = value
+            .as_object_mut()
+            .ok_or_else(|| serde::de::Error::custom("expected an error object"))?;
+        let version = match object.remove("schema_version") {
+            None => 1,
+            Some(version) => version
+                .as_u64()
+                .and_then(|version| u32::try_from(version).ok())
+                .ok_or_else(|| serde::de::Error::custom("invalid schema_version"))?,
+        };
+        if version > Self::SCHEMA_VERSION {
+            return Err(serde::de::Error::custom(format!("unsupported error schema version {version}")));
+        }
+        if version < 2
+            && !object.contains_key("code")
+            && let Some(code) = object.get("kind").and_then(|kind| kind.get("code")).cloned()
+        {
+            object.insert("code".to_string(), code);
+        }
+        let code = object
+            .get("code")
+            .and_then(serde_json::Value::as_u64)
+            .and_then(|code| u16::try_from(code).ok());
+        let mut error: Self = serde_json::from_value(value)?;
+        // The `code` is skipped by the serde representation unless the `wire` feature is enabled
+        if let Some(code) = code {
+            error.code = code;
+        }
+        Ok(error)
+    }
+}
@@ -0,0 +1,49 @@
+/// Allows an `Error` to be used as a warp rejection (e.g. `warp::reject::custom(error)`).
+impl ::warp::reject::Reject for crate::Error {}
+
+/// Implements the `warp::Reply` trait for `Error`.
+///
+/// The error is first [finalized](crate::Error::finalize). The reply uses the error code as HTTP
+/// status code (defaulting to `500 Internal Server Error` if invalid) and the error as JSON body,
+/// redacted using the global [`ErrorResponseConfig`](crate::ErrorResponseConfig). The headers
+/// describing the error are set according to the global [`ErrorHeadersConfig`](crate::ErrorHeadersConfig).
+///
+/// As warp implements `Reply` for any `Result<T, E>` where both `T` and `E` are replies,
+/// handlers can directly return a `cdumay_core::Result<T>`.
+impl ::warp::Reply for crate::Error {
+    fn into_response(self) -> ::warp::reply::Response {
+        let error = self.finalize();
+        let status = ::warp::http::StatusCode::from_u16(error.code()).unwrap_or(::warp::http::StatusCode::INTERNAL_SERVER_ERROR);
+        let body = crate::ErrorResponseConfig::global().apply(&error);
+        let mut response = ::warp::reply::with_status(::warp::reply::json(&body), status).into_response();
+        for (name, value) in crate::ErrorHeadersConfig::global().headers(&error) {
+            if let (Ok(name), Ok(value)) = (
+                ::warp::http::header::HeaderName::try_from(name),
+                ::warp::http::header::HeaderValue::try_from(value),
+            ) {
+                response.headers_mut().insert(name, value);
+            }
+        }
+        response
+    }
+}
+
+/// Recovery filter rendering `Error` rejections as JSON replies.
+///
+/// Other rejections are passed through unchanged.
+///
+/// # Example
+/// ```
+/// use cdumay_core::{handle_cdumay_rejection, ErrorBuilder};
+/// use warp::Filter;
+///
+/// let route = warp::path("fail")
+///     .and_then(|| async { Err::<String, _>(warp::reject::custom(ErrorBuilder::default().build())) })
+///     .recover(handle_cdumay_rejection);
+/// ```
+pub async fn handle_cdumay_rejection(rejection: ::warp::Rejection) -> Result<::warp::reply::Response, ::warp::Rejection> {
+    match rejection.find::<crate::Error>() {
+        Some(error) => Ok(::warp::Reply::into_response(error.clone())),
+        None => Err(rejection),
+    }
+}
@@ -0,0 +1,63 @@
+use js_sys::Reflect;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use wasm_bindgen::{JsCast, JsValue};
+
+/// Returns the string property of a JS value, if any.
+fn string_property(value: &JsValue, key: &str) -> Option<String> {
+    Reflect::get(value, &JsValue::from_str(key))
+        .ok()
+        .and_then(|property| property.as_string())
+}
+
+/// Converts an `Error` into a JS `Error` whose `name` is the error class, carrying the `class`,
+/// `code`, `message` and `details` properties.
+///
+/// The details are converted into plain JS objects.
+impl From<crate::Error> for JsValue {
+    fn from(error: crate::Error) -> Self {
+        let object = js_sys::Error::new(&error.message);
+        object.set_name(&error.class);
+        let details = error
+            .resolved_details()
+            .serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+            .unwrap_or_else(|_| js_sys::Object::new().into());
+        for (key, value) in [
+            ("class", JsValue::from_str(&error.class)),
+            ("code", JsValue::from(error.code)),
+            ("details", details),
+        ] {
+            let _ = Reflect::set(&object, &JsValue::from_str(key), &value);
+        }
+        object.into()
+    }
+}
+
+/// Converts a JS value thrown or rejected by JS code.
+///
+/// - objects carrying a string `class` and a numeric `code`, such as the ones converted from an
+///   `Error`, give back the original error,
+/// - other JS `Error` objects give a `500 Internal Server Error` named after their `name`,
+///   with their message,
+/// - any other value gives a `500 Internal Server Error` named `UnknownError`, with its string
+///   representation as message.
+impl From<JsValue> for crate::Error {
+    fn from(value: JsValue) -> Self {
+        let code = Reflect::get(&value, &JsValue::from_str("code")).ok().and_then(|code| code.as_f64());
+        if let (Some(class), Some(code)) = (string_property(&value, "class"), code) {
+            let details = Reflect::get(&value, &JsValue::from_str("details"))
+                .ok()
+                .and_then(|details| serde_wasm_bindgen::from_value::<BTreeMap<String, serde_value::Value>>(details).ok())
+                .unwrap_or_default();
+            return crate::Error::new(code as u16, class, string_property(&value, "message").unwrap_or_default(), details);
+        }
+        match value.dyn_ref::<js_sys::Error>() {
+            Some(error) => crate::ErrorBuilder::new(crate::kinds::http::InternalServerError, &String::from(error.name()))
+                .with_message(String::from(error.message()))
+                .build(),
+            None => crate::ErrorBuilder::default()
+                .with_message(value.as_string().unwrap_or_else(|| format!("{:?}", value)))
+                .build(),
+        }
+    }
+}
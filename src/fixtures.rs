@@ -0,0 +1,168 @@
+//! Deterministic sample errors, for contract tests and API documentation examples.
+//!
+//! Each [standard HTTP kind](crate::kinds::http) has a sample error with a realistic name and
+//! detail. The samples are returned as [`ErrorBuilder`](crate::ErrorBuilder)s, so that they can be
+//! completed before being built, and carry a fixed [request identifier](REQUEST_ID). With the
+//! `timestamp` feature, their creation date is fixed as well, see [`timestamp()`].
+//!
+//! Details of the [ambient context](crate::context) are still added when the errors are built.
+//!
+//! # Example
+//! ```
+//! use cdumay_core::fixtures;
+//! use serde_value::Value;
+//!
+//! let err = fixtures::not_found().with_detail("user", Value::U64(42)).build();
+//! assert_eq!(err.class(), "Client::NotFound::ResourceNotFound");
+//! assert_eq!(err.code(), 404);
+//! assert_eq!(err.request_id(), Some(fixtures::REQUEST_ID));
+//! assert_eq!(err, fixtures::not_found().with_detail("user", Value::U64(42)).build());
+//! ```
+use crate::ErrorKind;
+use crate::kinds::http::*;
+
+/// Request identifier of the sample errors.
+pub const REQUEST_ID: &str = "00000000-0000-4000-8000-000000000000";
+
+/// Kind, name, detail key and detail value of the sample error of each standard kind, ordered by code.
+const SAMPLES: [(ErrorKind, &str, &str, &str); 40] = [
+    (BadRequest, "InvalidParameter", "parameter", "limit"),
+    (Unauthorized, "MissingCredentials", "scheme", "Bearer"),
+    (PaymentRequired, "SubscriptionExpired", "plan", "premium"),
+    (Forbidden, "PermissionDenied", "permission", "users:write"),
+    (NotFound, "ResourceNotFound", "resource", "/users/42"),
+    (MethodNotAllowed, "UnsupportedMethod", "method", "DELETE"),
+    (NotAcceptable, "UnsupportedFormat", "accept", "application/pdf"),
+    (ProxyAuthenticationRequired, "MissingProxyCredentials", "proxy", "proxy.example.com"),
+    (RequestTimeout, "RequestBodyTimeout", "timeout", "30s"),
+    (Conflict, "ResourceAlreadyExists", "resource", "/users/42"),
+    (Gone, "ResourceDeleted", "resource", "/users/42"),
+    (LengthRequired, "MissingContentLength", "header", "Content-Length"),
+    (PreconditionFailed, "VersionMismatch", "etag", "\"33a64df5\""),
+    (PayloadTooLarge, "BodyTooLarge", "limit", "1MiB"),
+    (UriTooLong, "QueryTooLong", "limit", "8KiB"),
+    (UnsupportedMediaType, "UnsupportedContentType", "content_type", "text/plain"),
+    (RangeNotSatisfiable, "InvalidRange", "range", "bytes=2048-4096"),
+    (ExpectationFailed, "UnsupportedExpectation", "expect", "100-continue"),
+    (ImATeapot, "CoffeeRefused", "beverage", "coffee"),
+    (MisdirectedRequest, "UnknownAuthority", "authority", "api.example.com"),
+    (UnprocessableEntity, "ValidationFailed", "field", "email"),
+    (Locked, "ResourceLocked", "resource", "/documents/7"),
+    (FailedDependency, "PreviousStepFailed", "step", "reserve"),
+    (TooEarly, "EarlyDataRejected", "header", "Early-Data"),
+    (UpgradeRequired, "ProtocolUpgradeRequired", "upgrade", "HTTP/2"),
+    (PreconditionRequired, "MissingPrecondition", "header", "If-Match"),
+    (TooManyRequests, "RateLimitExceeded", "retry_after", "60"),
+    (RequestHeaderFieldsTooLarge, "HeadersTooLarge", "header", "Cookie"),
+    (UnavailableForLegalReasons, "LegalRestriction", "region", "EU"),
+    (InternalServerError, "UnexpectedError", "operation", "create_user"),
+    (NotImplemented, "UnsupportedFeature", "feature", "export"),
+    (BadGateway, "InvalidUpstreamResponse", "upstream", "billing"),
+    (ServiceUnavailable, "DependencyUnavailable", "dependency", "database"),
+    (GatewayTimeout, "UpstreamTimeout", "upstream", "billing"),
+    (HttpVersionNotSupported, "UnsupportedHttpVersion", "version", "HTTP/0.9"),
+    (VariantAlsoNegotiates, "NegotiationLoop", "variant", "/users/42.json"),
+    (InsufficientStorage, "QuotaExceeded", "volume", "uploads"),
+    (LoopDetected, "InfiniteLoop", "resource", "/folders/1"),
+    (NotExtended, "MissingExtension", "extension", "http://example.com/ext"),
+    (NetworkAuthenticationRequired, "CaptivePortal", "portal", "https://wifi.example.com"),
+];
+
+/// Creation date and time of the sample errors: `2024-01-01T00:00:00Z`.
+#[cfg(feature = "timestamp")]
+pub fn timestamp() -> chrono::DateTime<chrono::Utc> {
+    chrono::DateTime::UNIX_EPOCH + chrono::TimeDelta::seconds(1_704_067_200)
+}
+
+/// Returns the builder of the sample error of the given kind.
+///
+/// Kinds which are not part of the [standard HTTP kinds](crate::kinds::http) produce a
+/// `SampleError` without details.
+///
+/// # Example
+/// ```
+/// use cdumay_core::fixtures;
+/// use cdumay_core::kinds::http::Conflict;
+///
+/// let err = fixtures::builder(Conflict).build();
+/// assert_eq!(err.class(), "Client::Conflict::ResourceAlreadyExists");
+/// assert_eq!(err.details().get("resource"), Some(&serde_value::Value::String("/users/42".to_string())));
+/// ```
+pub fn builder(kind: ErrorKind) -> crate::ErrorBuilder {
+    let builder = match SAMPLES.iter().find(|(sample, ..)| *sample == kind) {
+        Some((_, name, key, value)) => crate::ErrorBuilder::new(kind, name).with_detail(*key, serde_value::Value::String(value.to_string())),
+        None => crate::ErrorBuilder::new(kind, "SampleError"),
+    };
+    #[cfg(feature = "timestamp")]
+    let builder = builder.with_timestamp(timestamp());
+    builder.with_request_id(REQUEST_ID.to_string())
+}
+
+/// Returns the sample error of each standard kind, ordered by code.
+///
+/// # Example
+/// ```
+/// use cdumay_core::fixtures;
+///
+/// let codes: Vec<u16> = fixtures::samples().iter().map(|err| err.code()).collect();
+/// assert_eq!(codes.len(), 40);
+/// assert!(codes.is_sorted());
+/// ```
+pub fn samples() -> Vec<crate::Error> {
+    SAMPLES.iter().map(|(kind, ..)| builder(kind.clone()).build()).collect()
+}
+
+/// Returns the builder of the `400 Bad Request` sample error.
+pub fn bad_request() -> crate::ErrorBuilder {
+    builder(BadRequest)
+}
+
+/// Returns the builder of the `401 Unauthorized` sample error.
+pub fn unauthorized() -> crate::ErrorBuilder {
+    builder(Unauthorized)
+}
+
+/// Returns the builder of the `403 Forbidden` sample error.
+pub fn forbidden() -> crate::ErrorBuilder {
+    builder(Forbidden)
+}
+
+/// Returns the builder of the `404 Not Found` sample error.
+pub fn not_found() -> crate::ErrorBuilder {
+    builder(NotFound)
+}
+
+/// Returns the builder of the `409 Conflict` sample error.
+pub fn conflict() -> crate::ErrorBuilder {
+    builder(Conflict)
+}
+
+/// Returns the builder of the `422 Unprocessable Entity` sample error.
+pub fn unprocessable_entity() -> crate::ErrorBuilder {
+    builder(UnprocessableEntity)
+}
+
+/// Returns the builder of the `429 Too Many Requests` sample error.
+pub fn too_many_requests() -> crate::ErrorBuilder {
+    builder(TooManyRequests)
+}
+
+/// Returns the builder of the `500 Internal Server Error` sample error.
+pub fn internal_server_error() -> crate::ErrorBuilder {
+    builder(InternalServerError)
+}
+
+/// Returns the builder of the `502 Bad Gateway` sample error.
+pub fn bad_gateway() -> crate::ErrorBuilder {
+    builder(BadGateway)
+}
+
+/// Returns the builder of the `503 Service Unavailable` sample error.
+pub fn service_unavailable() -> crate::ErrorBuilder {
+    builder(ServiceUnavailable)
+}
+
+/// Returns the builder of the `504 Gateway Timeout` sample error.
+pub fn gateway_timeout() -> crate::ErrorBuilder {
+    builder(GatewayTimeout)
+}
@@ -0,0 +1,166 @@
+//! Fluent-based internationalization of error messages.
+//!
+//! Translations are registered per locale using [`add_resource`], then rendered with
+//! [`Error::localized`](crate::Error::localized). Error details are passed to Fluent as
+//! message arguments.
+//!
+//! A message is looked up using the error message key (e.g. `Client-NotFound-UserNotFound`),
+//! then using the kind message key (e.g. `NotFound`). If none is found, the original
+//! message is returned.
+//!
+//! # Example
+//! ```
+//! use cdumay_core::{i18n, ErrorBuilder, ErrorKind};
+//! use serde_value::Value;
+//! use std::collections::BTreeMap;
+//!
+//! i18n::add_resource("fr", "NotFound = La ressource { $id } est introuvable").unwrap();
+//!
+//! let mut details = BTreeMap::new();
+//! details.insert("id".to_string(), Value::String("42".to_string()));
+//! let err = ErrorBuilder::new(ErrorKind("NotFound", 404, "Not Found"), "UserNotFound")
+//!     .with_details(details)
+//!     .build();
+//!
+//! assert_eq!(err.localized("fr-FR"), "La ressource 42 est introuvable");
+//! assert_eq!(err.localized("de"), "Not Found");
+//! ```
+use fluent_bundle::concurrent::FluentBundle;
+use fluent_bundle::{FluentArgs, FluentResource, FluentValue};
+use std::collections::BTreeMap;
+use std::sync::{PoisonError, RwLock};
+use unic_langid::LanguageIdentifier;
+
+/// Registered bundles, indexed by locale.
+static BUNDLES: RwLock<BTreeMap<String, FluentBundle<FluentResource>>> = RwLock::new(BTreeMap::new());
+
+const INVALID_TRANSLATION: crate::ErrorKind = crate::ErrorKind("InvalidTranslation", 500, "Invalid translation resource");
+
+/// Registers a Fluent resource (FTL source) for the given locale.
+///
+/// Messages already registered for the locale are overridden.
+///
+/// # Errors
+/// Returns an `Error` if the locale is not a valid language identifier or if the
+/// resource cannot be parsed.
+pub fn add_resource(locale: &str, source: &str) -> crate::Result<()> {
+    let langid: LanguageIdentifier = locale.parse().map_err(|_| {
+        crate::ErrorBuilder::new(INVALID_TRANSLATION, "InvalidLocale")
+            .with_message(format!("Invalid locale: {}", locale))
+            .build()
+    })?;
+    let resource = FluentResource::try_new(source.to_string()).map_err(|(_, errors)| {
+        crate::ErrorBuilder::new(INVALID_TRANSLATION, "InvalidResource")
+            .with_message(format!("Failed to parse resource for locale {}: {:?}", locale, errors))
+            .build()
+    })?;
+    let mut bundles = BUNDLES.write().unwrap_or_else(PoisonError::into_inner);
+    bundles
+        .entry(langid.to_string())
+        .or_insert_with(|| {
+            let mut bundle = FluentBundle::new_concurrent(vec![langid]);
+            bundle.set_use_isolating(false);
+            bundle
+        })
+        .add_resource_overriding(resource);
+    Ok(())
+}
+
+/// Converts error details into Fluent arguments.
+///
+/// Only strings, numbers and booleans are supported, other values are ignored.
+fn to_args(details: &BTreeMap<String, serde_value::Value>) -> FluentArgs<'_> {
+    let mut args = FluentArgs::new();
+    for (key, value) in details {
+        let value: FluentValue = match value {
+            serde_value::Value::String(value) => value.as_str().into(),
+            serde_value::Value::Bool(value) => value.to_string().into(),
+            serde_value::Value::U8(value) => (*value).into(),
+            serde_value::Value::U16(value) => (*value).into(),
+            serde_value::Value::U32(value) => (*value).into(),
+            serde_value::Value::U64(value) => (*value).into(),
+            serde_value::Value::I8(value) => (*value).into(),
+            serde_value::Value::I16(value) => (*value).into(),
+            serde_value::Value::I32(value) => (*value).into(),
+            serde_value::Value::I64(value) => (*value).into(),
+            serde_value::Value::F32(value) => (*value).into(),
+            serde_value::Value::F64(value) => (*value).into(),
+            _ => continue,
+        };
+        args.set(key.as_str(), value);
+    }
+    args
+}
+
+impl crate::ErrorKind {
+    /// Returns the key used to look up the kind message in a Fluent bundle.
+    ///
+    /// As for [`Error::message_key`](crate::Error::message_key), the namespace separators are replaced by `-`.
+    ///
+    /// # Example
+    /// ```
+    /// use cdumay_core::ErrorKind;
+    ///
+    /// assert_eq!(ErrorKind("NotFound", 404, "Not Found").message_key(), "NotFound");
+    /// assert_eq!(ErrorKind("Billing::InvalidInvoice", 400, "Invalid invoice").message_key(), "Billing-InvalidInvoice");
+    /// ```
+    pub fn message_key(&self) -> String {
+        self.name().replace("::", "-")
+    }
+}
+
+impl crate::Error {
+    /// Returns the key used to look up the error message in a Fluent bundle.
+    ///
+    /// Fluent identifiers cannot contain `::`, so the class separators are replaced by `-`.
+    ///
+    /// # Example
+    /// ```
+    /// use cdumay_core::{ErrorBuilder, ErrorKind};
+    ///
+    /// let err = ErrorBuilder::new(ErrorKind("NotFound", 404, "Not Found"), "UserNotFound").build();
+    /// assert_eq!(err.message_key(), "Client-NotFound-UserNotFound");
+    /// ```
+    pub fn message_key(&self) -> String {
+        self.class_ref().replace("::", "-")
+    }
+
+    /// Returns the message translated in the given locale.
+    ///
+    /// If no bundle is registered for the exact locale, the bundle of its language is
+    /// used (e.g. `fr` for `fr-FR`). Falls back to the error message when no translation
+    /// is available.
+    pub fn localized(&self, locale: &str) -> String {
+        let bundles = BUNDLES.read().unwrap_or_else(PoisonError::into_inner);
+        let bundle = match locale.parse::<LanguageIdentifier>() {
+            Ok(langid) => bundles
+                .get(&langid.to_string())
+                .or_else(|| bundles.get(langid.language.as_str())),
+            Err(_) => None,
+        };
+        let Some(bundle) = bundle else {
+            return self.message();
+        };
+        let kind_key = match self.kind() {
+            Some(kind) => Some(kind.name.replace("::", "-")),
+            None => self
+                .class_ref()
+                .split_once("::")
+                .and_then(|(_, class)| class.rsplit_once("::"))
+                .map(|(kind, _)| kind.replace("::", "-")),
+        };
+        let message = std::iter::once(self.message_key())
+            .chain(kind_key)
+            .find_map(|key| bundle.get_message(&key))
+            .and_then(|message| message.value());
+        match message {
+            Some(pattern) => {
+                let details = self.resolved_details();
+                let args = to_args(&details);
+                let mut errors = vec![];
+                bundle.format_pattern(pattern, Some(&args), &mut errors).to_string()
+            }
+            None => self.message(),
+        }
+    }
+}
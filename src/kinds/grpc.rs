@@ -0,0 +1,151 @@
+//! gRPC status error kinds.
+//!
+//! Each kind carries the HTTP code matching its gRPC status code, following the
+//! `google.rpc.Code` mapping. Use [`grpc_code`] and [`kind_from_grpc`] to switch between the
+//! two families.
+//!
+//! # Example
+//! ```
+//! use cdumay_core::kinds::grpc::{self, ResourceExhausted};
+//!
+//! assert_eq!(ResourceExhausted.code(), 429);
+//! assert_eq!(grpc::grpc_code(&ResourceExhausted), 8);
+//! assert_eq!(grpc::kind_from_grpc(8), ResourceExhausted);
+//! ```
+crate::define_kinds! {
+    Cancelled = (499, "The operation was cancelled"),
+    Unknown = (500, "Unknown error"),
+    InvalidArgument = (400, "Invalid argument"),
+    DeadlineExceeded = (504, "Deadline exceeded"),
+    NotFound = (404, "Not found"),
+    AlreadyExists = (409, "Already exists"),
+    PermissionDenied = (403, "Permission denied"),
+    ResourceExhausted = (429, "Resource exhausted"),
+    FailedPrecondition = (400, "Failed precondition"),
+    Aborted = (409, "Aborted"),
+    OutOfRange = (400, "Out of range"),
+    Unimplemented = (501, "Unimplemented"),
+    Internal = (500, "Internal error"),
+    Unavailable = (503, "Service unavailable"),
+    DataLoss = (500, "Data loss"),
+    Unauthenticated = (401, "Unauthenticated"),
+}
+
+/// Kinds defined in this module, ordered by gRPC status code.
+pub(crate) const KINDS: [crate::ErrorKind; 16] = [
+    Cancelled,
+    Unknown,
+    InvalidArgument,
+    DeadlineExceeded,
+    NotFound,
+    AlreadyExists,
+    PermissionDenied,
+    ResourceExhausted,
+    FailedPrecondition,
+    Aborted,
+    OutOfRange,
+    Unimplemented,
+    Internal,
+    Unavailable,
+    DataLoss,
+    Unauthenticated,
+];
+
+/// gRPC status codes of the kinds defined in this module, by kind name.
+const GRPC_CODES: [(&str, i32); 16] = [
+    ("Cancelled", 1),
+    ("Unknown", 2),
+    ("InvalidArgument", 3),
+    ("DeadlineExceeded", 4),
+    ("NotFound", 5),
+    ("AlreadyExists", 6),
+    ("PermissionDenied", 7),
+    ("ResourceExhausted", 8),
+    ("FailedPrecondition", 9),
+    ("Aborted", 10),
+    ("OutOfRange", 11),
+    ("Unimplemented", 12),
+    ("Internal", 13),
+    ("Unavailable", 14),
+    ("DataLoss", 15),
+    ("Unauthenticated", 16),
+];
+
+/// Returns the gRPC status code of a kind.
+///
+/// Kinds of this module map to their own status code, other kinds are mapped from their
+/// HTTP code using [`grpc_code_from_http`].
+pub fn grpc_code(kind: &crate::ErrorKind) -> i32 {
+    GRPC_CODES
+        .iter()
+        .find(|(name, code)| *name == kind.name() && kind_from_grpc(*code).code() == kind.code())
+        .map(|(_, code)| *code)
+        .unwrap_or_else(|| grpc_code_from_http(kind.code()))
+}
+
+/// Returns the gRPC status code matching an HTTP code.
+///
+/// # Example
+/// ```
+/// use cdumay_core::kinds::grpc::grpc_code_from_http;
+///
+/// assert_eq!(grpc_code_from_http(404), 5);
+/// assert_eq!(grpc_code_from_http(418), 9);
+/// ```
+pub fn grpc_code_from_http(code: u16) -> i32 {
+    match code {
+        200..=299 => 0,
+        400 => 3,
+        401 => 16,
+        403 => 7,
+        404 => 5,
+        409 => 10,
+        429 => 8,
+        499 => 1,
+        402 | 405..=408 | 410..=428 | 430..=498 => 9,
+        501 => 12,
+        503 => 14,
+        504 => 4,
+        500 | 502 | 505..=599 => 13,
+        _ => 2,
+    }
+}
+
+/// Returns the kind of this module matching a gRPC status code.
+///
+/// Unknown status codes (including `0`, which is not an error) map to [`Unknown`].
+pub fn kind_from_grpc(code: i32) -> crate::ErrorKind {
+    match code {
+        1 => Cancelled,
+        3 => InvalidArgument,
+        4 => DeadlineExceeded,
+        5 => NotFound,
+        6 => AlreadyExists,
+        7 => PermissionDenied,
+        8 => ResourceExhausted,
+        9 => FailedPrecondition,
+        10 => Aborted,
+        11 => OutOfRange,
+        12 => Unimplemented,
+        13 => Internal,
+        14 => Unavailable,
+        15 => DataLoss,
+        16 => Unauthenticated,
+        _ => Unknown,
+    }
+}
+
+/// Returns the HTTP code matching a gRPC status code.
+///
+/// # Example
+/// ```
+/// use cdumay_core::kinds::grpc::http_code_from_grpc;
+///
+/// assert_eq!(http_code_from_grpc(14), 503);
+/// ```
+pub fn http_code_from_grpc(code: i32) -> u16 {
+    match code {
+        0 => 200,
+        code => kind_from_grpc(code).code(),
+    }
+}
@@ -0,0 +1,120 @@
+//! Standard HTTP error kinds.
+//!
+//! # Example
+//! ```
+//! use cdumay_core::define_errors;
+//! use cdumay_core::kinds::http::{NotFound, TooManyRequests};
+//!
+//! define_errors! {
+//!     UserNotFound = NotFound,
+//!     QuotaExceeded = TooManyRequests,
+//! }
+//!
+//! assert_eq!(UserNotFound::new().code(), 404);
+//! ```
+crate::define_kinds! {
+    #![unique_codes]
+    BadRequest = (400, "Bad Request"),
+    Unauthorized = (401, "Unauthorized"),
+    PaymentRequired = (402, "Payment Required"),
+    Forbidden = (403, "Forbidden"),
+    NotFound = (404, "Not Found"),
+    MethodNotAllowed = (405, "Method Not Allowed"),
+    NotAcceptable = (406, "Not Acceptable"),
+    ProxyAuthenticationRequired = (407, "Proxy Authentication Required"),
+    RequestTimeout = (408, "Request Timeout"),
+    Conflict = (409, "Conflict"),
+    Gone = (410, "Gone"),
+    LengthRequired = (411, "Length Required"),
+    PreconditionFailed = (412, "Precondition Failed"),
+    PayloadTooLarge = (413, "Payload Too Large"),
+    UriTooLong = (414, "URI Too Long"),
+    UnsupportedMediaType = (415, "Unsupported Media Type"),
+    RangeNotSatisfiable = (416, "Range Not Satisfiable"),
+    ExpectationFailed = (417, "Expectation Failed"),
+    ImATeapot = (418, "I'm a teapot"),
+    MisdirectedRequest = (421, "Misdirected Request"),
+    UnprocessableEntity = (422, "Unprocessable Entity"),
+    Locked = (423, "Locked"),
+    FailedDependency = (424, "Failed Dependency"),
+    TooEarly = (425, "Too Early"),
+    UpgradeRequired = (426, "Upgrade Required"),
+    PreconditionRequired = (428, "Precondition Required"),
+    TooManyRequests = (429, "Too Many Requests"),
+    RequestHeaderFieldsTooLarge = (431, "Request Header Fields Too Large"),
+    UnavailableForLegalReasons = (451, "Unavailable For Legal Reasons"),
+    InternalServerError = (500, "Internal Server Error"),
+    NotImplemented = (501, "Not Implemented"),
+    BadGateway = (502, "Bad Gateway"),
+    ServiceUnavailable = (503, "Service Unavailable"),
+    GatewayTimeout = (504, "Gateway Timeout"),
+    HttpVersionNotSupported = (505, "HTTP Version Not Supported"),
+    VariantAlsoNegotiates = (506, "Variant Also Negotiates"),
+    InsufficientStorage = (507, "Insufficient Storage"),
+    LoopDetected = (508, "Loop Detected"),
+    NotExtended = (510, "Not Extended"),
+    NetworkAuthenticationRequired = (511, "Network Authentication Required"),
+}
+
+/// Kinds defined in this module, ordered by code.
+pub(crate) const KINDS: [crate::ErrorKind; 40] = [
+    BadRequest,
+    Unauthorized,
+    PaymentRequired,
+    Forbidden,
+    NotFound,
+    MethodNotAllowed,
+    NotAcceptable,
+    ProxyAuthenticationRequired,
+    RequestTimeout,
+    Conflict,
+    Gone,
+    LengthRequired,
+    PreconditionFailed,
+    PayloadTooLarge,
+    UriTooLong,
+    UnsupportedMediaType,
+    RangeNotSatisfiable,
+    ExpectationFailed,
+    ImATeapot,
+    MisdirectedRequest,
+    UnprocessableEntity,
+    Locked,
+    FailedDependency,
+    TooEarly,
+    UpgradeRequired,
+    PreconditionRequired,
+    TooManyRequests,
+    RequestHeaderFieldsTooLarge,
+    UnavailableForLegalReasons,
+    InternalServerError,
+    NotImplemented,
+    BadGateway,
+    ServiceUnavailable,
+    GatewayTimeout,
+    HttpVersionNotSupported,
+    VariantAlsoNegotiates,
+    InsufficientStorage,
+    LoopDetected,
+    NotExtended,
+    NetworkAuthenticationRequired,
+];
+
+/// Returns the kind matching an HTTP status code.
+///
+/// Unknown codes fall back to [`BadRequest`] for `4xx` codes and [`InternalServerError`] otherwise.
+///
+/// # Example
+/// ```
+/// use cdumay_core::kinds::http::{kind_from_code, BadRequest, NotFound};
+///
+/// assert_eq!(kind_from_code(404), NotFound);
+/// assert_eq!(kind_from_code(499), BadRequest);
+/// ```
+pub fn kind_from_code(code: u16) -> crate::ErrorKind {
+    match KINDS.iter().find(|kind| kind.code() == code) {
+        Some(kind) => kind.clone(),
+        None if (400..500).contains(&code) => BadRequest,
+        None => InternalServerError,
+    }
+}
@@ -0,0 +1,4 @@
+//! Predefined `ErrorKind` constants, ready to be used with [`define_errors!`](crate::define_errors).
+pub mod grpc;
+pub mod http;
+pub mod posix;
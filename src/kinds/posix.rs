@@ -0,0 +1,225 @@
+//! POSIX errno-based error kinds.
+//!
+//! Each kind is named after its errno constant and carries the closest HTTP code.
+//! [`kind_from_io_error`] picks the right kind for a `std::io::Error`, which is used by the
+//! `From<std::io::Error>` implementation of `Error`.
+//!
+//! # Example
+//! ```
+//! use cdumay_core::Error;
+//!
+//! let err: Error = std::fs::read("/does/not/exist").unwrap_err().into();
+//! assert_eq!(err.code(), 404);
+//! assert_eq!(err.class(), "Client::ENOENT::IoError");
+//! ```
+crate::define_kinds! {
+    EPERM = (403, "Operation not permitted"),
+    ENOENT = (404, "No such file or directory"),
+    ESRCH = (404, "No such process"),
+    EINTR = (500, "Interrupted system call"),
+    EIO = (500, "Input/output error"),
+    ENXIO = (404, "No such device or address"),
+    E2BIG = (400, "Argument list too long"),
+    ENOEXEC = (500, "Exec format error"),
+    EBADF = (500, "Bad file descriptor"),
+    ECHILD = (500, "No child processes"),
+    EAGAIN = (503, "Resource temporarily unavailable"),
+    ENOMEM = (500, "Cannot allocate memory"),
+    EACCES = (403, "Permission denied"),
+    EFAULT = (500, "Bad address"),
+    EBUSY = (409, "Device or resource busy"),
+    EEXIST = (409, "File exists"),
+    EXDEV = (500, "Invalid cross-device link"),
+    ENODEV = (404, "No such device"),
+    ENOTDIR = (400, "Not a directory"),
+    EISDIR = (400, "Is a directory"),
+    EINVAL = (400, "Invalid argument"),
+    ENFILE = (503, "Too many open files in system"),
+    EMFILE = (503, "Too many open files"),
+    ENOTTY = (500, "Inappropriate ioctl for device"),
+    EFBIG = (413, "File too large"),
+    ENOSPC = (507, "No space left on device"),
+    ESPIPE = (500, "Illegal seek"),
+    EROFS = (500, "Read-only file system"),
+    EMLINK = (500, "Too many links"),
+    EPIPE = (500, "Broken pipe"),
+    EDOM = (400, "Numerical argument out of domain"),
+    ERANGE = (400, "Numerical result out of range"),
+    ENOTEMPTY = (409, "Directory not empty"),
+    ENAMETOOLONG = (400, "File name too long"),
+    EADDRINUSE = (500, "Address already in use"),
+    EADDRNOTAVAIL = (500, "Cannot assign requested address"),
+    ENETUNREACH = (503, "Network is unreachable"),
+    ECONNABORTED = (502, "Software caused connection abort"),
+    ECONNRESET = (502, "Connection reset by peer"),
+    ENOTCONN = (503, "Transport endpoint is not connected"),
+    ETIMEDOUT = (504, "Connection timed out"),
+    ECONNREFUSED = (503, "Connection refused"),
+    EHOSTUNREACH = (503, "No route to host"),
+    EDEADLK = (500, "Resource deadlock avoided"),
+    ENOSYS = (501, "Function not implemented"),
+    ENOTSUP = (501, "Operation not supported"),
+}
+
+/// Kinds defined in this module.
+pub(crate) const KINDS: [crate::ErrorKind; 46] = [
+    EPERM,
+    ENOENT,
+    ESRCH,
+    EINTR,
+    EIO,
+    ENXIO,
+    E2BIG,
+    ENOEXEC,
+    EBADF,
+    ECHILD,
+    EAGAIN,
+    ENOMEM,
+    EACCES,
+    EFAULT,
+    EBUSY,
+    EEXIST,
+    EXDEV,
+    ENODEV,
+    ENOTDIR,
+    EISDIR,
+    EINVAL,
+    ENFILE,
+    EMFILE,
+    ENOTTY,
+    EFBIG,
+    ENOSPC,
+    ESPIPE,
+    EROFS,
+    EMLINK,
+    EPIPE,
+    EDOM,
+    ERANGE,
+    ENOTEMPTY,
+    ENAMETOOLONG,
+    EADDRINUSE,
+    EADDRNOTAVAIL,
+    ENETUNREACH,
+    ECONNABORTED,
+    ECONNRESET,
+    ENOTCONN,
+    ETIMEDOUT,
+    ECONNREFUSED,
+    EHOSTUNREACH,
+    EDEADLK,
+    ENOSYS,
+    ENOTSUP,
+];
+
+/// Returns the kind matching a raw errno value of the current platform.
+///
+/// The values are resolved using the `libc` constants of the target, as they differ between
+/// operating systems (e.g. `11` is `EAGAIN` on Linux but `EDEADLK` on macOS). Always `None` on
+/// platforms which are not Unix-like, where raw OS errors are not errno values.
+#[cfg(unix)]
+pub fn kind_from_errno(errno: i32) -> Option<crate::ErrorKind> {
+    Some(match errno {
+        libc::EPERM => EPERM,
+        libc::ENOENT => ENOENT,
+        libc::ESRCH => ESRCH,
+        libc::EINTR => EINTR,
+        libc::EIO => EIO,
+        libc::ENXIO => ENXIO,
+        libc::E2BIG => E2BIG,
+        libc::ENOEXEC => ENOEXEC,
+        libc::EBADF => EBADF,
+        libc::ECHILD => ECHILD,
+        libc::EAGAIN => EAGAIN,
+        libc::ENOMEM => ENOMEM,
+        libc::EACCES => EACCES,
+        libc::EFAULT => EFAULT,
+        libc::EBUSY => EBUSY,
+        libc::EEXIST => EEXIST,
+        libc::EXDEV => EXDEV,
+        libc::ENODEV => ENODEV,
+        libc::ENOTDIR => ENOTDIR,
+        libc::EISDIR => EISDIR,
+        libc::EINVAL => EINVAL,
+        libc::ENFILE => ENFILE,
+        libc::EMFILE => EMFILE,
+        libc::ENOTTY => ENOTTY,
+        libc::EFBIG => EFBIG,
+        libc::ENOSPC => ENOSPC,
+        libc::ESPIPE => ESPIPE,
+        libc::EROFS => EROFS,
+        libc::EMLINK => EMLINK,
+        libc::EPIPE => EPIPE,
+        libc::EDOM => EDOM,
+        libc::ERANGE => ERANGE,
+        libc::ENOTEMPTY => ENOTEMPTY,
+        libc::ENAMETOOLONG => ENAMETOOLONG,
+        libc::EADDRINUSE => EADDRINUSE,
+        libc::EADDRNOTAVAIL => EADDRNOTAVAIL,
+        libc::ENETUNREACH => ENETUNREACH,
+        libc::ECONNABORTED => ECONNABORTED,
+        libc::ECONNRESET => ECONNRESET,
+        libc::ENOTCONN => ENOTCONN,
+        libc::ETIMEDOUT => ETIMEDOUT,
+        libc::ECONNREFUSED => ECONNREFUSED,
+        libc::EHOSTUNREACH => EHOSTUNREACH,
+        libc::EDEADLK => EDEADLK,
+        libc::ENOSYS => ENOSYS,
+        libc::ENOTSUP => ENOTSUP,
+        _ => return None,
+    })
+}
+
+/// Returns the kind matching a raw errno value of the current platform.
+///
+/// Always `None` on platforms which are not Unix-like, where raw OS errors are not errno values.
+#[cfg(not(unix))]
+pub fn kind_from_errno(_errno: i32) -> Option<crate::ErrorKind> {
+    None
+}
+
+/// Returns the kind matching a `std::io::Error`.
+///
+/// The `std::io::ErrorKind` is inspected first, as it is portable across platforms; then, on
+/// Unix-like platforms, the raw OS error using [`kind_from_errno`]. Falls back to [`EIO`].
+///
+/// The same mapping is used by the `IoErrorConverter`.
+pub fn kind_from_io_error(error: &std::io::Error) -> crate::ErrorKind {
+    use std::io::ErrorKind;
+    match error.kind() {
+        ErrorKind::NotFound => ENOENT,
+        ErrorKind::PermissionDenied => EACCES,
+        ErrorKind::ConnectionRefused => ECONNREFUSED,
+        ErrorKind::ConnectionReset => ECONNRESET,
+        ErrorKind::HostUnreachable => EHOSTUNREACH,
+        ErrorKind::NetworkUnreachable => ENETUNREACH,
+        ErrorKind::ConnectionAborted => ECONNABORTED,
+        ErrorKind::NotConnected => ENOTCONN,
+        ErrorKind::AddrInUse => EADDRINUSE,
+        ErrorKind::AddrNotAvailable => EADDRNOTAVAIL,
+        ErrorKind::BrokenPipe => EPIPE,
+        ErrorKind::AlreadyExists => EEXIST,
+        ErrorKind::WouldBlock => EAGAIN,
+        ErrorKind::NotADirectory => ENOTDIR,
+        ErrorKind::IsADirectory => EISDIR,
+        ErrorKind::DirectoryNotEmpty => ENOTEMPTY,
+        ErrorKind::ReadOnlyFilesystem => EROFS,
+        ErrorKind::StorageFull => ENOSPC,
+        ErrorKind::NotSeekable => ESPIPE,
+        ErrorKind::FileTooLarge => EFBIG,
+        ErrorKind::ResourceBusy => EBUSY,
+        ErrorKind::Deadlock => EDEADLK,
+        ErrorKind::CrossesDevices => EXDEV,
+        ErrorKind::TooManyLinks => EMLINK,
+        ErrorKind::InvalidFilename => ENAMETOOLONG,
+        ErrorKind::ArgumentListTooLong => E2BIG,
+        ErrorKind::InvalidInput | ErrorKind::InvalidData => EINVAL,
+        ErrorKind::TimedOut => ETIMEDOUT,
+        ErrorKind::Interrupted => EINTR,
+        ErrorKind::Unsupported => ENOTSUP,
+        ErrorKind::OutOfMemory => ENOMEM,
+        #[cfg(unix)]
+        _ => error.raw_os_error().and_then(kind_from_errno).unwrap_or(EIO),
+        #[cfg(not(unix))]
+        _ => EIO,
+    }
+}
@@ -41,6 +41,45 @@
 //!
 //! - `utoipa`: Implement `utoipa::ToSchema` to `Error`
 //! - `actix-web`: Allow to use `Result` and `Error` with actix
+//! - `tracing`: Emit `Error` as structured `tracing` events with `Error::trace()`
+//! - `derive`: Provide the `AsCdumayError` derive macro for enums and the `ErrorConverter` derive macro
+//! - `wire`: Include the `code` and `kind` fields in the serialized `Error` so they survive a round-trip
+//! - `i18n`: Translate error messages using [Fluent](https://projectfluent.org/) bundles
+//! - `std-converters`: Provide `ErrorConverter` implementations for common standard library errors
+//! - `anyhow`: Convert `Error` from and into `anyhow::Error`
+//! - `sentry`: Convert `Error` into Sentry events and send them with `Error::capture()`
+//! - `metrics`: Count errors by class, code and side using a Prometheus counter
+//! - `warp`: Allow to use `Result` and `Error` with warp
+//! - `opentelemetry`: Record `Error` as OpenTelemetry span attributes
+//! - `serde-json`: Provide an `ErrorConverter` implementation for `serde_json::Error`, a versioned JSON form of errors which older payloads are upgraded from, and JSON helpers such as `Error::to_json_pretty()`
+//! - `reqwest`: Convert `reqwest::Error` and parse errors returned by other services with `Error::from_response()`
+//! - `sqlx`: Provide an `ErrorConverter` implementation for `sqlx::Error`
+//! - `diesel`: Provide an `ErrorConverter` implementation for `diesel::result::Error`
+//! - `timestamp`: Record the creation date and time of `Error`, serialized in ISO-8601
+//! - `xml`: Render `Error` as XML in `actix-web` responses when requested by the `Accept` header
+//! - `retry`: Retry asynchronous operations failing with retryable errors, using `tokio` timers
+//! - `rmp`: Encode errors in MessagePack using a versioned layout including the `code`, for transport between services
+//! - `cbor`: Encode errors in CBOR using the same layout as `rmp`, e.g. for CoAP transports
+//! - `gelf`: Format errors as GELF 1.1 messages for Graylog
+//! - `log`: Emit errors through the `log` facade with key-values, optionally on every build
+//! - `pyo3`: Convert errors from and into a `CdumayError` Python exception carrying their class, code and details
+//! - `wasm`: Convert errors from and into JS values with `wasm-bindgen`
+//! - `napi`: Convert errors into `napi` errors and JS exceptions with structured properties, for Node.js addons
+//! - `uniffi`: Expose errors to Kotlin and Swift as a typed `CdumayError` carrying their class, code and details
+//! - `http`: Convert errors into `http::StatusCode`, for frameworks and middleware built on the `http` crate
+//! - `tower`: Provide the `ErrorMappingLayer` tower layer rendering service errors as structured HTTP responses, for hyper, axum or tonic stacks
+//! - `miette`: Report errors as miette diagnostics, with their class as code, their help and a severity, for CLI tools
+//! - `snafu`: Convert snafu errors into `Error` with `Error::from_snafu()`, keeping the fields of their context selectors in the details
+//! - `eyre`: Convert `Error` from and into `eyre::Report`, keeping the chain of causes and, optionally, the sections attached by handlers such as `color-eyre`
+//! - `redis`: Provide an `ErrorConverter` implementation for `redis::RedisError`
+//! - `mongodb`: Provide an `ErrorConverter` implementation for `mongodb::error::Error` (the TLS backend of `mongodb` is selected by the application)
+//! - `mongodb-rustls`: Enable `mongodb` along with its `rustls` TLS backend, e.g. to run the test suite
+//! - `url`: Provide an `ErrorConverter` implementation for `url::ParseError`
+//! - `uuid`: Provide an `ErrorConverter` implementation for `uuid::Error`, raised as an `InvalidIdentifier` error
+//! - `jwt`: Provide an `ErrorConverter` implementation for `jsonwebtoken::errors::Error`, for authentication middleware
+//! - `testing`: Provide assertion macros such as `assert_error_kind!` and `assert_error_code!` for test suites
+//! - `arbitrary`: Implement `proptest::arbitrary::Arbitrary` for `Error` and `ErrorKind`, generating realistic errors for property tests
+//! - `fixtures`: Provide deterministic sample errors for each standard kind, such as `fixtures::not_found()`, for contract tests and documentation examples
 //!
 //! # Compatibility
 //!
@@ -68,8 +107,155 @@
 //! }
 //! ```
 mod error;
-pub use error::{Error, ErrorBuilder, ErrorConverter, ErrorKind};
-pub type Result<D> = std::result::Result<D, Error>;
+pub use error::{CatalogError, CatalogKind, CodeLocation, ConverterRegistry, DocumentedKind, Error, ErrorBuilder, ErrorCatalog, ErrorConverter, ErrorFormat, ErrorHeadersConfig, ErrorKey, ErrorKind, ErrorResponseConfig, ErrorTemplate, Kind, KindInfo, MultiError, ProblemDetails, Redactor, ResultExt, Side, SyslogSeverity, catch_panic, set_docs_url_template, set_fingerprint_keys};
+mod result;
+pub use result::envelope as result_envelope;
+pub use result::{AsyncResultExt, Result, ResultContextExt, ResultEnvelope, ResultIteratorExt};
+
+#[cfg(feature = "actix-web")]
+pub use error::{RequestId, attach_request_id, catch_panic_middleware, json_config, negotiate_error_format, path_config, query_config};
+#[cfg(feature = "miette")]
+pub use error::ErrorDiagnostic;
+#[cfg(feature = "eyre")]
+pub use error::{IntoEyre, set_eyre_report};
+#[cfg(feature = "pyo3")]
+pub use error::PyCdumayError;
+#[cfg(feature = "uniffi")]
+pub use error::CdumayError;
+#[cfg(feature = "tower")]
+pub use error::{ErrorMapping, ErrorMappingFuture, ErrorMappingLayer};
+#[cfg(feature = "warp")]
+pub use error::handle_cdumay_rejection;
+
+#[cfg(feature = "derive")]
+pub use cdumay_core_derive::{AsCdumayError, ErrorConverter};
+
+pub mod breaker;
+pub mod context;
+pub mod converters;
+
+#[cfg(feature = "fixtures")]
+pub mod fixtures;
+#[cfg(feature = "i18n")]
+pub mod i18n;
+pub mod kinds;
+#[cfg(feature = "log")]
+pub mod log;
+#[cfg(feature = "metrics")]
+pub mod metrics;
+pub mod pipeline;
+pub mod report;
+#[cfg(feature = "retry")]
+pub mod retry;
+#[cfg(feature = "testing")]
+pub mod testing;
 
 #[macro_use]
 mod macros;
+
+#[cfg(feature = "uniffi")]
+uniffi::setup_scaffolding!("cdumay_core");
+
+#[doc(hidden)]
+pub mod __private {
+    pub use serde_value;
+    #[cfg(feature = "utoipa")]
+    pub use utoipa;
+
+    /// Underlying error of the errors generated by `define_errors!`, serialized as `None`.
+    #[derive(Debug, Clone, Default)]
+    pub struct Source(pub Option<std::sync::Arc<dyn std::error::Error + Send + Sync>>);
+
+    impl serde::Serialize for Source {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+            serializer.serialize_none()
+        }
+    }
+
+    impl<'de> serde::Deserialize<'de> for Source {
+        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+            serde::de::IgnoredAny::deserialize(deserializer).map(|_| Self::default())
+        }
+    }
+
+    /// Returns the `ErrorKind` equivalent to the given kind.
+    pub fn error_kind<K: crate::Kind>(kind: &K) -> crate::ErrorKind {
+        crate::ErrorKind(kind.name(), kind.code(), kind.description())
+    }
+
+    /// Kind of an error defined using [`define_errors!`](crate::define_errors), whose `ErrorKind`
+    /// is needed in a constant, documented or not.
+    pub struct ConstKind<K>(pub K);
+
+    impl ConstKind<crate::ErrorKind> {
+        pub const fn error_kind(self) -> crate::ErrorKind {
+            self.0
+        }
+    }
+
+    impl ConstKind<crate::DocumentedKind> {
+        pub const fn error_kind(self) -> crate::ErrorKind {
+            self.0.0
+        }
+    }
+
+    /// Writes the lines following the first one in the verbose (`{:#}`) display of an error.
+    ///
+    /// The details are scrubbed using the global [`Redactor`](crate::Redactor).
+    pub fn write_verbose(
+        f: &mut std::fmt::Formatter<'_>,
+        request_id: Option<&str>,
+        help: Option<&str>,
+        mut details: std::collections::BTreeMap<String, serde_value::Value>,
+        context: &[String],
+        source: Option<&(dyn std::error::Error + 'static)>,
+    ) -> std::fmt::Result {
+        if let Some(request_id) = request_id {
+            write!(f, "\n  request_id: {}", request_id)?;
+        }
+        if let Some(help) = help {
+            write!(f, "\n  help: {}", help)?;
+        }
+        if !details.is_empty() {
+            crate::Redactor::global().redact_details(&mut details);
+            f.write_str("\n  details:")?;
+            for (key, value) in &details {
+                write!(f, "\n    {}: ", key)?;
+                write_value(f, value)?;
+            }
+        }
+        if !context.is_empty() {
+            f.write_str("\n  context:")?;
+            for frame in context {
+                write!(f, "\n    {}", frame)?;
+            }
+        }
+        let mut source = source;
+        while let Some(error) = source {
+            write!(f, "\n  caused by: {}", error)?;
+            source = error.source();
+        }
+        Ok(())
+    }
+
+    /// Writes a detail value, scalars using their display representation, other values using their debug representation.
+    fn write_value(f: &mut std::fmt::Formatter<'_>, value: &serde_value::Value) -> std::fmt::Result {
+        use serde_value::Value;
+        match value {
+            Value::String(value) => f.write_str(value),
+            Value::Bool(value) => write!(f, "{}", value),
+            Value::U8(value) => write!(f, "{}", value),
+            Value::U16(value) => write!(f, "{}", value),
+            Value::U32(value) => write!(f, "{}", value),
+            Value::U64(value) => write!(f, "{}", value),
+            Value::I8(value) => write!(f, "{}", value),
+            Value::I16(value) => write!(f, "{}", value),
+            Value::I32(value) => write!(f, "{}", value),
+            Value::I64(value) => write!(f, "{}", value),
+            Value::F32(value) => write!(f, "{}", value),
+            Value::F64(value) => write!(f, "{}", value),
+            Value::Char(value) => write!(f, "{}", value),
+            other => write!(f, "{:?}", other),
+        }
+    }
+}
@@ -0,0 +1,115 @@
+//! Bridge to the `log` facade.
+//!
+//! Errors are emitted using [`Error::log`](crate::Error::log) at a level depending on their code,
+//! with their class, code, request identifier and details as key-values. They can also be logged
+//! automatically on [`ErrorBuilder::build`](crate::ErrorBuilder::build) once [`set_auto_log`] is
+//! enabled, so that each error is logged once, where it is created.
+//!
+//! # Example
+//! ```
+//! use cdumay_core::{log, ErrorBuilder};
+//! use cdumay_core::kinds::http::NotFound;
+//!
+//! let err = ErrorBuilder::new(NotFound, "UnknownUser").build();
+//! assert_eq!(err.log_level(), ::log::Level::Warn);
+//! err.log();
+//!
+//! log::set_auto_log(true);
+//! let err = ErrorBuilder::new(NotFound, "UnknownUser").build(); // logged
+//! # log::set_auto_log(false);
+//! ```
+use ::log::kv::{Error, Key, Source, Value, VisitSource};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static AUTO_LOG: AtomicBool = AtomicBool::new(false);
+
+/// Enables or disables the automatic logging of every error built with `ErrorBuilder::build`.
+pub fn set_auto_log(enabled: bool) {
+    AUTO_LOG.store(enabled, Ordering::Relaxed);
+}
+
+/// Returns whether errors are logged automatically on build.
+pub fn auto_log() -> bool {
+    AUTO_LOG.load(Ordering::Relaxed)
+}
+
+/// Converts a detail value into a key-value value.
+///
+/// Scalars keep their type, other values are recorded using their debug representation.
+fn to_log_value(value: &serde_value::Value) -> Value<'_> {
+    match value {
+        serde_value::Value::Bool(value) => Value::from(*value),
+        serde_value::Value::U8(value) => Value::from(*value),
+        serde_value::Value::U16(value) => Value::from(*value),
+        serde_value::Value::U32(value) => Value::from(*value),
+        serde_value::Value::U64(value) => Value::from(*value),
+        serde_value::Value::I8(value) => Value::from(*value),
+        serde_value::Value::I16(value) => Value::from(*value),
+        serde_value::Value::I32(value) => Value::from(*value),
+        serde_value::Value::I64(value) => Value::from(*value),
+        serde_value::Value::F32(value) => Value::from(*value),
+        serde_value::Value::F64(value) => Value::from(*value),
+        serde_value::Value::Char(value) => Value::from(*value),
+        serde_value::Value::String(value) => Value::from(value.as_str()),
+        other => Value::from_debug(other),
+    }
+}
+
+/// Key-values of a logged error, the details being prefixed by `details.`.
+struct ErrorKeyValues<'a> {
+    error: &'a crate::Error,
+    details: Vec<(String, serde_value::Value)>,
+}
+
+impl Source for ErrorKeyValues<'_> {
+    fn visit<'kvs>(&'kvs self, visitor: &mut dyn VisitSource<'kvs>) -> Result<(), Error> {
+        visitor.visit_pair(Key::from_str("class"), Value::from(self.error.class_ref()))?;
+        visitor.visit_pair(Key::from_str("code"), Value::from(self.error.code()))?;
+        if let Some(request_id) = self.error.request_id() {
+            visitor.visit_pair(Key::from_str("request_id"), Value::from(request_id))?;
+        }
+        for (key, value) in &self.details {
+            visitor.visit_pair(Key::from_str(key), to_log_value(value))?;
+        }
+        Ok(())
+    }
+}
+
+impl crate::Error {
+    /// Returns the level at which the error is logged: **error** for codes 500 and above,
+    /// **warn** for codes between 400 and 499 and **info** otherwise.
+    pub fn log_level(&self) -> ::log::Level {
+        match self.code() {
+            500.. => ::log::Level::Error,
+            400..=499 => ::log::Level::Warn,
+            _ => ::log::Level::Info,
+        }
+    }
+
+    /// Emits the error through the `log` facade, with the `cdumay_core` target.
+    ///
+    /// The class, code and request identifier are recorded as key-values, along with the details
+    /// scrubbed using the global [`Redactor`](crate::Redactor) under `details.<key>`.
+    pub fn log(&self) {
+        let metadata = ::log::Metadata::builder().level(self.log_level()).target("cdumay_core").build();
+        let logger = ::log::logger();
+        if self.log_level() > ::log::max_level() || !logger.enabled(&metadata) {
+            return;
+        }
+        let key_values = ErrorKeyValues {
+            error: self,
+            details: self
+                .redacted_details()
+                .into_iter()
+                .map(|(key, value)| (format!("details.{}", key), value))
+                .collect(),
+        };
+        logger.log(
+            &::log::Record::builder()
+                .metadata(metadata)
+                .args(format_args!("{}", self))
+                .key_values(&key_values)
+                .build(),
+        );
+    }
+}
@@ -23,19 +23,251 @@
 /// ```
 ///
 /// These constants can be used directly in your code or passed into higher-level error builders.
+///
+/// Each constant accepts attributes (doc comments, `#[cfg]`, …) and a visibility modifier,
+/// constants without visibility modifier being `pub`:
+///
+/// ```rust
+/// mod kinds {
+///     use cdumay_core::define_kinds;
+///
+///     define_kinds! {
+///         /// Raised when the requested user does not exist.
+///         pub UserNotFound = (404, "User not found"),
+///         #[cfg(feature = "billing")]
+///         PaymentRequired = (402, "Payment required"),
+///         pub(crate) DatabaseDown = (503, "Database down"),
+///     }
+/// }
+///
+/// assert_eq!(kinds::UserNotFound.code(), 404);
+/// assert_eq!(kinds::DatabaseDown.code(), 503);
+/// ```
+///
+/// Kinds can be grouped in namespaces, defined as (public by default) modules, to organize them per domain. The
+/// namespace is part of the kind name, and so of the class of the errors:
+///
+/// ```rust
+/// use cdumay_core::{define_kinds, ErrorBuilder};
+///
+/// define_kinds! {
+///     pub namespace Billing {
+///         InvalidInvoice = (400, "Invalid invoice"),
+///         namespace Payment {
+///             Declined = (402, "Payment declined"),
+///         }
+///     },
+///     NotFound = (404, "Resource Not Found"),
+/// }
+///
+/// assert_eq!(Billing::InvalidInvoice.name(), "Billing::InvalidInvoice");
+/// let err = ErrorBuilder::new(Billing::Payment::Declined, "CardExpired").build();
+/// assert_eq!(err.class(), "Client::Billing::Payment::Declined::CardExpired");
+/// ```
+///
+/// The `#[docs = "..."]` attribute attaches the [URL template of the documentation](crate::ErrorKind::with_docs_url)
+/// of a kind, used by the errors raised from it. Such a kind is defined as a [`DocumentedKind`](crate::DocumentedKind):
+///
+/// ```rust
+/// use cdumay_core::{define_kinds, ErrorBuilder};
+///
+/// define_kinds! {
+///     #[docs = "https://docs.example.com/errors/{kind}"]
+///     QuotaExceeded = (429, "Quota exceeded"),
+/// }
+///
+/// let err = ErrorBuilder::new(QuotaExceeded, "DailyQuota").build();
+/// assert_eq!(err.docs_url(), Some("https://docs.example.com/errors/QuotaExceeded"));
+/// ```
+///
+/// Duplicate kind names are rejected by the compiler. Duplicate codes can be rejected as well by
+/// starting the invocation with `#![unique_codes]`:
+///
+/// ```rust,compile_fail
+/// use cdumay_core::define_kinds;
+///
+/// define_kinds! {
+///     #![unique_codes]
+///     NotFound = (404, "Resource Not Found"),
+///     UserNotFound = (404, "User Not Found"),
+/// }
+/// ```
+///
+/// ```rust,compile_fail
+/// use cdumay_core::define_kinds;
+///
+/// define_kinds! {
+///     NotFound = (404, "Resource Not Found"),
+///     NotFound = (410, "Resource Gone"),
+/// }
+/// ```
 #[macro_export]
 macro_rules! define_kinds {
     (
+        #![unique_codes]
         $(
+            $(#[$($meta:tt)*])*
             $ident:ident = ($code:expr, $description:expr)
         ),* $(,)?
     ) => {
+        $crate::define_kinds!($($(#[$($meta)*])* $ident = ($code, $description)),*);
+        $crate::define_kinds!(@unique_codes [$($ident = $code),*]);
+    };
+
+    (#![unique_codes] $($tokens:tt)*) => {
+        $crate::define_kinds!($($tokens)*);
+        $crate::define_kinds!(@codes [] $($tokens)*);
+    };
+
+    // Collects the codes of the constants to check their uniqueness
+    (@codes [$($acc:tt)*] $(#[$($meta:tt)*])* $vis:vis $ident:ident = ($code:expr, $description:expr) $(, $($rest:tt)*)?) => {
+        $crate::define_kinds!(@codes [$($acc)* $ident = $code,] $($($rest)*)?);
+    };
+
+    (@codes [$($acc:tt)*] $(#[$($meta:tt)*])* $vis:vis namespace $namespace:ident { $($inner:tt)* } $($rest:tt)*) => {
+        $crate::define_kinds!(@codes [$($acc)*] $($inner)*, $($rest)*);
+    };
+
+    (@codes [$($acc:tt)*] , $($rest:tt)*) => {
+        $crate::define_kinds!(@codes [$($acc)*] $($rest)*);
+    };
+
+    (@codes [$($ident:ident = $code:expr,)*]) => {
+        $crate::define_kinds!(@unique_codes [$($ident = $code),*]);
+    };
+
+    (@unique_codes [$($ident:ident = $code:expr),*]) => {
+        const _: () = {
+            const CODES: &[u16] = &[$($code),*];
+            const fn count(code: u16) -> usize {
+                let (mut count, mut index) = (0, 0);
+                while index < CODES.len() {
+                    if CODES[index] == code {
+                        count += 1;
+                    }
+                    index += 1;
+                }
+                count
+            }
+            $(
+                assert!(count($code) == 1, concat!("duplicate code ", stringify!($code), " for kind ", stringify!($ident)));
+            )*
+        };
+    };
+
+    (
         $(
-            #[doc = concat!("ErrorKind : ", stringify!($ident), " (", $code, ") - ", $description)]
-            #[allow(non_upper_case_globals)]
-            pub const $ident: cdumay_core::ErrorKind = cdumay_core::ErrorKind(stringify!($ident), $code, $description);
+            $(#[$($meta:tt)*])*
+            $ident:ident = ($code:expr, $description:expr)
+        ),* $(,)?
+    ) => {
+        $(
+            $crate::define_kinds!(@const [] [$(#[$($meta)*])*] [pub] $ident = ($code, $description));
         )*
     };
+
+    (@const [$($namespace:ident)*] [$($attrs:tt)*] [$vis:vis] $ident:ident = ($code:expr, $description:expr)) => {
+        $crate::define_kinds!(@attrs [$($namespace)*] [$($attrs)*] [] [] [$vis] $ident = ($code, $description));
+    };
+
+    // Separates the documentation URL template from the other attributes of a constant
+    (@attrs $namespace:tt [#[docs = $docs_url:literal] $($attrs:tt)*] [$($meta:tt)*] [$($docs:tt)*] $($tail:tt)*) => {
+        $crate::define_kinds!(@attrs $namespace [$($attrs)*] [$($meta)*] [$docs_url] $($tail)*);
+    };
+
+    (@attrs $namespace:tt [#[$($attr:tt)*] $($attrs:tt)*] [$($meta:tt)*] $($tail:tt)*) => {
+        $crate::define_kinds!(@attrs $namespace [$($attrs)*] [$($meta)* #[$($attr)*]] $($tail)*);
+    };
+
+    (@attrs [$($namespace:ident)*] [] [$($meta:tt)*] [] [$vis:vis] $ident:ident = ($code:expr, $description:expr)) => {
+        #[doc = concat!("ErrorKind : ", $(stringify!($namespace), "::",)* stringify!($ident), " (", $code, ") - ", $description)]
+        $($meta)*
+        #[allow(non_upper_case_globals)]
+        $vis const $ident: $crate::ErrorKind = $crate::ErrorKind(concat!($(stringify!($namespace), "::",)* stringify!($ident)), $code, $description);
+    };
+
+    // Constant with a documentation URL template
+    (@attrs [$($namespace:ident)*] [] [$($meta:tt)*] [$docs_url:literal] [$vis:vis] $ident:ident = ($code:expr, $description:expr)) => {
+        #[doc = concat!("ErrorKind : ", $(stringify!($namespace), "::",)* stringify!($ident), " (", $code, ") - ", $description)]
+        $($meta)*
+        #[allow(non_upper_case_globals)]
+        $vis const $ident: $crate::DocumentedKind =
+            $crate::ErrorKind(concat!($(stringify!($namespace), "::",)* stringify!($ident)), $code, $description).with_docs_url($docs_url);
+    };
+
+    // Namespace without visibility modifier, defined as a public module
+    (@munch [$($namespace:ident)*] $(#[$($meta:tt)*])* namespace $name:ident { $($inner:tt)* } $($rest:tt)*) => {
+        $crate::define_kinds!(@munch [$($namespace)*] $(#[$($meta)*])* pub namespace $name { $($inner)* } $($rest)*);
+    };
+
+    // Namespace, defined as a module
+    (@munch [$($namespace:ident)*] $(#[$($meta:tt)*])* $vis:vis namespace $name:ident { $($inner:tt)* } , $($rest:tt)*) => {
+        $crate::define_kinds!(@munch [$($namespace)*] $(#[$($meta)*])* $vis namespace $name { $($inner)* });
+        $crate::define_kinds!(@munch [$($namespace)*] $($rest)*);
+    };
+
+    (@munch [$($namespace:ident)*] $(#[$($meta:tt)*])* $vis:vis namespace $name:ident { $($inner:tt)* } $($rest:tt)*) => {
+        $(#[$($meta)*])*
+        #[allow(non_snake_case)]
+        $vis mod $name {
+            $crate::define_kinds!(@munch [$($namespace)* $name] $($inner)*);
+        }
+        $crate::define_kinds!(@munch [$($namespace)*] $($rest)*);
+    };
+
+    // Constant without visibility modifier
+    (@munch [$($namespace:ident)*] $(#[$($meta:tt)*])* $ident:ident = ($code:expr, $description:expr) $(, $($rest:tt)*)?) => {
+        $crate::define_kinds!(@const [$($namespace)*] [$(#[$($meta)*])*] [pub] $ident = ($code, $description));
+        $crate::define_kinds!(@munch [$($namespace)*] $($($rest)*)?);
+    };
+
+    // Constant with visibility modifier
+    (@munch [$($namespace:ident)*] $(#[$($meta:tt)*])* $vis:vis $ident:ident = ($code:expr, $description:expr) $(, $($rest:tt)*)?) => {
+        $crate::define_kinds!(@const [$($namespace)*] [$(#[$($meta)*])*] [$vis] $ident = ($code, $description));
+        $crate::define_kinds!(@munch [$($namespace)*] $($($rest)*)?);
+    };
+
+    (@munch [$($namespace:ident)*]) => {};
+
+    (@munch [$($namespace:ident)*] $($invalid:tt)*) => {
+        compile_error!(concat!("invalid kind definition: ", stringify!($($invalid)*)));
+    };
+
+    // At least one constant has a visibility modifier or a namespace: items are defined one by one
+    ($($tokens:tt)*) => {
+        $crate::define_kinds!(@munch [] $($tokens)*);
+    };
+}
+
+/// Implements `utoipa::IntoResponses` for an error generated by [`define_errors!`].
+#[cfg(feature = "utoipa")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __into_responses {
+    ($name:ident, $code:expr, $message:expr) => {
+        impl $crate::__private::utoipa::IntoResponses for $name {
+            fn responses() -> ::std::collections::BTreeMap<
+                String,
+                $crate::__private::utoipa::openapi::RefOr<$crate::__private::utoipa::openapi::response::Response>,
+            > {
+                let content = $crate::__private::utoipa::openapi::content::Content::new(Some(
+                    <$crate::Error as $crate::__private::utoipa::PartialSchema>::schema(),
+                ));
+                let response = $crate::__private::utoipa::openapi::response::ResponseBuilder::new()
+                    .description($message.to_string())
+                    .content("application/json", content);
+                ::std::collections::BTreeMap::from([(($code).to_string(), response.into())])
+            }
+        }
+    };
+}
+
+/// Implements `utoipa::IntoResponses` for an error generated by [`define_errors!`].
+#[cfg(not(feature = "utoipa"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __into_responses {
+    ($name:ident, $code:expr, $message:expr) => {};
 }
 
 /// Defines structured error types tied to specific `ErrorKind` constants.
@@ -64,51 +296,178 @@ macro_rules! define_kinds {
 /// }
 /// ```
 ///
+/// Errors can also carry typed payload fields. They become constructor arguments and
+/// accessors, and are automatically serialized into the error details:
+///
+/// ```rust
+/// use cdumay_core::{define_errors, define_kinds};
+///
+/// define_kinds! {
+///     Quota = (429, "Quota exceeded"),
+/// }
+///
+/// define_errors! {
+///     QuotaExceeded = Quota { limit: u64, used: u64 },
+/// }
+///
+/// let err = QuotaExceeded::new(100, 120);
+/// assert_eq!(*err.limit(), 100);
+/// assert!(err.details().contains_key("used"));
+/// ```
+///
+/// Payload fields marked with `#[redact]` are replaced by `[REDACTED]` in the error details,
+/// while remaining available through their accessor:
+///
+/// ```rust
+/// use cdumay_core::define_errors;
+/// use cdumay_core::kinds::http::Unauthorized;
+///
+/// define_errors! {
+///     InvalidCredentials = Unauthorized { login: String, #[redact] password: String },
+/// }
+///
+/// let err = InvalidCredentials::new("john".to_string(), "hunter2".to_string());
+/// assert_eq!(err.password(), "hunter2");
+/// assert_eq!(err.details()["password"], serde_value::Value::String("[REDACTED]".to_string()));
+/// ```
+///
+/// Attributes placed before an error name (doc comments, extra derives…) are applied to the
+/// generated struct:
+///
+/// ```rust
+/// use cdumay_core::define_errors;
+/// use cdumay_core::kinds::http::NotFound;
+///
+/// define_errors! {
+///     /// Raised when the user does not exist.
+///     #[derive(serde::Serialize)]
+///     UserNotFound = NotFound { id: u64 },
+/// }
+///
+/// assert_eq!(UserNotFound::new(42), UserNotFound::new(42));
+/// assert!(serde_json::to_value(UserNotFound::new(42)).is_ok());
+/// ```
+///
+/// Kinds declared in a namespace using [`define_kinds!`] are referred to by their path, without
+/// parentheses, as `(Type::Variant)` designates a variant of a type implementing [`Kind`](crate::Kind):
+///
+/// ```rust
+/// use cdumay_core::{define_errors, define_kinds};
+///
+/// define_kinds! {
+///     namespace Billing {
+///         InvalidInvoice = (400, "Invalid invoice"),
+///     }
+/// }
+///
+/// define_errors! {
+///     MissingTotal = Billing::InvalidInvoice,
+///     NegativeTotal = Billing::InvalidInvoice { total: i64 },
+/// }
+///
+/// assert_eq!(MissingTotal::new().class(), "Client::Billing::InvalidInvoice::MissingTotal");
+/// assert_eq!(NegativeTotal::new(-1).code(), 400);
+/// ```
+///
+/// With the `utoipa` feature, the generated errors implement `utoipa::IntoResponses`, so they can
+/// be listed in the `responses` of a `#[utoipa::path]` with their code and the `Error` schema.
+///
 /// This expands to:
 /// - A `struct` for each error type (e.g., `NotFoundError`)
 /// - Methods to configure error code, message, and details
+/// - Implementations of `Debug`, `Clone` and `PartialEq` (comparing the code, message and details,
+///   payload fields included), and of `Default` for errors without payload
 /// - Implementations of `std::error::Error`, `Display`, and `From<T> for Error`
 ///
 /// The generated errors are intended for use in APIs or services where structured,
 /// serializable errors are preferred.
 ///
 /// > **Note**: Requires a corresponding constant to be defined using [`define_kinds!`].
+///
+/// The generated code only refers to this crate through `$crate`, so the macro keeps working
+/// when the crate is renamed in `Cargo.toml` or wrapped by another library's macros.
 #[macro_export]
 macro_rules! define_errors {
     (
         $(
-            $name:ident = $kind_spec:tt
+            $(#[$attr:meta])*
+            $name:ident = $($kind_spec:tt)::+ $({ $($(#[$field_attr:ident])? $field:ident : $ty:ty),* $(,)? })?
         ),* $(,)?
     ) => {
         $(
-            define_errors!(@parse $name = $kind_spec);
+            $crate::define_errors!(@parse [$(#[$attr])*] $name = $($kind_spec)::+ [$($([$($field_attr)?] $field : $ty),*)?]);
         )*
     };
 
+    // Errors without payload can be created using `Default`
+    (@default $name:ident []) => {
+        impl Default for $name {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+    };
+
+    (@default $name:ident [$($fields:tt)+]) => {};
+
+    // Payload field marked with `#[redact]`
+    (@value [redact] $value:expr) => {
+        $crate::__private::serde_value::Value::String("[REDACTED]".to_string())
+    };
+
+    // Payload field
+    (@value [] $value:expr) => {
+        $crate::__private::serde_value::to_value($value).unwrap_or($crate::__private::serde_value::Value::Unit)
+    };
+
     // Error = Kind
-    (@parse $name:ident = $kind:ident) => {
-        define_errors!(@impl $name, $kind, $kind.code(), $kind.description());
+    (@parse [$($attr:tt)*] $name:ident = $kind:ident [$($fields:tt)*]) => {
+        $crate::define_errors!(@impl [$($attr)*] $name, $crate::ErrorKind, $crate::__private::ConstKind($kind).error_kind(), $kind, $kind.code(), $kind.description(), [$($fields)*]);
+    };
+
+    // Error = Namespace::Kind
+    (@parse [$($attr:tt)*] $name:ident = $($kind:ident)::+ [$($fields:tt)*]) => {
+        $crate::define_errors!(@impl [$($attr)*] $name, $crate::ErrorKind, $crate::__private::ConstKind($($kind)::+).error_kind(), $($kind)::+, $($kind)::+.code(), $($kind)::+.description(), [$($fields)*]);
     };
 
     // Error = (Kind, Code)
-    (@parse $name:ident = ($kind:ident, $code:expr)) => {
-        define_errors!(@impl $name, $kind, $code, $kind.description());
+    (@parse [$($attr:tt)*] $name:ident = ($kind:ident, $code:expr) [$($fields:tt)*]) => {
+        $crate::define_errors!(@impl [$($attr)*] $name, $crate::ErrorKind, $crate::__private::ConstKind($kind).error_kind(), $kind, $code, $kind.description(), [$($fields)*]);
     };
 
     // Error = (Kind, Code, Message)
-    (@parse $name:ident = ($kind:ident, $code:expr, $message:expr)) => {
-        define_errors!(@impl $name, $kind, $code, $message);
+    (@parse [$($attr:tt)*] $name:ident = ($kind:ident, $code:expr, $message:expr) [$($fields:tt)*]) => {
+        $crate::define_errors!(@impl [$($attr)*] $name, $crate::ErrorKind, $crate::__private::ConstKind($kind).error_kind(), $kind, $code, $message, [$($fields)*]);
     };
-    
-    (@impl $name:ident, $kind:ident, $code:expr, $message:expr) => {
+
+    // Error = (KindType::Variant)
+    (@parse [$($attr:tt)*] $name:ident = ($kind_ty:ident :: $variant:ident) [$($fields:tt)*]) => {
+        $crate::define_errors!(@impl [$($attr)*] $name, $kind_ty, $kind_ty::$variant, $kind_ty::$variant, $crate::Kind::code(&$kind_ty::$variant), $crate::Kind::description(&$kind_ty::$variant), [$($fields)*]);
+    };
+
+    // Error = (KindType::Variant, Code)
+    (@parse [$($attr:tt)*] $name:ident = ($kind_ty:ident :: $variant:ident, $code:expr) [$($fields:tt)*]) => {
+        $crate::define_errors!(@impl [$($attr)*] $name, $kind_ty, $kind_ty::$variant, $kind_ty::$variant, $code, $crate::Kind::description(&$kind_ty::$variant), [$($fields)*]);
+    };
+
+    // Error = (KindType::Variant, Code, Message)
+    (@parse [$($attr:tt)*] $name:ident = ($kind_ty:ident :: $variant:ident, $code:expr, $message:expr) [$($fields:tt)*]) => {
+        $crate::define_errors!(@impl [$($attr)*] $name, $kind_ty, $kind_ty::$variant, $kind_ty::$variant, $code, $message, [$($fields)*]);
+    };
+
+    // `$kind_const` is the value of the `kind` constant, `$kind` the kind from which the errors are built
+    (@impl [$(#[$attr:meta])*] $name:ident, $kind_ty:ty, $kind_const:expr, $kind:expr, $code:expr, $message:expr, [$([$($redact:ident)?] $field:ident : $ty:ty),*]) => {
         #[doc = concat!("Error : ", stringify!($name), " (Kind: [`", stringify!($kind), "`])")]
         #[derive(Debug, Clone)]
+        $(#[$attr])*
         pub struct $name {
+            $($field: $ty,)*
             code: Option<u16>,
             message: Option<String>,
-            details: Option<std::collections::BTreeMap<String, serde_value::Value>>,
+            details: Option<::std::collections::BTreeMap<String, $crate::__private::serde_value::Value>>,
+            source: $crate::__private::Source,
         }
-        
+
         impl $name {
             /// Creates a new `Error` instance.
             ///
@@ -122,15 +481,17 @@ macro_rules! define_errors {
             /// # Returns
             ///
             /// A new instance of `Error`.
-            pub fn new() -> Self {
+            pub fn new($($field: $ty),*) -> Self {
                 Self {
+                    $($field,)*
                     code: None,
                     message: None,
                     details: None,
+                    source: $crate::__private::Source::default(),
                 }
             }
             /// Represents a categorized error kind
-            pub const kind: cdumay_core::ErrorKind = $kind;
+            pub const kind: $kind_ty = $kind_const;
             /// Numerical status or error code (e.g., HTTP status code).
             pub fn code(&self) -> u16 {
                 self.code.unwrap_or($code)
@@ -149,37 +510,209 @@ macro_rules! define_errors {
                 self.message = Some(message);
                 self
             }
-            /// Returns a clone of the details map.
-            pub fn details(&self) -> std::collections::BTreeMap<String, serde_value::Value> {
-                self.details.clone().unwrap_or_default()
+            /// Returns a clone of the details map, including the payload fields.
+            ///
+            /// Payload fields marked with `#[redact]` are replaced by `[REDACTED]`.
+            pub fn details(&self) -> ::std::collections::BTreeMap<String, $crate::__private::serde_value::Value> {
+                #[allow(unused_mut)]
+                let mut details = self.details.clone().unwrap_or_default();
+                $(
+                    details.insert(stringify!($field).to_string(), $crate::define_errors!(@value [$($redact)?] &self.$field));
+                )*
+                details
             }
+            $(
+                #[doc = concat!("Returns the `", stringify!($field), "` payload field.")]
+                pub fn $field(&self) -> &$ty {
+                    &self.$field
+                }
+            )*
             /// Adds a structured map of additional error details.
-            pub fn with_details(mut self, details: std::collections::BTreeMap<String, serde_value::Value>) -> Self {
+            pub fn with_details(mut self, details: ::std::collections::BTreeMap<String, $crate::__private::serde_value::Value>) -> Self {
                 self.details = Some(details);
                 self
             }
+            /// Sets the underlying error, returned by `std::error::Error::source()`.
+            pub fn with_source<E: Into<Box<dyn ::std::error::Error + Send + Sync>>>(mut self, source: E) -> Self {
+                self.source = $crate::__private::Source(Some(::std::sync::Arc::from(source.into())));
+                self
+            }
             /// Returns the error class as a `String`.
             pub fn class(&self) -> String {
-                format!("{}::{}::{}", Self::kind.side(), Self::kind.name(), stringify!($name))
+                format!("{}::{}::{}", $crate::Kind::side(&Self::kind), $crate::Kind::name(&Self::kind), stringify!($name))
             }
         }
         
-        impl std::error::Error for $name {}
+        $crate::define_errors!(@default $name [$($field)*]);
+
+        $crate::__into_responses!($name, $code, $message);
+
+        // Compared as converted into an `Error`, so that payload types are not required to implement `PartialEq`
+        impl PartialEq for $name {
+            fn eq(&self, other: &Self) -> bool {
+                self.code() == other.code() && self.message() == other.message() && self.details() == other.details()
+            }
+        }
+
+        impl ::std::error::Error for $name {
+            fn source(&self) -> Option<&(dyn ::std::error::Error + 'static)> {
+                self.source.0.as_deref().map(|source| source as &(dyn ::std::error::Error + 'static))
+            }
+        }
     
-        impl From<$name> for cdumay_core::Error {
-            fn from(err: $name) -> cdumay_core::Error {
-                cdumay_core::ErrorBuilder::new($name::kind, stringify!($name))
+        impl From<$name> for $crate::Error {
+            fn from(err: $name) -> $crate::Error {
+                let builder = $crate::ErrorBuilder::new($kind, stringify!($name))
                     .with_code(err.code())
                     .with_message(err.message())
-                    .with_details(err.details())
-                    .build()
+                    .with_details(err.details());
+                match err.source.0 {
+                    Some(source) => builder.with_shared_source(source),
+                    None => builder,
+                }
+                .build()
             }
         }
 
-        impl std::fmt::Display for $name {
-            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-                write!(f, "{} ({}): {}", self.class(), self.code(), self.message())
+        impl ::std::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                write!(f, "{}::{}::{} ({}): ", $crate::Kind::side(&Self::kind), $crate::Kind::name(&Self::kind), stringify!($name), self.code())?;
+                match &self.message {
+                    Some(message) => f.write_str(message)?,
+                    None => write!(f, "{}", $message)?,
+                }
+                if f.alternate() {
+                    let source = self.source.0.as_deref().map(|source| source as &(dyn ::std::error::Error + 'static));
+                    $crate::__private::write_verbose(f, None, None, self.details(), &[], source)?;
+                }
+                Ok(())
             }
         }
     };
 }
+
+/// Builds an [`Error`](crate::Error) inline from an `ErrorKind`, an optional formatted
+/// message and optional details.
+///
+/// The kind name is used as error name, so the resulting class is `Side::Kind::Kind`.
+///
+/// # Syntax
+///
+/// ```rust
+/// use cdumay_core::{define_kinds, err};
+///
+/// define_kinds! {
+///     NotFound = (404, "Resource Not Found"),
+/// }
+///
+/// let id = 42;
+/// let error = err!(NotFound);
+/// let error = err!(NotFound, "user {} not found", id);
+/// let error = err!(NotFound, "user {} not found", id; { "user_id" => id });
+/// assert_eq!(error.message(), "user 42 not found");
+/// assert!(error.details().contains_key("user_id"));
+/// ```
+#[macro_export]
+macro_rules! err {
+    ($kind:expr $(,)?) => {{
+        let kind = $kind;
+        let name = $crate::Kind::name(&kind);
+        $crate::ErrorBuilder::new(kind, name).build()
+    }};
+    ($kind:expr; { $($key:expr => $value:expr),* $(,)? }) => {{
+        let kind = $kind;
+        let name = $crate::Kind::name(&kind);
+        $crate::ErrorBuilder::new(kind, name)
+            .with_details($crate::err!(@details $($key => $value),*))
+            .build()
+    }};
+    ($kind:expr, $fmt:literal $(, $arg:expr)* $(,)? $(; { $($key:expr => $value:expr),* $(,)? })?) => {{
+        let kind = $kind;
+        let name = $crate::Kind::name(&kind);
+        $crate::ErrorBuilder::new(kind, name)
+            .with_message(format!($fmt $(, $arg)*))
+            .with_details($crate::err!(@details $($($key => $value),*)?))
+            .build()
+    }};
+    (@details $($key:expr => $value:expr),*) => {{
+        #[allow(unused_mut)]
+        let mut details = ::std::collections::BTreeMap::new();
+        $(
+            details.insert($key.to_string(), $crate::__private::serde_value::to_value(&$value).unwrap_or($crate::__private::serde_value::Value::Unit));
+        )*
+        details
+    }};
+}
+
+/// Returns early with an [`Error`](crate::Error) built using [`err!`](crate::err).
+///
+/// # Example
+///
+/// ```rust
+/// use cdumay_core::{bail, define_kinds, Result};
+///
+/// define_kinds! {
+///     NotFound = (404, "Resource Not Found"),
+/// }
+///
+/// fn find_user(id: u64) -> Result<String> {
+///     bail!(NotFound, "user {} not found", id; { "user_id" => id })
+/// }
+///
+/// assert_eq!(find_user(42).unwrap_err().code(), 404);
+/// ```
+#[macro_export]
+macro_rules! bail {
+    ($($tt:tt)+) => {
+        return ::std::result::Result::Err($crate::err!($($tt)+).into())
+    };
+}
+
+/// Returns early with an [`Error`](crate::Error) built using [`err!`](crate::err) if the
+/// condition is not satisfied.
+///
+/// # Example
+///
+/// ```rust
+/// use cdumay_core::{define_kinds, ensure, Result};
+///
+/// define_kinds! {
+///     BadRequest = (400, "Bad Request"),
+/// }
+///
+/// fn check_age(age: u8) -> Result<u8> {
+///     ensure!(age >= 18, BadRequest, "age must be at least 18, got {}", age);
+///     Ok(age)
+/// }
+///
+/// assert!(check_age(21).is_ok());
+/// assert_eq!(check_age(12).unwrap_err().message(), "age must be at least 18, got 12");
+/// ```
+#[macro_export]
+macro_rules! ensure {
+    ($cond:expr, $($tt:tt)+) => {
+        if !$cond {
+            $crate::bail!($($tt)+);
+        }
+    };
+}
+
+/// Returns the [`CodeLocation`](crate::CodeLocation) where it is invoked, including the module path.
+///
+/// # Example
+/// ```
+/// let location = cdumay_core::here!();
+/// assert_eq!(location.module, Some(module_path!()));
+/// assert_eq!(location.line, line!() - 2);
+/// ```
+#[macro_export]
+macro_rules! here {
+    () => {
+        $crate::CodeLocation {
+            file: file!(),
+            line: line!(),
+            column: column!(),
+            module: Some(module_path!()),
+        }
+    };
+}
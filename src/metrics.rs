@@ -0,0 +1,60 @@
+//! Prometheus metrics for error counting.
+//!
+//! Errors are counted by the `cdumay_errors_total` counter, labeled by `class`, `code` and
+//! `side`. Errors can be recorded explicitly using [`Error::record`](crate::Error::record),
+//! or automatically on [`ErrorBuilder::build`](crate::ErrorBuilder::build) once
+//! [`set_auto_record`] is enabled.
+//!
+//! # Example
+//! ```
+//! use cdumay_core::{metrics, ErrorBuilder};
+//!
+//! let registry = prometheus::Registry::new();
+//! metrics::register(&registry).unwrap();
+//!
+//! ErrorBuilder::default().build().record();
+//! assert_eq!(
+//!     metrics::error_counter().with_label_values(&["Server::InternalServerError::UnknownError", "500", "Server"]).get(),
+//!     1
+//! );
+//! ```
+use prometheus::{IntCounterVec, Opts, Registry};
+use std::sync::LazyLock;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static ERROR_COUNTER: LazyLock<IntCounterVec> = LazyLock::new(|| {
+    IntCounterVec::new(Opts::new("cdumay_errors_total", "Number of errors, by class, code and side"), &["class", "code", "side"])
+        .expect("valid metric definition")
+});
+
+static AUTO_RECORD: AtomicBool = AtomicBool::new(false);
+
+/// Returns the counter vector used to count errors.
+pub fn error_counter() -> &'static IntCounterVec {
+    &ERROR_COUNTER
+}
+
+/// Registers the error counter into the given Prometheus registry.
+pub fn register(registry: &Registry) -> prometheus::Result<()> {
+    registry.register(Box::new(ERROR_COUNTER.clone()))
+}
+
+/// Enables or disables the automatic recording of every error built with `ErrorBuilder::build`.
+pub fn set_auto_record(enabled: bool) {
+    AUTO_RECORD.store(enabled, Ordering::Relaxed);
+}
+
+/// Returns whether errors are recorded automatically on build.
+pub fn auto_record() -> bool {
+    AUTO_RECORD.load(Ordering::Relaxed)
+}
+
+impl crate::Error {
+    /// Increments the error counter for this error class, code and side.
+    pub fn record(&self) {
+        let side = self.class_ref().split("::").next().unwrap_or_default();
+        ERROR_COUNTER
+            .with_label_values(&[self.class_ref(), &self.code().to_string(), side])
+            .inc();
+    }
+}
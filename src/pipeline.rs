@@ -0,0 +1,65 @@
+//! Ordered transformations applied to errors before they leave the application.
+//!
+//! Applications register [`ErrorTransformer`] hooks (redaction, code remapping, enrichment…)
+//! which are applied in registration order by [`Error::finalize`](crate::Error::finalize). The
+//! HTTP responders (`actix-web` and `warp` features) finalize errors automatically, so that
+//! cross-cutting policies live in one place instead of every converter.
+//!
+//! # Example
+//! ```
+//! use cdumay_core::{pipeline, Error, ErrorBuilder};
+//!
+//! pipeline::register(|error: Error| {
+//!     match error.code() {
+//!         404 => error.to_builder().with_code(410).build(),
+//!         _ => error,
+//!     }
+//! });
+//!
+//! let err = ErrorBuilder::new(cdumay_core::kinds::http::NotFound, "PageNotFound").build();
+//! assert_eq!(err.finalize().code(), 410);
+//! # pipeline::clear();
+//! ```
+use std::sync::{Arc, PoisonError, RwLock};
+
+/// A transformation applied to errors by [`Error::finalize`](crate::Error::finalize).
+///
+/// It is implemented for any `Fn(Error) -> Error` closure.
+pub trait ErrorTransformer: Send + Sync {
+    /// Transforms the given error.
+    fn transform(&self, error: crate::Error) -> crate::Error;
+}
+
+impl<F: Fn(crate::Error) -> crate::Error + Send + Sync> ErrorTransformer for F {
+    fn transform(&self, error: crate::Error) -> crate::Error {
+        self(error)
+    }
+}
+
+static TRANSFORMERS: RwLock<Vec<Arc<dyn ErrorTransformer>>> = RwLock::new(Vec::new());
+
+/// Appends a transformer to the pipeline.
+pub fn register<T: ErrorTransformer + 'static>(transformer: T) {
+    TRANSFORMERS.write().unwrap_or_else(PoisonError::into_inner).push(Arc::new(transformer));
+}
+
+/// Removes all the transformers of the pipeline.
+pub fn clear() {
+    TRANSFORMERS.write().unwrap_or_else(PoisonError::into_inner).clear();
+}
+
+impl crate::Error {
+    /// Applies the registered transformers to the error, in registration order.
+    ///
+    /// An error which was already finalized is returned unchanged, so that the transformers run
+    /// once even when an error goes through several responders or middlewares.
+    pub fn finalize(self) -> Self {
+        if self.metadata.finalized {
+            return self;
+        }
+        let transformers = TRANSFORMERS.read().unwrap_or_else(PoisonError::into_inner).clone();
+        let mut error = transformers.iter().fold(self, |error, transformer| transformer.transform(error));
+        error.metadata_mut().finalized = true;
+        error
+    }
+}
@@ -0,0 +1,251 @@
+//! Reporting of errors to an external backend (Sentry, Slack, webhook…).
+//!
+//! Applications plug a backend by setting a global [`ErrorReporter`] using
+//! [`set_global_reporter`]. Errors are reported explicitly using
+//! [`Error::report`](crate::Error::report), or automatically on
+//! [`ErrorBuilder::build`](crate::ErrorBuilder::build) when their
+//! [syslog severity](crate::Error::syslog_severity) reaches the threshold set using
+//! [`set_auto_report_threshold`].
+//!
+//! Reports can be rate limited per [fingerprint](crate::Error::fingerprint) and sampled using
+//! [`set_report_limits`], so that a hot failure loop does not flood the backend. The number of
+//! reports suppressed for a fingerprint is recorded under the `suppressed` detail of its next
+//! emitted report.
+//!
+//! # Example
+//! ```
+//! use std::sync::atomic::{AtomicUsize, Ordering};
+//! use cdumay_core::{report, Error, ErrorBuilder, SyslogSeverity};
+//! use cdumay_core::kinds::http::{BadGateway, NotFound};
+//!
+//! static REPORTED: AtomicUsize = AtomicUsize::new(0);
+//!
+//! report::set_global_reporter(|_: &Error| {
+//!     REPORTED.fetch_add(1, Ordering::Relaxed);
+//! });
+//! ErrorBuilder::new(NotFound, "UnknownUser").build().report();
+//! assert_eq!(REPORTED.load(Ordering::Relaxed), 1);
+//!
+//! report::set_auto_report_threshold(Some(SyslogSeverity::Error));
+//! ErrorBuilder::new(NotFound, "UnknownUser").build();
+//! ErrorBuilder::new(BadGateway, "UpstreamError").build();
+//! assert_eq!(REPORTED.load(Ordering::Relaxed), 2);
+//! # report::set_auto_report_threshold(None);
+//! # report::clear_global_reporter();
+//! ```
+use std::cell::Cell;
+use std::collections::BTreeMap;
+use std::hash::{BuildHasher, RandomState};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, PoisonError, RwLock};
+use std::time::{Duration, Instant};
+
+/// A backend to which errors are reported by [`Error::report`](crate::Error::report).
+///
+/// It is implemented for any `Fn(&Error)` closure.
+pub trait ErrorReporter: Send + Sync {
+    /// Reports the given error.
+    fn report(&self, error: &crate::Error);
+}
+
+impl<F: Fn(&crate::Error) + Send + Sync> ErrorReporter for F {
+    fn report(&self, error: &crate::Error) {
+        self(error)
+    }
+}
+
+/// Limits applied to the reports, see [`set_report_limits`].
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use cdumay_core::report::{self, ReportLimits};
+///
+/// // At most 10 reports per fingerprint and minute, a quarter of them being kept
+/// report::set_report_limits(ReportLimits::new().with_rate_limit(10, Duration::from_secs(60)).with_sample_rate(0.25));
+/// # report::set_report_limits(ReportLimits::new());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReportLimits {
+    rate_limit: Option<(u32, Duration)>,
+    sample_rate: f64,
+}
+
+impl ReportLimits {
+    /// Creates limits letting every report through.
+    pub const fn new() -> Self {
+        Self {
+            rate_limit: None,
+            sample_rate: 1.0,
+        }
+    }
+
+    /// Allows at most `max_reports` reports per fingerprint during each `window`.
+    pub fn with_rate_limit(mut self, max_reports: u32, window: Duration) -> Self {
+        self.rate_limit = Some((max_reports, window));
+        self
+    }
+
+    /// Keeps the given ratio of the reports (between `0.0` and `1.0`), chosen at random.
+    pub fn with_sample_rate(mut self, sample_rate: f64) -> Self {
+        self.sample_rate = sample_rate.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Returns the maximum number of reports per fingerprint and its window, if rate limited.
+    pub fn rate_limit(&self) -> Option<(u32, Duration)> {
+        self.rate_limit
+    }
+
+    /// Returns the ratio of the reports which are kept.
+    pub fn sample_rate(&self) -> f64 {
+        self.sample_rate
+    }
+}
+
+impl Default for ReportLimits {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Reporting state of a fingerprint.
+struct FingerprintState {
+    window_start: Instant,
+    reported: u32,
+    suppressed: u64,
+}
+
+static REPORTER: RwLock<Option<Arc<dyn ErrorReporter>>> = RwLock::new(None);
+static AUTO_REPORT_THRESHOLD: RwLock<Option<crate::SyslogSeverity>> = RwLock::new(None);
+static LIMITS: RwLock<ReportLimits> = RwLock::new(ReportLimits::new());
+static FINGERPRINTS: Mutex<BTreeMap<String, FingerprintState>> = Mutex::new(BTreeMap::new());
+static SAMPLES: AtomicU64 = AtomicU64::new(0);
+
+thread_local! {
+    /// Whether an error is being reported by the current thread, to ignore the errors built by the reporter itself.
+    static REPORTING: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Resets the reporting flag of the current thread when dropped, even if the reporter panics.
+struct ReportingGuard;
+
+impl Drop for ReportingGuard {
+    fn drop(&mut self) {
+        REPORTING.set(false);
+    }
+}
+
+/// Sets the global reporter, replacing the previous one.
+pub fn set_global_reporter<R: ErrorReporter + 'static>(reporter: R) {
+    *REPORTER.write().unwrap_or_else(PoisonError::into_inner) = Some(Arc::new(reporter));
+}
+
+/// Removes the global reporter: errors are no longer reported.
+pub fn clear_global_reporter() {
+    *REPORTER.write().unwrap_or_else(PoisonError::into_inner) = None;
+}
+
+/// Sets the least severe level of the errors reported automatically on build, or disables the
+/// automatic reporting with `None` (the default).
+pub fn set_auto_report_threshold(threshold: Option<crate::SyslogSeverity>) {
+    *AUTO_REPORT_THRESHOLD.write().unwrap_or_else(PoisonError::into_inner) = threshold;
+}
+
+/// Returns the least severe level of the errors reported automatically on build.
+pub fn auto_report_threshold() -> Option<crate::SyslogSeverity> {
+    *AUTO_REPORT_THRESHOLD.read().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Sets the limits applied to the reports, resetting the state of the rate limiting.
+pub fn set_report_limits(limits: ReportLimits) {
+    *LIMITS.write().unwrap_or_else(PoisonError::into_inner) = limits;
+    FINGERPRINTS.lock().unwrap_or_else(PoisonError::into_inner).clear();
+}
+
+/// Returns the limits applied to the reports.
+pub fn report_limits() -> ReportLimits {
+    LIMITS.read().unwrap_or_else(PoisonError::into_inner).clone()
+}
+
+/// Returns whether a report is kept by the sampling.
+fn sampled(sample_rate: f64) -> bool {
+    match sample_rate {
+        1.0 => true,
+        0.0 => false,
+        // Hashes a counter using a randomly seeded hasher to obtain a uniform value
+        _ => (RandomState::new().hash_one(SAMPLES.fetch_add(1, Ordering::Relaxed)) as f64) < sample_rate * u64::MAX as f64,
+    }
+}
+
+/// Applies the limits to a report of the error.
+///
+/// Returns `None` if the report must be suppressed, or the number of reports suppressed since
+/// the last report of the same fingerprint.
+fn admit(error: &crate::Error) -> Option<u64> {
+    let limits = report_limits();
+    if limits.rate_limit.is_none() && limits.sample_rate >= 1.0 {
+        return Some(0);
+    }
+    let now = Instant::now();
+    let mut fingerprints = FINGERPRINTS.lock().unwrap_or_else(PoisonError::into_inner);
+    let fingerprint = error.fingerprint();
+    if !fingerprints.contains_key(&fingerprint) {
+        // Forgets the fingerprints which are no longer limited
+        let window = limits.rate_limit.map(|(_, window)| window).unwrap_or_default();
+        fingerprints.retain(|_, state| state.suppressed > 0 || now.duration_since(state.window_start) < window);
+    }
+    let state = fingerprints.entry(fingerprint).or_insert(FingerprintState {
+        window_start: now,
+        reported: 0,
+        suppressed: 0,
+    });
+    if let Some((max_reports, window)) = limits.rate_limit {
+        if now.duration_since(state.window_start) >= window {
+            state.window_start = now;
+            state.reported = 0;
+        }
+        if state.reported >= max_reports {
+            state.suppressed += 1;
+            return None;
+        }
+    }
+    if !sampled(limits.sample_rate) {
+        state.suppressed += 1;
+        return None;
+    }
+    state.reported += 1;
+    Some(std::mem::take(&mut state.suppressed))
+}
+
+/// Reports the error if its severity reaches the automatic reporting threshold.
+pub(crate) fn auto_report(error: &crate::Error) {
+    if auto_report_threshold().is_some_and(|threshold| error.syslog_severity() <= threshold) {
+        error.report();
+    }
+}
+
+impl crate::Error {
+    /// Reports the error using the global reporter, if any, within the [limits](ReportLimits).
+    ///
+    /// Errors reported while the reporter runs on the same thread are ignored, so that a
+    /// failing reporter cannot loop.
+    pub fn report(&self) {
+        let Some(reporter) = REPORTER.read().unwrap_or_else(PoisonError::into_inner).clone() else {
+            return;
+        };
+        if REPORTING.replace(true) {
+            return;
+        }
+        let _guard = ReportingGuard;
+        match admit(self) {
+            None => {}
+            Some(0) => reporter.report(self),
+            Some(suppressed) => {
+                let mut error = self.clone();
+                error.details_mut().insert("suppressed".to_string(), serde_value::Value::U64(suppressed));
+                reporter.report(&error);
+            }
+        }
+    }
+}
@@ -0,0 +1,306 @@
+/// A `std::result::Result` using [`Error`](crate::Error) as error type.
+///
+/// As it is an alias of the standard type, all its methods are available, including the
+/// `unwrap` family (`unwrap`, `expect`, `unwrap_or`, `unwrap_or_else`, `unwrap_or_default`),
+/// `ok()`, `err()`, `is_ok()` and `is_err()`:
+///
+/// ```
+/// use cdumay_core::{ErrorBuilder, Result};
+///
+/// let result: Result<u32> = Err(ErrorBuilder::default().build());
+/// assert!(result.is_err());
+/// assert_eq!(result.clone().unwrap_or_default(), 0);
+/// assert_eq!(result.err().map(|err| err.code()), Some(500));
+/// ```
+///
+/// Likewise, all its combinators are available. For instance,
+/// an iterator of `Result<T>` can be collected into a `Result<Vec<T>>`, stopping at the
+/// first error:
+///
+/// ```
+/// use cdumay_core::{ErrorBuilder, Result};
+///
+/// let results: Vec<Result<u32>> = vec![Ok(1), Err(ErrorBuilder::default().build()), Ok(3)];
+/// let collected: Result<Vec<u32>> = results.into_iter().collect();
+/// assert_eq!(collected.unwrap_err().code(), 500);
+/// ```
+///
+/// `iter()`, `iter_mut()` and `IntoIterator` yield the success value, if any, so that a
+/// `Result<T>` composes with iterator adapters such as `flatten()`:
+///
+/// ```
+/// use cdumay_core::{ErrorBuilder, Result};
+///
+/// let mut result: Result<u32> = Ok(1);
+/// for value in result.iter_mut() {
+///     *value += 1;
+/// }
+/// assert_eq!(result.iter().next(), Some(&2));
+///
+/// let results: Vec<Result<u32>> = vec![Ok(1), Err(ErrorBuilder::default().build()), Ok(3)];
+/// let values: Vec<u32> = results.into_iter().flatten().collect();
+/// assert_eq!(values, vec![1, 3]);
+/// ```
+///
+/// Side effects such as logging can be inserted in a chain using `inspect()` and `inspect_err()`:
+///
+/// ```
+/// use cdumay_core::{ErrorBuilder, Result};
+///
+/// let mut logs = Vec::new();
+/// let result: Result<u32> = Err(ErrorBuilder::default().build());
+/// let value = result
+///     .inspect_err(|err| logs.push(err.to_string()))
+///     .unwrap_or_default();
+/// assert_eq!(value, 0);
+/// assert_eq!(logs, ["Server::InternalServerError::UnknownError (500) - Internal Server Error"]);
+/// ```
+///
+/// As `Error` is `Clone`, `Eq`, `Hash` and `Ord`, a `Result<T>` implements these traits whenever
+/// `T` does, so that it can be compared in tests or kept in caches and sets:
+///
+/// ```
+/// use std::collections::HashMap;
+/// use cdumay_core::{ErrorBuilder, Result};
+/// use cdumay_core::kinds::http::NotFound;
+///
+/// let mut cache: HashMap<u64, Result<String>> = HashMap::new();
+/// let missing = ErrorBuilder::new(NotFound, "UnknownUser").build();
+/// cache.insert(1, Ok("john".to_string()));
+/// cache.insert(2, Err(missing.clone()));
+/// assert_eq!(cache[&1], Ok("john".to_string()));
+/// assert_eq!(cache.get(&2).cloned(), Some(Err(missing)));
+/// ```
+pub type Result<D> = std::result::Result<D, crate::Error>;
+
+/// Extension trait for iterators over [`Result<T>`].
+pub trait ResultIteratorExt<T>: Iterator<Item = Result<T>> + Sized {
+    /// Consumes the whole iterator and splits successes from errors.
+    ///
+    /// Unlike `collect::<Result<Vec<T>>>()`, this does not stop at the first error.
+    ///
+    /// # Example
+    /// ```
+    /// use cdumay_core::{ErrorBuilder, Result, ResultIteratorExt};
+    ///
+    /// let results: Vec<Result<u32>> = vec![Ok(1), Err(ErrorBuilder::default().build()), Ok(3)];
+    /// let (values, errors) = results.into_iter().partition_results();
+    /// assert_eq!(values, vec![1, 3]);
+    /// assert_eq!(errors.len(), 1);
+    /// ```
+    fn partition_results(self) -> (Vec<T>, Vec<crate::Error>) {
+        let mut values = Vec::new();
+        let mut errors = Vec::new();
+        for result in self {
+            match result {
+                Ok(value) => values.push(value),
+                Err(error) => errors.push(error),
+            }
+        }
+        (values, errors)
+    }
+}
+
+impl<T, I: Iterator<Item = Result<T>>> ResultIteratorExt<T> for I {}
+
+/// Extension trait to add context frames to the error of a [`Result<T>`].
+pub trait ResultContextExt<T> {
+    /// Pushes a context frame onto the error, if any.
+    ///
+    /// # Example
+    /// ```
+    /// use cdumay_core::{ErrorBuilder, Result, ResultContextExt};
+    ///
+    /// fn load_profile() -> Result<()> {
+    ///     Err(ErrorBuilder::default().build())
+    /// }
+    ///
+    /// let err = load_profile().with_context("while loading user profile").unwrap_err();
+    /// assert_eq!(err.context(), ["while loading user profile"]);
+    /// ```
+    fn with_context<C: Into<String>>(self, frame: C) -> Result<T>;
+}
+
+impl<T> ResultContextExt<T> for Result<T> {
+    fn with_context<C: Into<String>>(self, frame: C) -> Result<T> {
+        self.map_err(|error| error.with_context(frame))
+    }
+}
+
+/// Extension trait for futures resolving to a [`Result<T>`].
+///
+/// The combinators mirror those of `Result<T>`, without having to await and rebind the result
+/// at each step.
+///
+/// # Example
+/// ```
+/// use cdumay_core::{AsyncResultExt, ErrorBuilder, Result};
+///
+/// async fn fetch_user(id: u64) -> Result<String> {
+///     Ok(format!("user-{id}"))
+/// }
+///
+/// async fn fetch_profile(user: String) -> Result<String> {
+///     Err(ErrorBuilder::default().with_message(format!("no profile for {user}")).build())
+/// }
+///
+/// # let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+/// # runtime.block_on(async {
+/// let name = fetch_user(42).map_ok(|user| user.to_uppercase()).await;
+/// assert_eq!(name.unwrap(), "USER-42");
+///
+/// let err = fetch_user(42)
+///     .and_then_async(fetch_profile)
+///     .with_context("while loading the profile page")
+///     .await
+///     .unwrap_err();
+/// assert_eq!(err.message(), "no profile for user-42");
+/// assert_eq!(err.context(), ["while loading the profile page"]);
+/// # });
+/// ```
+pub trait AsyncResultExt<T>: Future<Output = Result<T>> + Sized {
+    /// Maps the success value using the given closure.
+    fn map_ok<U, F>(self, f: F) -> impl Future<Output = Result<U>>
+    where
+        F: FnOnce(T) -> U,
+    {
+        async move { self.await.map(f) }
+    }
+
+    /// Maps the success value using the given asynchronous closure.
+    fn map_async<U, F, Fut>(self, f: F) -> impl Future<Output = Result<U>>
+    where
+        F: FnOnce(T) -> Fut,
+        Fut: Future<Output = U>,
+    {
+        async move {
+            match self.await {
+                Ok(value) => Ok(f(value).await),
+                Err(error) => Err(error),
+            }
+        }
+    }
+
+    /// Chains an asynchronous operation on the success value.
+    fn and_then_async<U, F, Fut>(self, f: F) -> impl Future<Output = Result<U>>
+    where
+        F: FnOnce(T) -> Fut,
+        Fut: Future<Output = Result<U>>,
+    {
+        async move {
+            match self.await {
+                Ok(value) => f(value).await,
+                Err(error) => Err(error),
+            }
+        }
+    }
+
+    /// Maps the error using the given closure.
+    fn map_err_async<F>(self, f: F) -> impl Future<Output = Result<T>>
+    where
+        F: FnOnce(crate::Error) -> crate::Error,
+    {
+        async move { self.await.map_err(f) }
+    }
+
+    /// Recovers from the error using the given asynchronous closure.
+    fn or_else_async<F, Fut>(self, f: F) -> impl Future<Output = Result<T>>
+    where
+        F: FnOnce(crate::Error) -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        async move {
+            match self.await {
+                Ok(value) => Ok(value),
+                Err(error) => f(error).await,
+            }
+        }
+    }
+
+    /// Pushes a context frame onto the error, if any, see [`ResultContextExt::with_context`].
+    fn with_context<C: Into<String>>(self, frame: C) -> impl Future<Output = Result<T>> {
+        async move { self.await.with_context(frame) }
+    }
+}
+
+impl<T, F: Future<Output = Result<T>>> AsyncResultExt<T> for F {}
+
+/// A serializable envelope for a [`Result<T>`], tagged as `{"ok": ...}` or `{"err": {...}}`.
+///
+/// `Result<T>` itself is serializable using the standard serde representation
+/// (`{"Ok": ...}` / `{"Err": {...}}`); this envelope provides a lowercase tagging suited
+/// for persisting operation outcomes, e.g. in task queues. Enable the `wire` feature to keep
+/// the error code in the serialized form.
+///
+/// # Example
+/// ```
+/// use cdumay_core::{ErrorBuilder, Result, ResultEnvelope};
+///
+/// let result: Result<u32> = Ok(42);
+/// let json = serde_json::to_string(&ResultEnvelope::from(result)).unwrap();
+/// assert_eq!(json, r#"{"ok":42}"#);
+///
+/// let envelope: ResultEnvelope<u32> = serde_json::from_str(r#"{"err":{"class":"Server::InternalServerError::UnknownError","message":"Oops","details":{}}}"#).unwrap();
+/// let result: Result<u32> = envelope.into();
+/// assert_eq!(result.unwrap_err().message(), "Oops");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ResultEnvelope<T> {
+    /// Contains the success value.
+    Ok(T),
+    /// Contains the error value.
+    Err(crate::Error),
+}
+
+impl<T> From<Result<T>> for ResultEnvelope<T> {
+    fn from(result: Result<T>) -> Self {
+        match result {
+            Ok(value) => ResultEnvelope::Ok(value),
+            Err(error) => ResultEnvelope::Err(error),
+        }
+    }
+}
+
+impl<T> From<ResultEnvelope<T>> for Result<T> {
+    fn from(envelope: ResultEnvelope<T>) -> Self {
+        match envelope {
+            ResultEnvelope::Ok(value) => Ok(value),
+            ResultEnvelope::Err(error) => Err(error),
+        }
+    }
+}
+
+/// Serde helpers to (de)serialize a [`Result<T>`] field using the [`ResultEnvelope`] format.
+///
+/// # Example
+/// ```
+/// use cdumay_core::Result;
+///
+/// #[derive(serde::Serialize, serde::Deserialize)]
+/// struct TaskOutcome {
+///     id: u64,
+///     #[serde(with = "cdumay_core::result_envelope")]
+///     result: Result<String>,
+/// }
+///
+/// let outcome = TaskOutcome { id: 1, result: Ok("done".to_string()) };
+/// assert_eq!(serde_json::to_string(&outcome).unwrap(), r#"{"id":1,"result":{"ok":"done"}}"#);
+/// ```
+pub mod envelope {
+    use super::{Result, ResultEnvelope};
+
+    /// Serializes a `Result<T>` as a `ResultEnvelope<T>`.
+    pub fn serialize<S: serde::Serializer, T: serde::Serialize>(result: &Result<T>, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        let envelope = match result {
+            Ok(value) => ResultEnvelope::Ok(value),
+            Err(error) => ResultEnvelope::Err(error.clone()),
+        };
+        serde::Serialize::serialize(&envelope, serializer)
+    }
+
+    /// Deserializes a `Result<T>` from a `ResultEnvelope<T>`.
+    pub fn deserialize<'de, D: serde::Deserializer<'de>, T: serde::Deserialize<'de>>(deserializer: D) -> std::result::Result<Result<T>, D::Error> {
+        <ResultEnvelope<T> as serde::Deserialize>::deserialize(deserializer).map(Into::into)
+    }
+}
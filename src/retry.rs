@@ -0,0 +1,132 @@
+//! Retry of fallible asynchronous operations, driven by the errors they produce.
+//!
+//! [`retry_async`] re-runs an operation as long as it fails with a
+//! [retryable](crate::Error::is_retryable) error, waiting between attempts with an exponential
+//! backoff. The number of attempts is recorded in the details of the final error under the
+//! `attempts` key.
+//!
+//! # Example
+//! ```
+//! use std::time::Duration;
+//! use cdumay_core::retry::{retry_async, RetryPolicy};
+//! use cdumay_core::kinds::http::{NotFound, ServiceUnavailable};
+//! use cdumay_core::{ErrorBuilder, Result};
+//!
+//! let policy = RetryPolicy::new()
+//!     .with_max_attempts(3)
+//!     .with_initial_delay(Duration::from_millis(1))
+//!     .with_kind_override(&NotFound, 2);
+//!
+//! # let runtime = tokio::runtime::Builder::new_current_thread().enable_time().build().unwrap();
+//! # runtime.block_on(async {
+//! let result: Result<()> = retry_async(&policy, || async { Err(ErrorBuilder::new(ServiceUnavailable, "Maintenance").build()) }).await;
+//! assert_eq!(result.unwrap_err().details()["attempts"], serde_value::Value::U32(3));
+//! # });
+//! ```
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+/// Controls how many times and how often an operation is retried.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    initial_delay: Duration,
+    max_delay: Duration,
+    multiplier: u32,
+    overrides: BTreeMap<String, u32>,
+}
+
+impl RetryPolicy {
+    /// Creates a policy making up to 3 attempts, waiting 100ms then doubling the delay, up to 10s.
+    pub fn new() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(10),
+            multiplier: 2,
+            overrides: BTreeMap::new(),
+        }
+    }
+
+    /// Sets the maximum number of attempts, including the first one.
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    /// Sets the delay before the first retry.
+    pub fn with_initial_delay(mut self, initial_delay: Duration) -> Self {
+        self.initial_delay = initial_delay;
+        self
+    }
+
+    /// Sets the maximum delay between two attempts.
+    pub fn with_max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Sets the factor applied to the delay after each retry.
+    pub fn with_multiplier(mut self, multiplier: u32) -> Self {
+        self.multiplier = multiplier;
+        self
+    }
+
+    /// Sets the maximum number of attempts for errors raised from the given kind.
+    ///
+    /// Errors of this kind are retried even if they are not marked retryable, unless
+    /// `max_attempts` is `1`.
+    pub fn with_kind_override<K: crate::Kind>(mut self, kind: &K, max_attempts: u32) -> Self {
+        self.overrides.insert(kind.name().to_string(), max_attempts);
+        self
+    }
+
+    /// Returns the delay to wait after the given failed attempt (starting at 1).
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let factor = self.multiplier.saturating_pow(attempt.saturating_sub(1));
+        self.initial_delay.saturating_mul(factor).min(self.max_delay)
+    }
+
+    /// Returns the maximum number of attempts for the given error, or `None` if it must not be retried.
+    fn max_attempts_for(&self, error: &crate::Error) -> Option<u32> {
+        match error.kind().and_then(|kind| self.overrides.get(&kind.name)) {
+            Some(max_attempts) => Some(*max_attempts),
+            None => error.is_retryable().then_some(self.max_attempts),
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Runs the operation until it succeeds, fails with an error which must not be retried, or the
+/// maximum number of attempts is reached.
+///
+/// The number of attempts is recorded in the details of the returned error under the
+/// `attempts` key.
+pub async fn retry_async<T, F, Fut>(policy: &RetryPolicy, mut operation: F) -> crate::Result<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = crate::Result<T>>,
+{
+    let mut attempt = 1;
+    loop {
+        let mut error = match operation().await {
+            Ok(value) => return Ok(value),
+            Err(error) => error,
+        };
+        match policy.max_attempts_for(&error) {
+            Some(max_attempts) if attempt < max_attempts => {
+                tokio::time::sleep(policy.delay(attempt)).await;
+                attempt += 1;
+            }
+            _ => {
+                error.details_mut().insert("attempts".to_string(), serde_value::Value::U32(attempt));
+                return Err(error);
+            }
+        }
+    }
+}
@@ -0,0 +1,172 @@
+//! Assertions on errors for test suites, instead of comparing their display.
+//!
+//! The assertions accept an [`Error`](crate::Error) or a [`Result`](crate::Result), which must be
+//! an `Err`, and panic with the expected and actual values along with the verbose display of the
+//! error. They are available as functions and as macros.
+//!
+//! # Example
+//! ```
+//! use cdumay_core::kinds::http::NotFound;
+//! use cdumay_core::{ErrorBuilder, Result};
+//! use cdumay_core::{assert_error_class, assert_error_code, assert_error_detail, assert_error_kind};
+//!
+//! fn find_user(id: u64) -> Result<String> {
+//!     Err(ErrorBuilder::new(NotFound, "UnknownUser").with_detail("id", serde_value::Value::U64(id)).build())
+//! }
+//!
+//! assert_error_kind!(find_user(42), NotFound);
+//! assert_error_code!(find_user(42), 404);
+//! assert_error_class!(find_user(42), "Client::NotFound");
+//! assert_error_detail!(find_user(42), "id");
+//! assert_error_detail!(find_user(42), "id", serde_value::Value::U64(42));
+//! ```
+//!
+//! A failed assertion panics with a message such as:
+//! ```text
+//! error code mismatch
+//!   expected: 409
+//!     actual: 404
+//!      error: Client::NotFound::UnknownUser (404) - Not Found
+//!   details:
+//!     id: 42
+//! ```
+
+/// Value holding an error to check, i.e. an `Error` or a `Result` which must be an `Err`.
+pub trait ExpectError {
+    /// Returns the error, panicking if there is none.
+    #[track_caller]
+    fn expect_error(&self) -> &crate::Error;
+}
+
+impl ExpectError for crate::Error {
+    fn expect_error(&self) -> &crate::Error {
+        self
+    }
+}
+
+impl<T: std::fmt::Debug> ExpectError for crate::Result<T> {
+    #[track_caller]
+    fn expect_error(&self) -> &crate::Error {
+        match self {
+            Ok(value) => panic!("expected an error, got Ok({:?})", value),
+            Err(error) => error,
+        }
+    }
+}
+
+impl<E: ExpectError + ?Sized> ExpectError for &E {
+    #[track_caller]
+    fn expect_error(&self) -> &crate::Error {
+        (**self).expect_error()
+    }
+}
+
+/// Panics with the expected and actual values, followed by the verbose display of the error.
+#[track_caller]
+fn fail(assertion: &str, expected: &dyn std::fmt::Display, actual: &dyn std::fmt::Display, error: &crate::Error) -> ! {
+    panic!("{assertion}\n  expected: {expected}\n    actual: {actual}\n     error: {error:#}")
+}
+
+/// Asserts that the error was raised from the given kind, see [`Error::is_kind`](crate::Error::is_kind).
+#[track_caller]
+pub fn assert_kind<K: crate::Kind>(value: &impl ExpectError, kind: &K) {
+    let error = value.expect_error();
+    if !error.is_kind(kind) {
+        let actual = match error.kind() {
+            Some(info) => format!("{} ({})", info.name, info.code),
+            None => "unknown kind".to_string(),
+        };
+        fail("error kind mismatch", &format!("{} ({})", kind.name(), kind.code()), &actual, error);
+    }
+}
+
+/// Asserts that the error has the given code.
+#[track_caller]
+pub fn assert_code(value: &impl ExpectError, code: u16) {
+    let error = value.expect_error();
+    if error.code() != code {
+        fail("error code mismatch", &code, &error.code(), error);
+    }
+}
+
+/// Asserts that the class of the error starts with the given segments, see
+/// [`Error::has_class_prefix`](crate::Error::has_class_prefix).
+#[track_caller]
+pub fn assert_class_prefix(value: &impl ExpectError, prefix: &str) {
+    let error = value.expect_error();
+    if !error.has_class_prefix(prefix) {
+        fail("error class prefix mismatch", &format!("{prefix}::*"), &error.class(), error);
+    }
+}
+
+/// Asserts that the error holds a detail under the given key, including the lazy ones.
+#[track_caller]
+pub fn assert_detail(value: &impl ExpectError, key: &str) {
+    let error = value.expect_error();
+    let details = error.resolved_details();
+    if !details.contains_key(key) {
+        let keys = details.keys().map(String::as_str).collect::<Vec<_>>().join(", ");
+        fail("error detail missing", &key, &format!("[{keys}]"), error);
+    }
+}
+
+/// Asserts that the error holds the given value under the given key, including the lazy details.
+#[track_caller]
+pub fn assert_detail_eq(value: &impl ExpectError, key: &str, expected: &serde_value::Value) {
+    let error = value.expect_error();
+    let details = error.resolved_details();
+    match details.get(key) {
+        Some(actual) if actual == expected => {}
+        Some(actual) => fail(
+            &format!("error detail `{key}` mismatch"),
+            &format!("{expected:?}"),
+            &format!("{actual:?}"),
+            error,
+        ),
+        None => fail(&format!("error detail `{key}` missing"), &format!("{expected:?}"), &"none", error),
+    }
+}
+
+/// Asserts that an `Error`, or the error of a `Result`, was raised from the given kind.
+///
+/// See the [`testing`](crate::testing) module.
+#[macro_export]
+macro_rules! assert_error_kind {
+    ($value:expr, $kind:expr $(,)?) => {
+        $crate::testing::assert_kind(&$value, &$kind)
+    };
+}
+
+/// Asserts that an `Error`, or the error of a `Result`, has the given code.
+///
+/// See the [`testing`](crate::testing) module.
+#[macro_export]
+macro_rules! assert_error_code {
+    ($value:expr, $code:expr $(,)?) => {
+        $crate::testing::assert_code(&$value, $code)
+    };
+}
+
+/// Asserts that the class of an `Error`, or of the error of a `Result`, starts with the given segments.
+///
+/// See the [`testing`](crate::testing) module.
+#[macro_export]
+macro_rules! assert_error_class {
+    ($value:expr, $prefix:expr $(,)?) => {
+        $crate::testing::assert_class_prefix(&$value, $prefix)
+    };
+}
+
+/// Asserts that an `Error`, or the error of a `Result`, holds a detail under the given key,
+/// optionally with the given value.
+///
+/// See the [`testing`](crate::testing) module.
+#[macro_export]
+macro_rules! assert_error_detail {
+    ($value:expr, $key:expr $(,)?) => {
+        $crate::testing::assert_detail(&$value, $key)
+    };
+    ($value:expr, $key:expr, $expected:expr $(,)?) => {
+        $crate::testing::assert_detail_eq(&$value, $key, &$expected)
+    };
+}
@@ -0,0 +1,165 @@
+#![cfg(feature = "actix-web")]
+
+#[cfg(test)]
+mod tests {
+    use actix_web::ResponseError;
+    use cdumay_core::ErrorBuilder;
+
+    #[test]
+    fn test_attach_request_id() {
+        use actix_web::{App, HttpMessage, test, web};
+
+        async fn handler() -> cdumay_core::Result<String> {
+            Err(ErrorBuilder::default().build())
+        }
+
+        actix_web::rt::System::new().block_on(async {
+            let app = test::init_service(
+                App::new()
+                    .wrap(actix_web::middleware::from_fn(cdumay_core::attach_request_id))
+                    .wrap_fn(|req, srv| {
+                        req.extensions_mut().insert(cdumay_core::RequestId("req-42".to_string()));
+                        actix_web::dev::Service::call(srv, req)
+                    })
+                    .route("/", web::get().to(handler)),
+            )
+            .await;
+            let response = test::call_service(&app, test::TestRequest::get().uri("/").to_request()).await;
+            assert_eq!(response.status(), 500);
+            let body: serde_json::Value = test::read_body_json(response).await;
+            assert_eq!(body["request_id"], "req-42");
+        });
+    }
+
+    #[test]
+    fn test_catch_panic_middleware() {
+        use actix_web::{App, test, web};
+
+        async fn handler() -> String {
+            panic!("boom")
+        }
+
+        actix_web::rt::System::new().block_on(async {
+            let app = test::init_service(
+                App::new()
+                    .wrap(actix_web::middleware::from_fn(cdumay_core::catch_panic_middleware))
+                    .route("/", web::get().to(handler)),
+            )
+            .await;
+            let error = test::try_call_service(&app, test::TestRequest::get().uri("/").to_request()).await.unwrap_err();
+            let err = error.as_error::<cdumay_core::Error>().unwrap();
+            assert_eq!(err.code(), 500);
+            assert_eq!(err.details()["panic"], serde_value::Value::String("boom".to_string()));
+        });
+    }
+
+    #[test]
+    fn test_error_headers() {
+        let err = ErrorBuilder::new(cdumay_core::kinds::http::ServiceUnavailable, "Maintenance")
+            .with_detail("retry_after", serde_value::Value::U32(120))
+            .build();
+        let response = err.error_response();
+        assert_eq!(response.headers().get("X-Error-Class").unwrap(), "Server::ServiceUnavailable::Maintenance");
+        assert_eq!(response.headers().get("X-Error-Code").unwrap(), "503");
+        assert_eq!(response.headers().get("Retry-After").unwrap(), "120");
+
+        let err = ErrorBuilder::new(cdumay_core::kinds::http::ServiceUnavailable, "Maintenance")
+            .with_detail_lazy("retry_after", || serde_value::Value::U32(60))
+            .build();
+        assert_eq!(err.error_response().headers().get("Retry-After").unwrap(), "60");
+
+        let date = "Sun, 06 Nov 1994 08:49:37 GMT";
+        let err = ErrorBuilder::new(cdumay_core::kinds::http::ServiceUnavailable, "Maintenance")
+            .with_detail("retry_after", serde_value::Value::String(date.to_string()))
+            .build();
+        assert_eq!(err.error_response().headers().get("Retry-After").unwrap(), date);
+
+        let err = ErrorBuilder::new(cdumay_core::kinds::http::ServiceUnavailable, "Maintenance")
+            .with_detail("retry_after", serde_value::Value::String("soon\r\nSet-Cookie: a=b".to_string()))
+            .build();
+        let response = err.error_response();
+        assert_eq!(response.status(), 503);
+        assert!(response.headers().get("Retry-After").is_none());
+        assert!(response.headers().get("Set-Cookie").is_none());
+    }
+
+    #[test]
+    fn test_negotiate_error_format() {
+        use actix_web::{App, test, web};
+
+        async fn handler() -> cdumay_core::Result<String> {
+            Err(ErrorBuilder::new(cdumay_core::kinds::http::NotFound, "UserNotFound").build())
+        }
+
+        actix_web::rt::System::new().block_on(async {
+            let app = test::init_service(
+                App::new()
+                    .wrap(actix_web::middleware::from_fn(cdumay_core::negotiate_error_format))
+                    .route("/", web::get().to(handler)),
+            )
+            .await;
+
+            let request = test::TestRequest::get().uri("/").insert_header(("Accept", "application/problem+json")).to_request();
+            let response = test::call_service(&app, request).await;
+            assert_eq!(response.status(), 404);
+            assert_eq!(response.headers().get("Content-Type").unwrap(), "application/problem+json");
+            let body: serde_json::Value = test::read_body_json(response).await;
+            assert_eq!(body["title"], "Client::NotFound::UserNotFound");
+            assert_eq!(body["status"], 404);
+
+            let request = test::TestRequest::get().uri("/").insert_header(("Accept", "text/plain")).to_request();
+            let body = test::read_body(test::call_service(&app, request).await).await;
+            assert_eq!(body, "Client::NotFound::UserNotFound (404) - Not Found");
+
+            let response = test::call_service(&app, test::TestRequest::get().uri("/").to_request()).await;
+            assert_eq!(response.headers().get("Content-Type").unwrap(), "application/json");
+        });
+    }
+
+    #[test]
+    fn test_extractor_configs() {
+        use actix_web::{App, test, web};
+
+        #[derive(serde::Deserialize)]
+        struct Page {
+            #[allow(dead_code)]
+            page: u32,
+        }
+
+        async fn handler(_: web::Path<u32>, _: web::Query<Page>, _: web::Json<Vec<u32>>) -> String {
+            String::new()
+        }
+
+        actix_web::rt::System::new().block_on(async {
+            let app = test::init_service(
+                App::new()
+                    .app_data(cdumay_core::json_config())
+                    .app_data(cdumay_core::path_config())
+                    .app_data(cdumay_core::query_config())
+                    .route("/users/{id}", web::post().to(handler)),
+            )
+            .await;
+
+            let request = test::TestRequest::post().uri("/users/abc?page=1").set_json([1]).to_request();
+            let response = test::call_service(&app, request).await;
+            assert_eq!(response.status(), 404);
+            let body: serde_json::Value = test::read_body_json(response).await;
+            assert_eq!(body["class"], "Client::NotFound::InvalidPath");
+
+            let request = test::TestRequest::post().uri("/users/42?page=first").set_json([1]).to_request();
+            let body: serde_json::Value = test::read_body_json(test::call_service(&app, request).await).await;
+            assert_eq!(body["class"], "Client::BadRequest::InvalidQuery");
+
+            let request = test::TestRequest::post().uri("/users/42?page=1").set_json(["a"]).to_request();
+            let response = test::call_service(&app, request).await;
+            assert_eq!(response.status(), 422);
+            let body: serde_json::Value = test::read_body_json(response).await;
+            assert_eq!(body["class"], "Client::UnprocessableEntity::InvalidJsonPayload");
+            assert_eq!(body["details"]["line"], 1);
+
+            let request = test::TestRequest::post().uri("/users/42?page=1").set_payload("[1]").to_request();
+            let response = test::call_service(&app, request).await;
+            assert_eq!(response.status(), 415);
+        });
+    }
+}
@@ -0,0 +1,128 @@
+#![cfg(feature = "actix-web")]
+
+// Tests replacing the global response configuration, kept in their own binary so that the
+// responses built by the other tests are not affected.
+
+#[cfg(test)]
+mod tests {
+    use actix_web::ResponseError;
+    use cdumay_core::kinds::http::{BadGateway, TooManyRequests};
+    use cdumay_core::{Error, ErrorBuilder, ErrorHeadersConfig, ErrorResponseConfig, MultiError};
+    use std::sync::{Mutex, MutexGuard, PoisonError};
+
+    static LOCK: Mutex<()> = Mutex::new(());
+
+    /// Replaces the global configurations, restoring the previous ones when dropped.
+    struct GlobalConfig {
+        previous: (ErrorResponseConfig, ErrorHeadersConfig),
+        _lock: MutexGuard<'static, ()>,
+    }
+
+    impl GlobalConfig {
+        fn set(config: ErrorResponseConfig) -> Self {
+            Self::set_all(config, ErrorHeadersConfig::default())
+        }
+
+        fn set_all(config: ErrorResponseConfig, headers: ErrorHeadersConfig) -> Self {
+            let lock = LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+            let previous = (ErrorResponseConfig::global(), ErrorHeadersConfig::global());
+            config.set_global();
+            headers.set_global();
+            Self { previous, _lock: lock }
+        }
+    }
+
+    impl Drop for GlobalConfig {
+        fn drop(&mut self) {
+            self.previous.0.clone().set_global();
+            self.previous.1.clone().set_global();
+        }
+    }
+
+    fn body_json(response: actix_web::HttpResponse) -> serde_json::Value {
+        let bytes = actix_web::rt::System::new()
+            .block_on(actix_web::body::to_bytes(response.into_body()))
+            .unwrap();
+        serde_json::from_slice(&bytes).unwrap()
+    }
+
+    #[test]
+    fn test_response_redaction() {
+        let mut details = std::collections::BTreeMap::new();
+        details.insert("query".to_string(), serde_value::Value::String("SELECT 1".to_string()));
+        let err = ErrorBuilder::default()
+            .with_message("Database unreachable".to_string())
+            .with_details(details)
+            .build();
+
+        let response = {
+            let _config = GlobalConfig::set(ErrorResponseConfig::default());
+            err.error_response()
+        };
+        let body = body_json(response);
+        assert_eq!(body["message"], "Database unreachable");
+        assert_eq!(body["details"]["query"], "SELECT 1");
+
+        let response = {
+            let _config = GlobalConfig::set(ErrorResponseConfig {
+                expose_details: false,
+                expose_message_for_5xx: false,
+            });
+            err.error_response()
+        };
+        assert_eq!(response.status(), 500);
+        let body = body_json(response);
+        assert_eq!(body["message"], "Internal Server Error");
+        assert_eq!(body["details"], serde_json::json!({}));
+    }
+
+    #[test]
+    fn test_invalid_header_name() {
+        let err = ErrorBuilder::new(cdumay_core::kinds::http::NotFound, "UnknownUser").build();
+        let response = {
+            let _config = GlobalConfig::set_all(
+                ErrorResponseConfig::default(),
+                ErrorHeadersConfig::new().with_class_header("X-Error\nClass"),
+            );
+            err.error_response()
+        };
+        assert_eq!(response.status(), 404);
+        assert_eq!(response.headers().get("X-Error-Code").unwrap(), "404");
+        assert_eq!(body_json(response)["class"], "Client::NotFound::UnknownUser");
+    }
+
+    #[test]
+    fn test_multi_error_response() {
+        cdumay_core::pipeline::register(|error: Error| match error.class_ref() {
+            "Server::BadGateway::BatchUpstream" => error.with_context("finalized"),
+            _ => error,
+        });
+        let errors: MultiError = vec![
+            ErrorBuilder::new(TooManyRequests, "BatchQuota").with_detail("retry_after", serde_value::Value::U64(30)).build(),
+            ErrorBuilder::new(BadGateway, "BatchUpstream")
+                .with_message("Upstream password rejected".to_string())
+                .with_detail("retry_after", serde_value::Value::U64(60))
+                .build(),
+        ]
+        .into_iter()
+        .collect();
+
+        let response = {
+            let _config = GlobalConfig::set(ErrorResponseConfig {
+                expose_details: false,
+                expose_message_for_5xx: false,
+            });
+            errors.error_response()
+        };
+        assert_eq!(response.status(), 502);
+        let classes: Vec<_> = response.headers().get_all("X-Error-Class").map(|value| value.to_str().unwrap()).collect();
+        assert_eq!(classes, ["Client::TooManyRequests::BatchQuota", "Server::BadGateway::BatchUpstream"]);
+        let retry_after: Vec<_> = response.headers().get_all("Retry-After").collect();
+        assert_eq!(retry_after, ["30"]);
+
+        let body = body_json(response);
+        assert_eq!(body[0]["details"], serde_json::json!({}));
+        assert_eq!(body[1]["message"], "Internal Server Error");
+        assert_eq!(body[1]["context"], serde_json::json!(["finalized"]));
+    }
+}
@@ -0,0 +1,35 @@
+#![cfg(feature = "arbitrary")]
+
+#[cfg(test)]
+mod tests {
+    use cdumay_core::{Error, ErrorKind};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn test_json_roundtrip(err in any::<Error>()) {
+            let decoded: Error = serde_json::from_str(&serde_json::to_string(&err).unwrap()).unwrap();
+            prop_assert_eq!(decoded.class(), err.class());
+            prop_assert_eq!(decoded.message(), err.message());
+            prop_assert_eq!(decoded.details(), err.details());
+            prop_assert_eq!(decoded.context(), err.context());
+            // The kind is only serialized with the `wire` feature
+            if cfg!(feature = "wire") {
+                prop_assert_eq!(decoded.kind(), err.kind());
+            }
+        }
+
+        #[test]
+        fn test_kind(kind in any::<ErrorKind>()) {
+            prop_assert!((400..600).contains(&kind.code()));
+            prop_assert_eq!(cdumay_core::kinds::http::kind_from_code(kind.code()), kind);
+        }
+
+        #[test]
+        fn test_result(result in any::<cdumay_core::Result<u8>>()) {
+            if let Err(err) = result {
+                prop_assert!(err.is_kind(&cdumay_core::kinds::http::kind_from_code(err.code())));
+            }
+        }
+    }
+}
@@ -0,0 +1,55 @@
+#[cfg(test)]
+mod tests {
+    use cdumay_core::breaker::{Breaker, CircuitSignal, CircuitState};
+    use cdumay_core::kinds::http::{BadRequest, GatewayTimeout, InternalServerError};
+    use cdumay_core::{ErrorBuilder, Result};
+    use std::time::Duration;
+
+    fn failure() -> Result<()> {
+        Err(ErrorBuilder::new(GatewayTimeout, "UpstreamTimeout").build())
+    }
+
+    #[test]
+    fn test_classify() {
+        let breaker = Breaker::new(1, Duration::from_secs(1)).with_kind_signal(&InternalServerError, CircuitSignal::Ignore);
+        assert_eq!(breaker.classify(&ErrorBuilder::new(GatewayTimeout, "Timeout").build()), CircuitSignal::Trip);
+        assert_eq!(breaker.classify(&ErrorBuilder::new(BadRequest, "Invalid").build()), CircuitSignal::Ignore);
+        assert_eq!(breaker.classify(&ErrorBuilder::default().build()), CircuitSignal::Ignore);
+        let err = ErrorBuilder::default().with_circuit_signal(CircuitSignal::Trip).build();
+        assert_eq!(breaker.classify(&err), CircuitSignal::Trip);
+        assert_eq!(breaker.classify(&err.to_builder().build()), CircuitSignal::Trip);
+    }
+
+    #[test]
+    fn test_open_and_close() {
+        let mut breaker = Breaker::new(2, Duration::from_secs(60));
+        breaker.record(&failure());
+        breaker.record(&Ok(()));
+        breaker.record(&failure());
+        assert_eq!(breaker.state(), CircuitState::Closed);
+        breaker.record(&failure());
+        assert_eq!(breaker.state(), CircuitState::Open);
+
+        let mut called = false;
+        let err = breaker
+            .call(|| {
+                called = true;
+                Ok(())
+            })
+            .unwrap_err();
+        assert!(!called);
+        assert_eq!(err.code(), 503);
+        assert_eq!(err.details()["retry_after"], serde_value::Value::U64(60));
+    }
+
+    #[test]
+    fn test_half_open() {
+        let mut breaker = Breaker::new(1, Duration::ZERO);
+        breaker.record(&failure());
+        assert_eq!(breaker.state(), CircuitState::HalfOpen);
+        assert!(breaker.call(failure).is_err());
+        assert_eq!(breaker.state(), CircuitState::HalfOpen);
+        assert!(breaker.call(|| Ok(())).is_ok());
+        assert_eq!(breaker.state(), CircuitState::Closed);
+    }
+}
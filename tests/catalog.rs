@@ -0,0 +1,33 @@
+#[cfg(test)]
+mod tests {
+    use cdumay_core::kinds::http::{NotFound, TooManyRequests};
+    use cdumay_core::{ErrorCatalog, define_errors};
+
+    define_errors! {
+        UserNotFound = NotFound,
+        QuotaExceeded = TooManyRequests { limit: u64 },
+    }
+
+    #[test]
+    fn test_catalog_serialization() {
+        let catalog = ErrorCatalog::new()
+            .with_kind(NotFound)
+            .with_kind(NotFound)
+            .with_error(UserNotFound::new())
+            .with_error(QuotaExceeded::new(100));
+        assert_eq!(catalog.kinds().len(), 1);
+
+        let json = serde_json::to_value(&catalog).unwrap();
+        assert_eq!(json["kinds"][0]["side"], "Client");
+        assert!(json["errors"][0].get("example").is_none());
+        assert_eq!(json["errors"][1]["example"]["limit"], 100);
+        assert_eq!(serde_json::from_value::<ErrorCatalog>(json).unwrap(), catalog);
+    }
+
+    #[test]
+    fn test_catalog_markdown() {
+        let markdown = ErrorCatalog::new().with_kind(NotFound).with_error(UserNotFound::new()).to_markdown();
+        assert!(markdown.contains("| NotFound | 404 | Client | Not Found |"));
+        assert!(markdown.contains("| Client::NotFound::UserNotFound | 404 | Not Found |  |"));
+    }
+}
@@ -0,0 +1,65 @@
+#![cfg(feature = "cbor")]
+
+#[cfg(test)]
+mod tests {
+    use cdumay_core::breaker::CircuitSignal;
+    use cdumay_core::kinds::http::BadRequest;
+    use cdumay_core::{Error, ErrorBuilder};
+    use serde_value::Value;
+    use std::collections::BTreeMap;
+
+    fn roundtrip(value: Value) -> Value {
+        let err = ErrorBuilder::new(BadRequest, "InvalidReading").with_detail("value", value).build();
+        Error::from_cbor(&err.to_cbor().unwrap()).unwrap().details()["value"].clone()
+    }
+
+    #[test]
+    fn test_detail_values() {
+        for value in [
+            Value::Bool(true),
+            Value::U64(u64::MAX),
+            Value::I64(i64::MIN),
+            Value::F64(21.5),
+            Value::String("t-01".to_string()),
+            Value::Bytes(vec![0, 1, 2]),
+            Value::Seq(vec![Value::U64(1), Value::String("two".to_string())]),
+            Value::Map(BTreeMap::from([(Value::String("unit".to_string()), Value::String("celsius".to_string()))])),
+        ] {
+            assert_eq!(roundtrip(value.clone()), value);
+        }
+    }
+
+    #[test]
+    fn test_detail_integers_widened() {
+        assert_eq!(roundtrip(Value::U8(7)), Value::U64(7));
+        assert_eq!(roundtrip(Value::I8(-7)), Value::I64(-7));
+        assert_eq!(roundtrip(Value::Unit), Value::Option(None));
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let err = ErrorBuilder::new(BadRequest, "InvalidReading")
+            .with_message("Temperature out of range".to_string())
+            .with_detail("sensor", Value::String("t-01".to_string()))
+            .with_request_id("coap-7".to_string())
+            .with_circuit_signal(CircuitSignal::Ignore)
+            .build()
+            .with_context("while ingesting readings");
+
+        let decoded = Error::from_cbor(&err.to_cbor().unwrap()).unwrap();
+        assert_eq!(decoded, err);
+        assert_eq!(decoded.code(), 400);
+        assert_eq!(decoded.context(), ["while ingesting readings"]);
+    }
+
+    #[test]
+    fn test_custom_code() {
+        let err = Error::new(418, "Teapot".to_string(), "I'm a teapot".to_string(), BTreeMap::new());
+        assert_eq!(Error::from_cbor(&err.to_cbor().unwrap()).unwrap().code(), 418);
+    }
+
+    #[test]
+    fn test_invalid_data() {
+        assert!(Error::from_cbor(b"not cbor").is_err());
+    }
+}
@@ -0,0 +1,45 @@
+#[cfg(test)]
+mod tests {
+    use cdumay_core::ErrorBuilder;
+    use cdumay_core::context::{ContextMap, current, with_error_context, with_error_context_sync};
+    use serde_value::Value;
+
+    fn context(key: &str, value: &str) -> ContextMap {
+        let mut context = ContextMap::new();
+        context.insert(key.to_string(), Value::String(value.to_string()));
+        context
+    }
+
+    #[test]
+    fn test_nested_scopes() {
+        let err = with_error_context_sync(context("tenant", "acme"), || {
+            with_error_context_sync(context("request_id", "req-42"), || {
+                let mut details = std::collections::BTreeMap::new();
+                details.insert("tenant".to_string(), Value::String("explicit".to_string()));
+                ErrorBuilder::default().with_details(details).build()
+            })
+        });
+        assert_eq!(err.details()["tenant"], Value::String("explicit".to_string()));
+        assert_eq!(err.details()["request_id"], Value::String("req-42".to_string()));
+        assert!(current().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_async_scope() {
+        let err = with_error_context(context("tenant", "acme"), async {
+            tokio::task::yield_now().await;
+            ErrorBuilder::default().build()
+        })
+        .await;
+        assert_eq!(err.details()["tenant"], Value::String("acme".to_string()));
+        assert!(ErrorBuilder::default().build().details().is_empty());
+    }
+
+    #[test]
+    fn test_rebuild_outside_scope() {
+        let err = with_error_context_sync(context("tenant", "acme"), || ErrorBuilder::default().build());
+        let err = with_error_context_sync(context("request_id", "req-42"), || err.to_builder().build());
+        assert_eq!(err.details()["tenant"], Value::String("acme".to_string()));
+        assert!(!err.details().contains_key("request_id"));
+    }
+}
@@ -0,0 +1,353 @@
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "std-converters")]
+    mod std_errors {
+        use cdumay_core::converters::{AddrParseErrorConverter, IoErrorConverter, ParseIntErrorConverter, VarErrorConverter};
+        use cdumay_core::ErrorConverter;
+        use serde_value::Value;
+        use std::collections::BTreeMap;
+
+        #[test]
+        fn test_io_error() {
+            let error = std::io::Error::new(std::io::ErrorKind::PermissionDenied, "access denied");
+            let err = IoErrorConverter::convert_error(&error, None, BTreeMap::new());
+            assert_eq!(err.code(), 403);
+            assert_eq!(err.message(), "access denied");
+            assert_eq!(err.details().get("io_kind"), Some(&Value::String("permission denied".to_string())));
+            assert_eq!(err.details().get("origin"), Some(&Value::String("access denied".to_string())));
+
+            for kind in [std::io::ErrorKind::NotFound, std::io::ErrorKind::TimedOut, std::io::ErrorKind::ConnectionReset, std::io::ErrorKind::InvalidData] {
+                let err = IoErrorConverter::convert_error(&std::io::Error::from(kind), None, BTreeMap::new());
+                let from = cdumay_core::Error::from(std::io::Error::from(kind));
+                assert_eq!(err.code(), from.code());
+                assert_eq!(err.class(), from.class());
+            }
+        }
+
+        #[test]
+        fn test_parse_int_error() {
+            let error = "foo".parse::<u32>().unwrap_err();
+            let err = ParseIntErrorConverter::convert_error(&error, Some("Invalid port".to_string()), BTreeMap::new());
+            assert_eq!(err.code(), 400);
+            assert_eq!(err.class(), "Client::BadRequest::ParseIntError");
+            assert_eq!(err.message(), "Invalid port");
+        }
+
+        #[test]
+        fn test_var_error() {
+            let err = VarErrorConverter::convert_error(&std::env::VarError::NotPresent, None, BTreeMap::new());
+            assert_eq!(err.code(), 500);
+            assert_eq!(err.class(), "Server::InternalServerError::MissingEnvironmentVariable");
+        }
+
+        #[test]
+        fn test_addr_parse_error() {
+            let addr = AddrParseErrorConverter::parse::<std::net::IpAddr>("::1").unwrap();
+            assert!(addr.is_loopback());
+
+            let err = AddrParseErrorConverter::parse::<std::net::Ipv4Addr>("256.0.0.1").unwrap_err();
+            assert_eq!(err.code(), 400);
+            assert_eq!(err.message(), "invalid IPv4 address syntax");
+            assert_eq!(err.details().get("input"), Some(&Value::String("256.0.0.1".to_string())));
+        }
+    }
+
+    #[cfg(feature = "serde-json")]
+    mod serde_json_errors {
+        use cdumay_core::converters::SerdeJsonErrorConverter;
+        use cdumay_core::ErrorConverter;
+        use serde_value::Value;
+        use std::collections::BTreeMap;
+
+        #[test]
+        fn test_categories() {
+            let error = serde_json::from_str::<serde_json::Value>("{\n  \"a\": ]").unwrap_err();
+            let err = SerdeJsonErrorConverter::convert_error(&error, None, BTreeMap::new());
+            assert_eq!(err.code(), 400);
+            assert_eq!(err.class(), "Client::BadRequest::JsonSyntaxError");
+            assert_eq!(err.details().get("line"), Some(&Value::U64(2)));
+            assert_eq!(err.details().get("column"), Some(&Value::U64(8)));
+
+            let error = serde_json::from_str::<serde_json::Value>("[1, 2").unwrap_err();
+            let err = SerdeJsonErrorConverter::convert_error(&error, None, BTreeMap::new());
+            assert_eq!(err.class(), "Client::BadRequest::JsonEofError");
+
+            let error = serde_json::from_str::<u32>("-1").unwrap_err();
+            let err = SerdeJsonErrorConverter::convert_error(&error, Some("Invalid payload".to_string()), BTreeMap::new());
+            assert_eq!(err.code(), 422);
+            assert_eq!(err.message(), "Invalid payload");
+            assert!(err.details().contains_key("origin"));
+        }
+    }
+
+    #[cfg(feature = "sqlx")]
+    mod sqlx_errors {
+        use cdumay_core::converters::SqlxErrorConverter;
+        use cdumay_core::ErrorConverter;
+        use serde_value::Value;
+        use std::borrow::Cow;
+        use std::collections::BTreeMap;
+
+        #[derive(Debug)]
+        struct UniqueViolation;
+
+        impl std::fmt::Display for UniqueViolation {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "duplicate key value violates unique constraint")
+            }
+        }
+
+        impl std::error::Error for UniqueViolation {}
+
+        impl sqlx::error::DatabaseError for UniqueViolation {
+            fn message(&self) -> &str {
+                "duplicate key value violates unique constraint"
+            }
+
+            fn code(&self) -> Option<Cow<'_, str>> {
+                Some(Cow::Borrowed("23505"))
+            }
+
+            fn as_error(&self) -> &(dyn std::error::Error + Send + Sync + 'static) {
+                self
+            }
+
+            fn as_error_mut(&mut self) -> &mut (dyn std::error::Error + Send + Sync + 'static) {
+                self
+            }
+
+            fn into_error(self: Box<Self>) -> Box<dyn std::error::Error + Send + Sync + 'static> {
+                self
+            }
+
+            fn constraint(&self) -> Option<&str> {
+                Some("users_email_key")
+            }
+
+            fn kind(&self) -> sqlx::error::ErrorKind {
+                sqlx::error::ErrorKind::UniqueViolation
+            }
+        }
+
+        #[test]
+        fn test_database_error() {
+            let err = SqlxErrorConverter::convert_error(&sqlx::Error::database(UniqueViolation), None, BTreeMap::new());
+            assert_eq!(err.code(), 409);
+            assert_eq!(err.details().get("db_code"), Some(&Value::String("23505".to_string())));
+            assert_eq!(err.details().get("constraint"), Some(&Value::String("users_email_key".to_string())));
+            assert!(!err.details().contains_key("table"));
+        }
+
+        #[test]
+        fn test_connection_error() {
+            let err = SqlxErrorConverter::convert_error(&sqlx::Error::PoolTimedOut, None, BTreeMap::new());
+            assert_eq!(err.code(), 503);
+        }
+    }
+
+    #[cfg(feature = "diesel")]
+    mod diesel_errors {
+        use cdumay_core::converters::DieselErrorConverter;
+        use cdumay_core::ErrorConverter;
+        use diesel::result::{DatabaseErrorKind, Error};
+        use std::collections::BTreeMap;
+
+        #[test]
+        fn test_database_error() {
+            let error = Error::DatabaseError(DatabaseErrorKind::UniqueViolation, Box::new("duplicate key".to_string()));
+            let err = DieselErrorConverter::convert_error(&error, None, BTreeMap::new());
+            assert_eq!(err.code(), 409);
+            assert_eq!(err.message(), "duplicate key");
+
+            let error = Error::DatabaseError(DatabaseErrorKind::CheckViolation, Box::new("check failed".to_string()));
+            assert_eq!(DieselErrorConverter::convert_error(&error, None, BTreeMap::new()).code(), 422);
+
+            let error = Error::DatabaseError(DatabaseErrorKind::ClosedConnection, Box::new("closed".to_string()));
+            assert_eq!(DieselErrorConverter::convert_error(&error, None, BTreeMap::new()).code(), 503);
+        }
+
+        #[test]
+        fn test_other_error() {
+            let err = DieselErrorConverter::convert_error(&Error::RollbackTransaction, None, BTreeMap::new());
+            assert_eq!(err.code(), 500);
+        }
+    }
+
+    #[cfg(feature = "redis")]
+    mod redis_errors {
+        use cdumay_core::converters::RedisErrorConverter;
+        use cdumay_core::ErrorConverter;
+        use redis::{ErrorKind, RedisError};
+        use serde_value::Value;
+        use std::collections::BTreeMap;
+
+        #[test]
+        fn test_connection_errors() {
+            let error = RedisError::from(std::io::Error::from(std::io::ErrorKind::ConnectionRefused));
+            let err = RedisErrorConverter::convert_error(&error, None, BTreeMap::new());
+            assert_eq!(err.code(), 503);
+            assert_eq!(err.details().get("redis_category"), Some(&Value::String("I/O error".to_string())));
+
+            let error = RedisError::from(std::io::Error::from(std::io::ErrorKind::TimedOut));
+            let err = RedisErrorConverter::convert_error(&error, None, BTreeMap::new());
+            assert_eq!(err.code(), 504);
+            assert_eq!(err.class(), "Server::GatewayTimeout::RedisError");
+        }
+
+        #[test]
+        fn test_type_error() {
+            let error = RedisError::from((ErrorKind::UnexpectedReturnType, "Response was of incompatible type"));
+            let err = RedisErrorConverter::convert_error(&error, None, BTreeMap::new());
+            assert_eq!(err.code(), 500);
+            assert_eq!(err.details().get("redis_category"), Some(&Value::String("type error".to_string())));
+            assert!(!err.details().contains_key("redis_code"));
+        }
+    }
+
+    #[cfg(feature = "mongodb")]
+    mod mongodb_errors {
+        use cdumay_core::converters::MongodbErrorConverter;
+        use cdumay_core::ErrorConverter;
+        use mongodb::bson::{doc, from_document};
+        use mongodb::error::{Error, ErrorKind, WriteFailure};
+        use serde_value::Value;
+        use std::collections::BTreeMap;
+
+        #[test]
+        fn test_duplicate_key() {
+            let write_error = from_document(doc! { "code": 11000, "errmsg": "E11000 duplicate key error" }).unwrap();
+            let error = Error::from(ErrorKind::Write(WriteFailure::WriteError(write_error)));
+            let err = MongodbErrorConverter::convert_error(&error, None, BTreeMap::new());
+            assert_eq!(err.code(), 409);
+            assert_eq!(err.class(), "Client::Conflict::MongodbError");
+            assert_eq!(err.details().get("mongodb_code"), Some(&Value::I32(11000)));
+        }
+
+        #[test]
+        fn test_write_concern() {
+            let write_concern_error = from_document(doc! {
+                "code": 100,
+                "codeName": "UnsatisfiableWriteConcern",
+                "errmsg": "Not enough data-bearing nodes",
+                "errorLabels": ["RetryableWriteError"],
+            })
+            .unwrap();
+            let error = Error::from(ErrorKind::Write(WriteFailure::WriteConcernError(write_concern_error)));
+            let err = MongodbErrorConverter::convert_error(&error, None, BTreeMap::new());
+            assert_eq!(err.code(), 503);
+            assert_eq!(
+                err.details().get("mongodb_labels"),
+                Some(&Value::Seq(vec![Value::String("RetryableWriteError".to_string())]))
+            );
+
+            let write_concern_error = from_document(doc! { "code": 64, "errmsg": "waiting for replication timed out" }).unwrap();
+            let error = Error::from(ErrorKind::Write(WriteFailure::WriteConcernError(write_concern_error)));
+            assert_eq!(MongodbErrorConverter::convert_error(&error, None, BTreeMap::new()).code(), 504);
+        }
+
+        #[test]
+        fn test_network_errors() {
+            let error = Error::from(std::io::Error::from(std::io::ErrorKind::ConnectionRefused));
+            let err = MongodbErrorConverter::convert_error(&error, None, BTreeMap::new());
+            assert_eq!(err.code(), 503);
+            assert!(!err.details().contains_key("mongodb_code"));
+
+            let error = Error::from(std::io::Error::from(std::io::ErrorKind::TimedOut));
+            assert_eq!(MongodbErrorConverter::convert_error(&error, None, BTreeMap::new()).code(), 504);
+        }
+    }
+
+    #[cfg(feature = "url")]
+    mod url_errors {
+        use cdumay_core::converters::UrlParseErrorConverter;
+        use cdumay_core::ErrorConverter;
+        use serde_value::Value;
+        use std::collections::BTreeMap;
+
+        #[test]
+        fn test_parse() {
+            let url = UrlParseErrorConverter::parse("https://example.com/callback").unwrap();
+            assert_eq!(url.host_str(), Some("example.com"));
+
+            let err = UrlParseErrorConverter::parse("https://exa mple.com").unwrap_err();
+            assert_eq!(err.code(), 400);
+            assert_eq!(err.class(), "Client::BadRequest::UrlParseError");
+            assert_eq!(err.message(), "invalid international domain name");
+            assert_eq!(err.details().get("input"), Some(&Value::String("https://exa mple.com".to_string())));
+        }
+
+        #[test]
+        fn test_custom_message() {
+            let err = UrlParseErrorConverter::convert_error(&url::ParseError::InvalidPort, Some("Invalid callback URL".to_string()), BTreeMap::new());
+            assert_eq!(err.message(), "Invalid callback URL");
+            assert_eq!(err.details().get("origin"), Some(&Value::String("invalid port number".to_string())));
+        }
+    }
+
+    #[cfg(feature = "uuid")]
+    mod uuid_errors {
+        use cdumay_core::converters::{InvalidIdentifier, UuidErrorConverter};
+        use cdumay_core::ErrorConverter;
+        use serde_value::Value;
+        use std::collections::BTreeMap;
+
+        #[test]
+        fn test_parse() {
+            let id = UuidErrorConverter::parse("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+            assert_eq!(id.get_version_num(), 4);
+
+            let err = UuidErrorConverter::parse("67e55044-10b1-426f-9247").unwrap_err();
+            assert_eq!(err.code(), 400);
+            assert_eq!(err.kind().map(|kind| kind.name.as_str()), Some(InvalidIdentifier.name()));
+            assert_eq!(err.details().get("input"), Some(&Value::String("67e55044-10b1-426f-9247".to_string())));
+        }
+
+        #[test]
+        fn test_custom_message() {
+            let error = uuid::Uuid::parse_str("").unwrap_err();
+            let err = UuidErrorConverter::convert_error(&error, Some("Invalid user id".to_string()), BTreeMap::new());
+            assert_eq!(err.class(), "Client::InvalidIdentifier::UuidError");
+            assert_eq!(err.message(), "Invalid user id");
+            assert!(err.details().contains_key("origin"));
+        }
+    }
+
+    #[cfg(feature = "jwt")]
+    mod jwt_errors {
+        use cdumay_core::converters::JwtErrorConverter;
+        use cdumay_core::ErrorConverter;
+        use jsonwebtoken::errors::{Error, ErrorKind};
+        use serde_value::Value;
+        use std::collections::BTreeMap;
+
+        fn convert(kind: ErrorKind) -> cdumay_core::Error {
+            JwtErrorConverter::convert_error(&Error::from(kind), None, BTreeMap::new())
+        }
+
+        #[test]
+        fn test_unauthorized() {
+            let err = convert(ErrorKind::InvalidSignature);
+            assert_eq!(err.code(), 401);
+            assert_eq!(err.details().get("jwt_category"), Some(&Value::String("invalid_signature".to_string())));
+
+            let err = convert(ErrorKind::MissingRequiredClaim("exp".to_string()));
+            assert_eq!(err.code(), 401);
+            assert_eq!(err.details().get("claim"), Some(&Value::String("exp".to_string())));
+        }
+
+        #[test]
+        fn test_forbidden() {
+            let err = convert(ErrorKind::InvalidAudience);
+            assert_eq!(err.code(), 403);
+            assert_eq!(err.class(), "Client::Forbidden::JwtError");
+            assert_eq!(err.details().get("jwt_category"), Some(&Value::String("invalid_audience".to_string())));
+        }
+
+        #[test]
+        fn test_invalid_key() {
+            let err = convert(ErrorKind::InvalidKeyFormat);
+            assert_eq!(err.code(), 500);
+            assert_eq!(err.details().get("jwt_category"), Some(&Value::String("invalid_key".to_string())));
+        }
+    }
+}
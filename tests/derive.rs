@@ -0,0 +1,166 @@
+#![cfg(feature = "derive")]
+
+#[cfg(test)]
+mod tests {
+    use cdumay_core::{AsCdumayError, Error, Kind, Side, define_kinds};
+    use serde_value::Value;
+
+    define_kinds! {
+        #[docs = "https://docs.example.com/errors/{name}"]
+        NotFound = (404, "Resource Not Found"),
+        Unauthorized = (401, "Unauthorized Access"),
+    }
+
+    #[derive(Debug, AsCdumayError)]
+    enum UserError {
+        #[kind(NotFound)]
+        #[message("User not found")]
+        UserNotFound { id: u64 },
+        #[kind(Unauthorized)]
+        #[code(403)]
+        Forbidden,
+        #[kind(Unauthorized)]
+        InvalidToken(String),
+    }
+
+    struct QueueFull;
+
+    impl Kind for QueueFull {
+        fn name(&self) -> &'static str {
+            "QueueFull"
+        }
+        fn code(&self) -> u16 {
+            1
+        }
+        fn description(&self) -> &'static str {
+            "Queue is full"
+        }
+        fn side(&self) -> Side {
+            Side::Server
+        }
+    }
+
+    #[derive(Debug, AsCdumayError)]
+    enum JobError {
+        #[kind(QueueFull)]
+        Rejected,
+    }
+
+    #[test]
+    fn test_kind_with_explicit_side() {
+        let err = JobError::Rejected;
+        assert_eq!(err.class(), "Server::QueueFull::Rejected");
+        assert_eq!(format!("{}", err), "Server::QueueFull::Rejected (1): Queue is full");
+
+        let core: Error = err.into();
+        assert_eq!(core.class(), "Server::QueueFull::Rejected");
+        assert_eq!(core.kind().map(|kind| kind.side), Some(Side::Server));
+    }
+
+    #[test]
+    fn test_variant_with_named_fields() {
+        let err = UserError::UserNotFound { id: 42 };
+        assert_eq!(err.code(), 404);
+        assert_eq!(err.message(), "User not found");
+        assert_eq!(err.class(), "Client::NotFound::UserNotFound");
+        assert_eq!(err.details().get("id"), Some(&Value::U64(42)));
+        assert_eq!(Error::from(err).docs_url(), Some("https://docs.example.com/errors/UserNotFound"));
+    }
+
+    #[test]
+    fn test_variant_with_code_override() {
+        let err = UserError::Forbidden;
+        assert_eq!(err.code(), 403);
+        assert_eq!(err.message(), "Unauthorized Access");
+        assert!(err.details().is_empty());
+        assert_eq!(format!("{}", err), "Client::Unauthorized::Forbidden (403): Unauthorized Access");
+    }
+
+    #[test]
+    fn test_display_verbose() {
+        let err = UserError::UserNotFound { id: 42 };
+        assert_eq!(format!("{}", err), "Client::NotFound::UserNotFound (404): User not found");
+        assert_eq!(format!("{:#}", err), "Client::NotFound::UserNotFound (404): User not found\n  details:\n    id: 42");
+    }
+
+    #[test]
+    fn test_conversion_to_core_error() {
+        let core: Error = UserError::InvalidToken("abc".to_string()).into();
+        assert_eq!(core.code(), 401);
+        assert_eq!(core.class(), "Client::Unauthorized::InvalidToken");
+        assert_eq!(core.details().get("0"), Some(&Value::String("abc".to_string())));
+    }
+
+    #[derive(cdumay_core::ErrorConverter)]
+    #[converter(error = std::num::ParseIntError, kind = NotFound, code = 400)]
+    #[detail("reason", format!("{:?}", error.kind()))]
+    #[detail("source", "query")]
+    struct ParseIntConverter;
+
+    #[derive(cdumay_core::ErrorConverter)]
+    #[converter(error = std::env::VarError, kind = Unauthorized, name = "MissingCredentials")]
+    struct VarConverter;
+
+    #[test]
+    fn test_derived_error_converter() {
+        use cdumay_core::ErrorConverter;
+        use std::collections::BTreeMap;
+
+        let error = "foo".parse::<u32>().unwrap_err();
+        let err = ParseIntConverter::convert_error(&error, None, BTreeMap::new());
+        assert_eq!(err.code(), 400);
+        assert_eq!(err.class(), "Client::NotFound::ParseIntError");
+        assert_eq!(err.message(), "invalid digit found in string");
+        assert_eq!(err.details().get("reason"), Some(&Value::String("InvalidDigit".to_string())));
+        assert_eq!(err.details().get("source"), Some(&Value::String("query".to_string())));
+
+        let err = VarConverter::convert_error(&std::env::VarError::NotPresent, Some("Missing token".to_string()), BTreeMap::new());
+        assert_eq!(err.code(), 401);
+        assert_eq!(err.class(), "Client::Unauthorized::MissingCredentials");
+        assert_eq!(err.message(), "Missing token");
+    }
+
+    mod renamed {
+        use cdumay_core as errors;
+        use errors::kinds::http::{BadRequest, NotFound};
+
+        #[derive(Debug, errors::AsCdumayError)]
+        #[cdumay(crate = errors)]
+        enum ItemError {
+            #[kind(NotFound)]
+            UnknownItem { name: String },
+        }
+
+        #[derive(Debug)]
+        struct QueryError {
+            query: String,
+        }
+
+        impl std::fmt::Display for QueryError {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "invalid query {}", self.query)
+            }
+        }
+
+        impl std::error::Error for QueryError {}
+
+        #[derive(errors::ErrorConverter)]
+        #[cdumay(crate = errors)]
+        #[converter(error = QueryError, kind = BadRequest)]
+        #[detail("query", error.query)]
+        struct QueryConverter;
+
+        #[test]
+        fn test_crate_path() {
+            use errors::ErrorConverter;
+
+            let err: errors::Error = ItemError::UnknownItem { name: "chair".to_string() }.into();
+            assert_eq!(err.class(), "Client::NotFound::UnknownItem");
+
+            let error = QueryError { query: "name=".to_string() };
+            let err = QueryConverter::convert_error(&error, None, Default::default());
+            assert_eq!(err.class(), "Client::BadRequest::QueryError");
+            assert_eq!(err.details()["query"], serde_value::Value::String("name=".to_string()));
+        }
+    }
+}
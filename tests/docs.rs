@@ -0,0 +1,76 @@
+#[cfg(test)]
+mod tests {
+    use cdumay_core::kinds::http::NotFound;
+    use cdumay_core::{Error, ErrorBuilder, ErrorKind, Kind, ProblemDetails, define_errors, define_kinds, set_docs_url_template};
+
+    define_kinds! {
+        /// Raised when a payment is declined.
+        #[docs = "https://docs.example.com/payments/{name}"]
+        PaymentDeclined = (402, "Payment declined"),
+    }
+
+    define_errors! {
+        CardExpired = PaymentDeclined,
+    }
+
+    struct QuotaExceeded;
+
+    impl Kind for QuotaExceeded {
+        fn name(&self) -> &'static str {
+            "QuotaExceeded"
+        }
+        fn code(&self) -> u16 {
+            429
+        }
+        fn description(&self) -> &'static str {
+            "Quota exceeded"
+        }
+        fn docs_url(&self) -> Option<&'static str> {
+            Some("https://docs.example.com/quotas#{code}")
+        }
+    }
+
+    #[test]
+    fn test_docs_url() {
+        // Without template, there is no documentation URL
+        let err = ErrorBuilder::new(NotFound, "UnknownUser").build();
+        assert_eq!(err.docs_url(), None);
+        assert_eq!(ProblemDetails::from(&err).type_, "about:blank");
+
+        // The kind template is used, unless the builder overrides it
+        let err = ErrorBuilder::new(QuotaExceeded, "TooManyRequests").build();
+        assert_eq!(err.docs_url(), Some("https://docs.example.com/quotas#429"));
+        let err = ErrorBuilder::new(QuotaExceeded, "TooManyRequests")
+            .with_docs_url("https://docs.example.com/{side}/{kind}/{name}".to_string())
+            .build();
+        assert_eq!(err.docs_url(), Some("https://docs.example.com/Client/QuotaExceeded/TooManyRequests"));
+        assert_eq!(ProblemDetails::from(&err).type_, "https://docs.example.com/Client/QuotaExceeded/TooManyRequests");
+
+        let json = serde_json::to_value(&err).unwrap();
+        assert_eq!(json["docs_url"], "https://docs.example.com/Client/QuotaExceeded/TooManyRequests");
+        let restored: cdumay_core::Error = serde_json::from_value(json).unwrap();
+        assert_eq!(restored.docs_url(), err.docs_url());
+
+        // The global template is used as fallback
+        set_docs_url_template(Some("https://docs.example.com/errors/{class}"));
+        let err = ErrorBuilder::new(NotFound, "UnknownUser").with_code(410).build();
+        assert_eq!(err.docs_url(), Some("https://docs.example.com/errors/Client::NotFound::UnknownUser"));
+        let err = ErrorBuilder::new(QuotaExceeded, "TooManyRequests").build();
+        assert_eq!(err.docs_url(), Some("https://docs.example.com/quotas#429"));
+        set_docs_url_template(None);
+    }
+
+    #[test]
+    fn test_kind_docs_url() {
+        assert_eq!(PaymentDeclined.docs_url(), Some("https://docs.example.com/payments/{name}"));
+        assert_eq!(PaymentDeclined.0, ErrorKind("PaymentDeclined", 402, "Payment declined"));
+        let err = ErrorBuilder::new(PaymentDeclined, "CardExpired").build();
+        assert_eq!(err.docs_url(), Some("https://docs.example.com/payments/CardExpired"));
+        assert_eq!(CardExpired::kind, PaymentDeclined.0);
+        assert_eq!(Error::from(CardExpired::new()).docs_url(), err.docs_url());
+
+        // The replaced values are percent-encoded
+        let err = ErrorBuilder::new(PaymentDeclined, "Card expired?#").build();
+        assert_eq!(err.docs_url(), Some("https://docs.example.com/payments/Card%20expired%3F%23"));
+    }
+}
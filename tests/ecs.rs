@@ -0,0 +1,50 @@
+#[cfg(test)]
+mod tests {
+    use cdumay_core::ErrorBuilder;
+    use cdumay_core::kinds::http::BadGateway;
+    use serde_value::Value;
+    use std::collections::BTreeMap;
+
+    fn field<'a>(document: &'a BTreeMap<String, Value>, path: &[&str]) -> Option<&'a Value> {
+        let (first, rest) = path.split_first()?;
+        rest.iter().try_fold(document.get(*first)?, |value, key| match value {
+            Value::Map(map) => map.get(&Value::String(key.to_string())),
+            _ => None,
+        })
+    }
+
+    #[test]
+    fn test_to_ecs() {
+        let err = ErrorBuilder::new(BadGateway, "UpstreamError")
+            .with_message("Upstream failed".to_string())
+            .with_detail("upstream.host", Value::String("billing".to_string()))
+            .with_detail("attempts", Value::U32(3))
+            .with_detail("password", Value::String("hunter2".to_string()))
+            .with_request_id("req-1".to_string())
+            .with_source(std::io::Error::other("connection reset"))
+            .build()
+            .with_context("while charging the customer");
+        let document = err.to_ecs();
+
+        assert_eq!(field(&document, &["error", "type"]), Some(&Value::String("Server::BadGateway::UpstreamError".to_string())));
+        assert_eq!(field(&document, &["error", "code"]), Some(&Value::String("502".to_string())));
+        assert_eq!(field(&document, &["error", "message"]), Some(&Value::String("Upstream failed".to_string())));
+        assert_eq!(
+            field(&document, &["error", "stack_trace"]),
+            Some(&Value::String("while charging the customer\ncaused by: connection reset".to_string()))
+        );
+        assert_eq!(field(&document, &["labels", "upstream_host"]), Some(&Value::String("billing".to_string())));
+        assert_eq!(field(&document, &["labels", "attempts"]), Some(&Value::String("3".to_string())));
+        assert_eq!(field(&document, &["labels", "password"]), Some(&Value::String("[REDACTED]".to_string())));
+        assert_eq!(field(&document, &["http", "request", "id"]), Some(&Value::String("req-1".to_string())));
+        assert!(field(&document, &["ecs", "version"]).is_some());
+    }
+
+    #[test]
+    fn test_to_ecs_minimal() {
+        let document = ErrorBuilder::default().build().to_ecs();
+        assert_eq!(field(&document, &["error", "stack_trace"]), None);
+        assert_eq!(field(&document, &["http"]), None);
+        assert_eq!(field(&document, &["labels"]), Some(&Value::Map(BTreeMap::new())));
+    }
+}
@@ -22,4 +22,445 @@ mod test {
             .build();
         assert_eq!(format!("{}", err), "Server::TestError::MyError (500) - Test error");
     }
+
+    #[test]
+    fn test_deserialize_without_code() {
+        let err: cdumay_core::Error =
+            serde_json::from_str(r#"{"class": "Server::TestError::MyError", "message": "Test error", "details": {}}"#).unwrap();
+        assert_eq!(err.code(), 500);
+        assert_eq!(err.class(), "Server::TestError::MyError");
+    }
+
+    #[test]
+    fn test_tolerant_deserialize() {
+        let err: cdumay_core::Error = serde_json::from_str(
+            r#"{"name": "Client::NotFound::UnknownUser", "kind": {"name": "NotFound", "code": 404, "description": "Not Found"}, "details": null, "trace_id": "abc", "extra": {"nested": [1, 2]}}"#,
+        )
+        .unwrap();
+        assert_eq!(err.class(), "Client::NotFound::UnknownUser");
+        assert_eq!(err.code(), 404);
+        assert_eq!(err.message(), "Not Found");
+        assert!(err.details().is_empty());
+        assert!(err.context().is_empty());
+
+        let err: cdumay_core::Error =
+            serde_json::from_str(r#"{"class": "Server::TestError::MyError", "name": "Legacy", "message": "Test error"}"#).unwrap();
+        assert_eq!(err.class(), "Server::TestError::MyError");
+        assert_eq!(err.code(), 500);
+        assert_eq!(err.message(), "Test error");
+
+        let err = serde_json::from_str::<cdumay_core::Error>(r#"{"message": "Test error"}"#).unwrap_err();
+        assert!(err.to_string().contains("missing field `class`"));
+    }
+
+    #[cfg(feature = "wire")]
+    #[test]
+    fn test_wire_round_trip() {
+        let err = ErrorBuilder::new(TEST_ERROR, "MyError").with_code(503).build();
+        let json = serde_json::to_string(&err).unwrap();
+        let restored: cdumay_core::Error = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, err);
+        assert_eq!(restored.code(), 503);
+    }
+
+    #[cfg(feature = "anyhow")]
+    #[test]
+    fn test_anyhow_chain() {
+        let report = anyhow::anyhow!("connection reset").context("while loading user profile");
+        let err: cdumay_core::Error = report.into();
+        assert_eq!(
+            err.details().get("origin_chain"),
+            Some(&serde_value::Value::Seq(vec![
+                serde_value::Value::String("while loading user profile".to_string()),
+                serde_value::Value::String("connection reset".to_string()),
+            ]))
+        );
+    }
+
+    #[test]
+    fn test_context_serialization() {
+        let err = ErrorBuilder::new(TEST_ERROR, "MyError").build();
+        assert!(serde_json::to_value(&err).unwrap().get("context").is_none());
+
+        let err = err.with_context("while loading user profile");
+        let json = serde_json::to_value(&err).unwrap();
+        assert_eq!(json["context"], serde_json::json!(["while loading user profile"]));
+        let restored: cdumay_core::Error = serde_json::from_value(json).unwrap();
+        assert_eq!(restored.context(), ["while loading user profile"]);
+    }
+
+    #[test]
+    fn test_fingerprint() {
+        let mut details = std::collections::BTreeMap::new();
+        details.insert("tenant".to_string(), serde_value::to_value("acme").unwrap());
+        let first = ErrorBuilder::new(TEST_ERROR, "MyError").with_details(details).build();
+        let second = ErrorBuilder::new(TEST_ERROR, "MyError").with_message("Other".to_string()).build();
+
+        assert_eq!(first.fingerprint_with_keys(&[] as &[&str]), second.fingerprint_with_keys(&[] as &[&str]));
+        assert_ne!(first.fingerprint_with_keys(&["tenant"]), second.fingerprint_with_keys(&["tenant"]));
+        assert_eq!(first.fingerprint_with_keys(&["tenant"]).len(), 16);
+
+        let custom = ErrorBuilder::new(TEST_ERROR, "MyError").with_fingerprint("custom".to_string()).build();
+        let json = serde_json::to_value(&custom).unwrap();
+        assert_eq!(json["fingerprint"], "custom");
+        let restored: cdumay_core::Error = serde_json::from_value(json).unwrap();
+        assert_eq!(restored.fingerprint(), "custom");
+    }
+
+    #[test]
+    fn test_from_io_error() {
+        let err: cdumay_core::Error = std::io::Error::from(std::io::ErrorKind::TimedOut).into();
+        assert_eq!(err.code(), 504);
+        assert_eq!(err.class(), "Server::ETIMEDOUT::IoError");
+
+        let err: cdumay_core::Error = std::io::Error::from_raw_os_error(2).into();
+        assert_eq!(err.code(), 404);
+        assert_eq!(err.details()["errno"], serde_value::Value::I32(2));
+
+        let err: cdumay_core::Error = std::io::Error::other("boom").into();
+        assert_eq!(err.code(), 500);
+        assert_eq!(err.message(), "boom");
+        assert_eq!(err.class(), "Server::EIO::IoError");
+    }
+
+    #[test]
+    fn test_to_builder() {
+        let err = ErrorBuilder::new(TEST_ERROR, "MyError")
+            .with_message("Original".to_string())
+            .with_fingerprint("custom".to_string())
+            .build()
+            .with_context("while loading user profile");
+        let rebuilt = err.to_builder().build();
+        assert_eq!(rebuilt, err);
+
+        let adjusted = err.to_builder().with_code(503).with_detail("retry", serde_value::Value::Bool(true)).build();
+        assert_eq!(adjusted.code(), 503);
+        assert_eq!(adjusted.class(), err.class());
+        assert_eq!(adjusted.message(), "Original");
+        assert_eq!(adjusted.context(), err.context());
+        assert_eq!(adjusted.details().get("retry"), Some(&serde_value::Value::Bool(true)));
+    }
+
+    #[test]
+    fn test_request_id_serialization() {
+        let err = ErrorBuilder::new(TEST_ERROR, "MyError").build();
+        assert!(serde_json::to_value(&err).unwrap().get("request_id").is_none());
+
+        let err = err.with_request_id("req-42");
+        let json = serde_json::to_value(&err).unwrap();
+        assert_eq!(json["request_id"], "req-42");
+        let restored: cdumay_core::Error = serde_json::from_value(json).unwrap();
+        assert_eq!(restored.request_id(), Some("req-42"));
+    }
+
+    #[cfg(feature = "timestamp")]
+    #[test]
+    fn test_timestamp_serialization() {
+        let timestamp = chrono::DateTime::parse_from_rfc3339("2024-05-01T12:30:00Z").unwrap().to_utc();
+        let err = ErrorBuilder::new(TEST_ERROR, "MyError").with_timestamp(timestamp).build();
+        let json = serde_json::to_value(&err).unwrap();
+        assert_eq!(json["timestamp"], "2024-05-01T12:30:00Z");
+        let restored: cdumay_core::Error = serde_json::from_value(json).unwrap();
+        assert_eq!(restored.timestamp(), Some(timestamp));
+        assert_eq!(err.to_builder().build().timestamp(), Some(timestamp));
+
+        let other = ErrorBuilder::new(TEST_ERROR, "MyError").with_timestamp(timestamp + chrono::TimeDelta::seconds(1)).build();
+        assert_eq!(other, err);
+        assert_eq!(other.cmp(&err), std::cmp::Ordering::Equal);
+    }
+
+    #[test]
+    fn test_catch_panic() {
+        let err = cdumay_core::catch_panic(|| -> u32 { panic!("index {} out of range", 3) }).unwrap_err();
+        assert_eq!(err.code(), 500);
+        assert_eq!(err.message(), "Internal Server Error");
+        assert_eq!(err.details()["panic"], serde_value::Value::String("index 3 out of range".to_string()));
+        match &err.details()["location"] {
+            serde_value::Value::String(location) => assert!(location.starts_with("tests/error.rs:")),
+            other => panic!("unexpected location {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_category_predicates() {
+        use cdumay_core::kinds::http::{BadGateway, NotFound};
+
+        let err = ErrorBuilder::new(BadGateway, "UpstreamDown").build();
+        assert!(err.is_kind(&BadGateway));
+        assert!(!err.is_kind(&NotFound));
+        assert!(err.is_server());
+        assert!(err.has_class_prefix("Server"));
+        assert!(err.has_class_prefix("Server::BadGateway::UpstreamDown"));
+        assert!(!err.has_class_prefix("Server::Bad"));
+    }
+
+    #[cfg(all(feature = "serde-json", not(feature = "wire")))]
+    #[test]
+    fn test_kind_not_serialized() {
+        let err = ErrorBuilder::new(TEST_ERROR, "MyError").build();
+        let json = serde_json::to_value(&err).unwrap();
+        assert!(json.get("kind").is_none());
+        let restored = cdumay_core::Error::from_versioned_json(&err.to_versioned_json().unwrap()).unwrap();
+        assert_eq!(restored.kind(), err.kind());
+    }
+
+    #[cfg(feature = "wire")]
+    #[test]
+    fn test_kind_serialization() {
+        let err = ErrorBuilder::new(TEST_ERROR, "MyError").with_code(499).build();
+        let json = serde_json::to_value(&err).unwrap();
+        assert_eq!(json["kind"]["name"], TEST_ERROR.name());
+        let restored: cdumay_core::Error = serde_json::from_value(json).unwrap();
+        assert_eq!(restored.kind(), err.kind());
+        assert!(restored.is_kind(&TEST_ERROR));
+        assert_eq!(err.to_builder().build().kind(), err.kind());
+    }
+
+    #[test]
+    fn test_error_template() {
+        use cdumay_core::ErrorTemplate;
+
+        const TEMPLATE: ErrorTemplate = ErrorTemplate::new(TEST_ERROR, "BillingError").with_static_details(&[("service", "billing"), ("team", "payments")]);
+
+        let template = TEMPLATE.with_detail("team", serde_value::Value::String("finance".to_string()));
+        let err = template.instantiate().with_detail("invoice", serde_value::Value::U64(7)).build();
+        assert_eq!(err.class(), "Server::TestError::BillingError");
+        assert_eq!(err.details()["service"], serde_value::Value::String("billing".to_string()));
+        assert_eq!(err.details()["team"], serde_value::Value::String("finance".to_string()));
+        assert_eq!(err.details()["invoice"], serde_value::Value::U64(7));
+        assert_eq!(TEMPLATE.instantiate().build().details()["team"], serde_value::Value::String("payments".to_string()));
+    }
+
+    #[test]
+    fn test_location() {
+        let line = line!() + 1;
+        let err = ErrorBuilder::new(TEST_ERROR, "MyError").with_location().build();
+        match &err.details()["location"] {
+            serde_value::Value::String(location) => assert!(location.starts_with(&format!("tests/error.rs:{}:", line))),
+            other => panic!("unexpected location {:?}", other),
+        }
+        assert!(!err.details().contains_key("module"));
+
+        let err = ErrorBuilder::new(TEST_ERROR, "MyError").with_code_location(cdumay_core::here!()).build();
+        assert_eq!(err.details()["module"], serde_value::Value::String(module_path!().to_string()));
+    }
+
+    #[test]
+    fn test_side() {
+        use cdumay_core::Side;
+
+        let err = ErrorBuilder::new(ErrorKind("Internal", 0, "Internal code"), "MyError").build();
+        assert_eq!(err.class(), "Unknown::Internal::MyError");
+        assert_eq!(err.side(), Side::Unknown);
+        assert!(!err.is_client() && !err.is_server());
+
+        #[cfg(feature = "wire")]
+        assert_eq!(serde_json::to_value(&err).unwrap()["kind"]["side"], "Unknown");
+        let err = ErrorBuilder::new(TEST_ERROR, "MyError").with_code(404).build();
+        assert_eq!(err.side(), Side::Server);
+    }
+
+    #[test]
+    fn test_error_kind_serde_and_ordering() {
+        use cdumay_core::kinds::http::{BadRequest, NotFound};
+        use std::collections::{BTreeSet, HashMap};
+
+        let json = serde_json::json!({ "name": "Custom", "code": 418, "description": "Custom kind" });
+        assert!(serde_json::from_value::<ErrorKind>(json.clone()).unwrap_err().to_string().contains("unknown error kind `Custom` (418)"));
+        ErrorKind("Custom", 418, "Custom kind").register();
+        let kind: ErrorKind = serde_json::from_value(json).unwrap();
+        assert_eq!(kind, ErrorKind("Custom", 418, "Custom kind"));
+        let again: ErrorKind = serde_json::from_value(serde_json::to_value(&kind).unwrap()).unwrap();
+        assert!(std::ptr::eq(kind.name(), again.name()));
+
+        let mut counts = HashMap::new();
+        *counts.entry(NotFound).or_insert(0) += 1;
+        *counts.entry(NotFound).or_insert(0) += 1;
+        assert_eq!(counts[&NotFound], 2);
+
+        let sorted: Vec<ErrorKind> = BTreeSet::from([NotFound, BadRequest, kind]).into_iter().collect();
+        assert_eq!(sorted.iter().map(|kind| kind.name()).collect::<Vec<_>>(), ["BadRequest", "Custom", "NotFound"]);
+    }
+
+    #[test]
+    fn test_clone_shares_fields() {
+        let err = ErrorBuilder::default()
+            .with_detail("user", serde_value::Value::String("john".to_string()))
+            .build()
+            .with_context("while loading user profile");
+        let clone = err.clone();
+        assert!(std::ptr::eq(err.class_ref(), clone.class_ref()));
+        assert!(std::ptr::eq(err.message_ref(), clone.message_ref()));
+        assert!(std::ptr::eq(err.details_ref(), clone.details_ref()));
+        assert!(std::ptr::eq(err.context(), clone.context()));
+
+        // Modifying a clone leaves the original untouched
+        let clone = clone.with_context("while handling the request");
+        assert_eq!(err.context().len(), 1);
+        assert_eq!(clone.context().len(), 2);
+    }
+
+    #[test]
+    fn test_detail_lazy() {
+        use std::sync::Arc;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = calls.clone();
+        let err = ErrorBuilder::default()
+            .with_detail("user", serde_value::Value::String("john".to_string()))
+            .with_detail_lazy("request", move || {
+                counter.fetch_add(1, Ordering::SeqCst);
+                serde_value::Value::String("GET /users".to_string())
+            })
+            .build();
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+        assert!(!err.details_ref().contains_key("request"));
+
+        // Computed once, and shared by the clones
+        let clone = err.clone();
+        assert_eq!(err.details()["request"], serde_value::Value::String("GET /users".to_string()));
+        assert_eq!(clone.details()["request"], serde_value::Value::String("GET /users".to_string()));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert_eq!(serde_json::to_value(&err).unwrap()["details"]["request"], serde_json::json!("GET /users"));
+
+        // An eager detail replaces a lazy one
+        let err = err.to_builder().with_detail("request", serde_value::Value::Unit).build();
+        assert_eq!(err.details()["request"], serde_value::Value::Unit);
+    }
+
+    #[test]
+    fn test_detail_lazy_redacted() {
+        let err = ErrorBuilder::default()
+            .with_detail_lazy("password", || serde_value::Value::String("hunter2".to_string()))
+            .with_detail_lazy("header", || serde_value::Value::String("Bearer abc".to_string()))
+            .build();
+        let details = cdumay_core::Redactor::default().redact(&err).details();
+        assert_eq!(details["password"], serde_value::Value::String("[REDACTED]".to_string()));
+        assert_eq!(details["header"], serde_value::Value::String("[REDACTED]".to_string()));
+        assert_eq!(err.details()["password"], serde_value::Value::String("hunter2".to_string()));
+    }
+
+    #[test]
+    fn test_display_verbose() {
+        let err = ErrorBuilder::default()
+            .with_detail("user", serde_value::Value::String("john".to_string()))
+            .with_detail("password", serde_value::Value::String("hunter2".to_string()))
+            .with_request_id("req-42".to_string())
+            .with_source(std::io::Error::other("disk full"))
+            .build()
+            .with_context("while loading user profile");
+        assert_eq!(format!("{}", err), "Server::InternalServerError::UnknownError (500) - Internal Server Error");
+        assert_eq!(
+            format!("{:#}", err),
+            [
+                "Server::InternalServerError::UnknownError (500) - Internal Server Error",
+                "  request_id: req-42",
+                "  details:",
+                "    password: [REDACTED]",
+                "    user: john",
+                "  context:",
+                "    while loading user profile",
+                "  caused by: disk full",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn test_eq_hash_ord() {
+        use std::collections::{BTreeSet, HashSet};
+
+        let err = ErrorBuilder::default().with_detail("user", serde_value::Value::String("john".to_string())).build();
+        let lazy = err.to_builder().with_detail_lazy("user", || serde_value::Value::String("john".to_string())).build();
+        let other = err.to_builder().with_code(503).build();
+        assert_eq!(err, lazy);
+        assert_ne!(err, other);
+        assert!(err < other);
+
+        let errors: HashSet<_> = [err.clone(), lazy.clone(), other.clone()].into_iter().collect();
+        assert_eq!(errors.len(), 2);
+        let errors: BTreeSet<_> = [other.clone(), lazy, err.clone()].into_iter().collect();
+        assert_eq!(errors.into_iter().collect::<Vec<_>>(), vec![err.clone(), other.clone()]);
+
+        let renamed = err.to_builder().with_message("Something went wrong".to_string()).build();
+        assert_ne!(err, renamed);
+        assert_eq!(err.key(), renamed.key());
+        assert_ne!(err.key(), other.key());
+        assert_eq!(err.key().to_string(), "Server::InternalServerError::UnknownError (500)");
+    }
+
+    #[test]
+    fn test_help() {
+        let err = ErrorBuilder::default().with_help("Retry in a few minutes".to_string()).build();
+        assert_eq!(err.help(), Some("Retry in a few minutes"));
+
+        let json = serde_json::to_value(&err).unwrap();
+        assert_eq!(json["help"], "Retry in a few minutes");
+        let restored: cdumay_core::Error = serde_json::from_value(json).unwrap();
+        assert_eq!(restored.help(), Some("Retry in a few minutes"));
+
+        let problem = cdumay_core::ProblemDetails::from(&err.clone().with_help("Contact the support"));
+        assert_eq!(problem.help.as_deref(), Some("Contact the support"));
+        assert!(serde_json::to_value(ErrorBuilder::default().build()).unwrap().get("help").is_none());
+    }
+
+    #[test]
+    fn test_downcast_source() {
+        #[derive(Debug)]
+        struct QueryError(std::io::Error);
+
+        impl std::fmt::Display for QueryError {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "query failed")
+            }
+        }
+
+        impl std::error::Error for QueryError {
+            fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+                Some(&self.0)
+            }
+        }
+
+        let err = ErrorBuilder::default()
+            .with_source(QueryError(std::io::Error::new(std::io::ErrorKind::TimedOut, "connection timed out")))
+            .build();
+        assert!(err.downcast_source::<QueryError>().is_some());
+        assert!(err.downcast_source::<std::io::Error>().is_none());
+        assert_eq!(err.find_source::<std::io::Error>().map(|source| source.kind()), Some(std::io::ErrorKind::TimedOut));
+        assert!(err.find_source::<std::fmt::Error>().is_none());
+        assert!(ErrorBuilder::default().build().find_source::<QueryError>().is_none());
+    }
+
+    #[test]
+    fn test_from_boxed_error() {
+        #[derive(Debug)]
+        struct ConfigError(std::io::Error);
+
+        impl std::fmt::Display for ConfigError {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "cannot load the configuration")
+            }
+        }
+
+        impl std::error::Error for ConfigError {
+            fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+                Some(&self.0)
+            }
+        }
+
+        let boxed: Box<dyn std::error::Error + Send + Sync> = Box::new(ConfigError(std::io::Error::other("file not found")));
+        let err = cdumay_core::Error::from(boxed);
+        assert_eq!(err.code(), 500);
+        assert_eq!(err.class(), "Server::Unknown::Boxed");
+        assert_eq!(err.message(), "cannot load the configuration");
+        assert_eq!(
+            err.details()["origin_chain"],
+            serde_value::Value::Seq(vec![
+                serde_value::Value::String("cannot load the configuration".to_string()),
+                serde_value::Value::String("file not found".to_string()),
+            ])
+        );
+        assert!(err.downcast_source::<ConfigError>().is_some());
+    }
 }
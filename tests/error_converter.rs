@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod tests {
-    use cdumay_core::{define_errors, define_kinds, Error, ErrorConverter};
+    use cdumay_core::{define_errors, define_kinds, Error, ErrorConverter, ResultExt};
     use serde_value::Value;
     use std::collections::BTreeMap;
     use std::fmt;
@@ -91,4 +91,95 @@ mod tests {
 
         assert_eq!(result.message(), "Fallback error");
     }
+
+    #[test]
+    fn test_result_convert_with() {
+        let result: Result<(), MyError> = Err(MyError { message: "Oops".into() });
+        let mut context = BTreeMap::new();
+        context.insert("key".to_string(), Value::String("value".to_string()));
+
+        let err = result.convert_with::<MyErrorConverter>(context).unwrap_err();
+
+        assert_eq!(err.message(), "Oops");
+        assert_eq!(err.class(), "Server::MockKind::MockError");
+        assert!(err.details().contains_key("key"));
+    }
+
+    #[test]
+    fn test_result_map_kind() {
+        let result: Result<(), MyError> = Err(MyError { message: "Oops".into() });
+
+        let err = result.map_kind(MockKind, "Custom message").unwrap_err();
+
+        assert_eq!(err.message(), "Custom message");
+        assert_eq!(err.class(), "Server::MockKind::MockKind");
+        assert_eq!(err.details().get("origin").unwrap(), &Value::String("Oops".to_string()));
+    }
+
+    #[test]
+    fn test_convert_result_and_io() {
+        let result: Result<u32, MyError> = Ok(42);
+        assert_eq!(MyErrorConverter::convert_result(result, None, BTreeMap::new()).unwrap(), 42);
+
+        let result: Result<u32, MyError> = Err(MyError { message: "Oops".into() });
+        let err = MyErrorConverter::convert_result(result, Some("Custom message".into()), BTreeMap::new()).unwrap_err();
+        assert_eq!(err.message(), "Custom message");
+        assert_eq!(err.details().get("origin").unwrap(), &Value::String("Oops".to_string()));
+
+        let result: Result<u32, MyError> = Err(MyError { message: "Oops".into() });
+        let err = MyErrorConverter::convert_io(result, None, BTreeMap::new()).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        assert!(err.to_string().contains("Server::MockKind::MockError"));
+    }
+
+    #[test]
+    fn test_result_convert_err() {
+        let result: Result<(), MyError> = Err(MyError { message: "Oops".into() });
+        let mut context = BTreeMap::new();
+        context.insert("key".to_string(), Value::String("value".to_string()));
+
+        let err = result
+            .convert_err(context, |error, context| MockError::new().with_message(error.to_string()).with_details(context).into())
+            .unwrap_err();
+
+        assert_eq!(err.message(), "Oops");
+        assert_eq!(err.class(), "Server::MockKind::MockError");
+        assert!(err.details().contains_key("key"));
+    }
+
+    #[test]
+    fn test_converter_registry() {
+        use cdumay_core::ConverterRegistry;
+
+        #[derive(Debug)]
+        struct Wrapper(MyError);
+
+        impl fmt::Display for Wrapper {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "wrapped")
+            }
+        }
+
+        impl std::error::Error for Wrapper {
+            fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+                Some(&self.0)
+            }
+        }
+
+        let mut registry = ConverterRegistry::new();
+        registry.register::<MyErrorConverter>();
+        assert_eq!(registry.len(), 1);
+
+        let mut context = BTreeMap::new();
+        context.insert("key".to_string(), Value::String("value".to_string()));
+
+        let err = registry.convert(&Wrapper(MyError { message: "Oops".into() }), context.clone());
+        assert_eq!(err.class(), "Server::MockKind::MockError");
+        assert_eq!(err.message(), "Oops");
+        assert!(err.details().contains_key("key"));
+
+        let err = registry.convert(&std::fmt::Error, context);
+        assert_eq!(err.class(), "Server::InternalServerError::UnknownError");
+        assert!(err.details().contains_key("key"));
+    }
 }
@@ -0,0 +1,73 @@
+#![cfg(feature = "eyre")]
+
+#[cfg(test)]
+mod tests {
+    use cdumay_core::kinds::http::Conflict;
+    use cdumay_core::{Error, ErrorBuilder, IntoEyre, set_eyre_report};
+    use color_eyre::{Section, SectionExt};
+    use eyre::WrapErr;
+    use serde_value::Value;
+    use std::sync::Once;
+
+    /// Installs the color-eyre handler before the first report of the tests is created.
+    fn install_handler() {
+        static INSTALL: Once = Once::new();
+        INSTALL.call_once(|| color_eyre::config::HookBuilder::default().display_env_section(false).install().unwrap());
+    }
+
+    #[test]
+    fn test_report_chain() {
+        install_handler();
+        let report = Err::<(), _>(std::io::Error::other("connection reset"))
+            .wrap_err("while loading user profile")
+            .unwrap_err();
+        let err: Error = report.into();
+        assert_eq!(err.class(), "Server::InternalServerError::EyreReport");
+        assert_eq!(err.message(), "while loading user profile");
+        assert_eq!(
+            err.details()["origin_chain"],
+            Value::Seq(vec![
+                Value::String("while loading user profile".to_string()),
+                Value::String("connection reset".to_string())
+            ])
+        );
+        assert!(err.find_source::<std::io::Error>().is_some());
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        install_handler();
+        let err = ErrorBuilder::new(Conflict, "DuplicateUser").with_detail("id", Value::U8(42)).build();
+        let report = Err::<(), _>(err.clone()).into_eyre().unwrap_err();
+        assert_eq!(report.downcast_ref::<Error>(), Some(&err));
+        assert_eq!(Error::from(report), err);
+    }
+
+    #[test]
+    fn test_sections() {
+        install_handler();
+        let report = || {
+            eyre::eyre!("cannot start the worker")
+                .note("the queue is full")
+                .suggestion("increase the queue capacity")
+                .section("stalled jobs".header("Jobs:"))
+        };
+
+        // The rendering is not stored unless enabled
+        let err: Error = report().into();
+        assert!(!err.details().contains_key("report"));
+
+        set_eyre_report(true);
+        let err: Error = report().into();
+        set_eyre_report(false);
+        let Value::String(report) = &err.details()["report"] else {
+            panic!("report is not a string");
+        };
+        assert!(!report.contains('\u{1b}'));
+        assert!(report.contains("cannot start the worker"));
+        assert!(report.contains("Note: the queue is full"));
+        assert!(report.contains("Suggestion: increase the queue capacity"));
+        assert!(report.contains("Jobs:"));
+        assert!(report.contains("stalled jobs"));
+    }
+}
@@ -0,0 +1,59 @@
+#![cfg(feature = "fixtures")]
+
+#[cfg(test)]
+mod tests {
+    use cdumay_core::fixtures;
+    use cdumay_core::kinds::http::{NotFound, ServiceUnavailable};
+    use cdumay_core::{ErrorHeadersConfig, ErrorKind, Side};
+    use serde_value::Value;
+
+    #[test]
+    fn test_samples() {
+        let samples = fixtures::samples();
+        assert_eq!(samples.len(), 40);
+        assert_eq!(samples, fixtures::samples());
+        for err in &samples {
+            let kind = err.kind().unwrap();
+            assert_eq!(err.code(), kind.code);
+            assert_eq!(err.message(), kind.description);
+            assert_eq!(err.request_id(), Some(fixtures::REQUEST_ID));
+            assert_eq!(err.details().len(), 1);
+            assert_eq!(kind.side, if err.code() < 500 { Side::Client } else { Side::Server });
+        }
+    }
+
+    #[test]
+    fn test_builders() {
+        let err = fixtures::not_found().with_detail("user", Value::U64(42)).build();
+        assert!(err.is_kind(&NotFound));
+        assert_eq!(err.class(), "Client::NotFound::ResourceNotFound");
+        assert_eq!(err.details().get("user"), Some(&Value::U64(42)));
+        assert_eq!(err.details().get("resource"), Some(&Value::String("/users/42".to_string())));
+
+        let err = fixtures::service_unavailable().with_message("Database is down".to_string()).build();
+        assert!(err.is_kind(&ServiceUnavailable));
+        assert_eq!(err.message(), "Database is down");
+    }
+
+    #[test]
+    fn test_retry_after() {
+        let err = fixtures::too_many_requests().build();
+        let headers = ErrorHeadersConfig::default().headers(&err);
+        assert!(headers.contains(&("Retry-After".to_string(), "60".to_string())));
+    }
+
+    #[test]
+    fn test_custom_kind() {
+        let err = fixtures::builder(ErrorKind("QuotaError", 429, "Quota exceeded")).build();
+        assert_eq!(err.class(), "Client::QuotaError::SampleError");
+        assert!(err.details().is_empty());
+    }
+
+    #[cfg(feature = "timestamp")]
+    #[test]
+    fn test_timestamp() {
+        let err = fixtures::conflict().build();
+        assert_eq!(err.timestamp(), Some(fixtures::timestamp()));
+        assert_eq!(fixtures::timestamp().to_rfc3339(), "2024-01-01T00:00:00+00:00");
+    }
+}
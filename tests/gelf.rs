@@ -0,0 +1,55 @@
+#![cfg(feature = "gelf")]
+
+#[cfg(test)]
+mod tests {
+    use cdumay_core::kinds::http::{BadGateway, BadRequest};
+    use cdumay_core::{Error, ErrorBuilder};
+    use serde_value::Value;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_to_gelf() {
+        let mut user = BTreeMap::new();
+        user.insert(Value::String("id".to_string()), Value::U64(42));
+        user.insert(Value::String("roles".to_string()), Value::Seq(vec![Value::String("admin".to_string())]));
+        let err = ErrorBuilder::new(BadGateway, "UpstreamError")
+            .with_message("Upstream failed".to_string())
+            .with_detail("user", Value::Map(user))
+            .with_detail("id", Value::String("abc".to_string()))
+            .with_detail("cached", Value::Bool(false))
+            .with_detail("upstream host", Value::String("billing".to_string()))
+            .with_detail("api_key", Value::String("s3cr3t".to_string()))
+            .with_detail("code", Value::U16(1))
+            .with_request_id("req-1".to_string())
+            .build()
+            .with_context("while charging the customer");
+        let message = err.to_gelf("api-1");
+
+        assert_eq!(message["version"], "1.1");
+        assert_eq!(message["host"], "api-1");
+        assert_eq!(message["short_message"], "Upstream failed");
+        assert_eq!(
+            message["full_message"],
+            "Server::BadGateway::UpstreamError (502) - Upstream failed\nwhile charging the customer"
+        );
+        assert!(message["timestamp"].is_f64());
+        assert_eq!(message["level"], 3);
+        assert_eq!(message["_class"], "Server::BadGateway::UpstreamError");
+        assert_eq!(message["_code"], 502);
+        assert_eq!(message["_request_id"], "req-1");
+        assert_eq!(message["_user_id"], 42);
+        assert_eq!(message["_user_roles_0"], "admin");
+        assert_eq!(message["_id_"], "abc");
+        assert_eq!(message["_cached"], "false");
+        assert_eq!(message["_upstream_host"], "billing");
+        assert_eq!(message["_api_key"], "[REDACTED]");
+        assert!(message.get("_id").is_none());
+    }
+
+    #[test]
+    fn test_levels() {
+        assert_eq!(ErrorBuilder::new(BadRequest, "Invalid").build().to_gelf("api-1")["level"], 4);
+        let err = Error::new(302, "Redirect".to_string(), "Found".to_string(), BTreeMap::new());
+        assert_eq!(err.to_gelf("api-1")["level"], 6);
+    }
+}
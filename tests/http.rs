@@ -0,0 +1,25 @@
+#![cfg(feature = "http")]
+
+#[cfg(test)]
+mod tests {
+    use cdumay_core::{Error, ErrorBuilder};
+    use cdumay_core::kinds::http::{NotFound, ServiceUnavailable};
+    use http::StatusCode;
+
+    #[test]
+    fn test_status_code() {
+        assert_eq!(ErrorBuilder::new(NotFound, "UnknownUser").build().status_code(), StatusCode::NOT_FOUND);
+        let err = ErrorBuilder::new(ServiceUnavailable, "DatabaseDown").build();
+        assert_eq!(StatusCode::from(&err), StatusCode::SERVICE_UNAVAILABLE);
+    }
+
+    #[test]
+    fn test_status_code_clamped() {
+        for code in [0, 99, 600, 999, u16::MAX] {
+            let err = Error::new(code, "Custom::Error".to_string(), "Invalid code".to_string(), Default::default());
+            assert_eq!(err.status_code(), StatusCode::INTERNAL_SERVER_ERROR);
+        }
+        let err = Error::new(418, "Custom::Error".to_string(), "I'm a teapot".to_string(), Default::default());
+        assert_eq!(err.status_code(), StatusCode::IM_A_TEAPOT);
+    }
+}
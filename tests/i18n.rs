@@ -0,0 +1,65 @@
+#![cfg(feature = "i18n")]
+
+#[cfg(test)]
+mod tests {
+    use cdumay_core::{define_kinds, i18n, ErrorBuilder, ErrorKind};
+
+    const TEST_ERROR: ErrorKind = ErrorKind("TestError", 500, "Test error message");
+
+    #[test]
+    fn test_class_key_takes_precedence() {
+        i18n::add_resource("es", "TestError = Error de prueba\nServer-TestError-MyError = Mi error").unwrap();
+
+        let err = ErrorBuilder::new(TEST_ERROR, "MyError").build();
+        assert_eq!(err.localized("es"), "Mi error");
+
+        let err = ErrorBuilder::new(TEST_ERROR, "OtherError").build();
+        assert_eq!(err.localized("es-ES"), "Error de prueba");
+    }
+
+    define_kinds! {
+        namespace Billing {
+            InvalidInvoice = (400, "Invalid invoice"),
+        }
+    }
+
+    #[test]
+    fn test_namespaced_kind() {
+        assert_eq!(Billing::InvalidInvoice.message_key(), "Billing-InvalidInvoice");
+        i18n::add_resource("de", "Billing-InvalidInvoice = Ungültige Rechnung\nClient-Billing-InvalidInvoice-MissingTotal = Gesamtbetrag fehlt").unwrap();
+
+        let err = ErrorBuilder::new(Billing::InvalidInvoice, "MissingTotal").build();
+        assert_eq!(err.message_key(), "Client-Billing-InvalidInvoice-MissingTotal");
+        assert_eq!(err.localized("de"), "Gesamtbetrag fehlt");
+
+        let err = ErrorBuilder::new(Billing::InvalidInvoice, "MissingDate").build();
+        assert_eq!(err.localized("de"), "Ungültige Rechnung");
+
+        // The kind of a remote error is taken from its class when unknown
+        #[cfg(feature = "serde-json")]
+        {
+            let remote: cdumay_core::Error = serde_json::from_value(serde_json::json!({"code": 400, "class": "Client::Billing::InvalidInvoice::MissingDate", "message": "Invalid invoice"})).unwrap();
+            assert!(remote.kind().is_none());
+            assert_eq!(remote.localized("de"), "Ungültige Rechnung");
+        }
+    }
+
+    #[test]
+    fn test_lazy_details_as_arguments() {
+        i18n::add_resource("pt", "TestError = Tentativa { $attempt }").unwrap();
+
+        let err = ErrorBuilder::new(TEST_ERROR, "LazyError")
+            .with_detail_lazy("attempt", || serde_value::Value::U8(3))
+            .build();
+        assert_eq!(err.localized("pt"), "Tentativa 3");
+    }
+
+    #[test]
+    fn test_invalid_resource() {
+        let err = i18n::add_resource("it", "= invalid").unwrap_err();
+        assert_eq!(err.class(), "Server::InvalidTranslation::InvalidResource");
+
+        let err = i18n::add_resource("not a locale!", "Foo = bar").unwrap_err();
+        assert_eq!(err.class(), "Server::InvalidTranslation::InvalidLocale");
+    }
+}
@@ -0,0 +1,43 @@
+#![cfg(feature = "serde-json")]
+
+#[cfg(test)]
+mod tests {
+    use cdumay_core::kinds::http::Conflict;
+    use cdumay_core::{Error, ErrorBuilder};
+
+    #[cfg(not(any(feature = "timestamp", feature = "wire")))]
+    #[test]
+    fn test_pretty_snapshot() {
+        use serde_value::Value;
+
+        let err = ErrorBuilder::new(Conflict, "DuplicateUser")
+            .with_detail("user", Value::String("john".to_string()))
+            .with_detail("attempt", Value::U8(2))
+            .with_request_id("req-42".to_string())
+            .build()
+            .with_context("while creating the account");
+        assert_eq!(
+            err.to_json_pretty().unwrap(),
+            r#"{
+  "class": "Client::Conflict::DuplicateUser",
+  "context": [
+    "while creating the account"
+  ],
+  "details": {
+    "attempt": 2,
+    "user": "john"
+  },
+  "message": "Conflict",
+  "request_id": "req-42"
+}"#
+        );
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let err = ErrorBuilder::new(Conflict, "DuplicateUser").with_code(422).build();
+        assert_eq!(Error::from_json_str(&err.to_json_pretty().unwrap()).unwrap().class(), err.class());
+        assert_eq!(Error::from_json_str(&err.to_versioned_json().unwrap()).unwrap(), err);
+        assert!(Error::from_json_str("[]").is_err());
+    }
+}
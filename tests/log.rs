@@ -0,0 +1,87 @@
+#![cfg(feature = "log")]
+
+#[cfg(test)]
+mod tests {
+    use cdumay_core::kinds::http::{BadGateway, NotFound};
+    use cdumay_core::{ErrorBuilder, log};
+    use serde_value::Value;
+    use std::collections::BTreeMap;
+    use std::sync::Mutex;
+
+    /// A logged record: level, message and key-values.
+    type Captured = (::log::Level, String, BTreeMap<String, String>);
+
+    struct CaptureLogger(Mutex<Vec<Captured>>);
+
+    struct Collect(BTreeMap<String, String>);
+
+    impl<'kvs> ::log::kv::VisitSource<'kvs> for Collect {
+        fn visit_pair(&mut self, key: ::log::kv::Key<'kvs>, value: ::log::kv::Value<'kvs>) -> Result<(), ::log::kv::Error> {
+            self.0.insert(key.to_string(), value.to_string());
+            Ok(())
+        }
+    }
+
+    impl ::log::Log for CaptureLogger {
+        fn enabled(&self, _: &::log::Metadata) -> bool {
+            true
+        }
+
+        fn log(&self, record: &::log::Record) {
+            let mut collect = Collect(BTreeMap::new());
+            record.key_values().visit(&mut collect).unwrap();
+            self.0.lock().unwrap().push((record.level(), record.args().to_string(), collect.0));
+        }
+
+        fn flush(&self) {}
+    }
+
+    static LOGGER: CaptureLogger = CaptureLogger(Mutex::new(Vec::new()));
+
+    /// Held by the tests building errors, so that none is built while auto-logging is enabled.
+    static AUTO_LOG: Mutex<()> = Mutex::new(());
+
+    /// Returns the records logged for the given class.
+    fn logged(class: &str) -> Vec<Captured> {
+        let _ = ::log::set_logger(&LOGGER);
+        ::log::set_max_level(::log::LevelFilter::Trace);
+        LOGGER.0.lock().unwrap().iter().filter(|(_, _, kvs)| kvs["class"] == class).cloned().collect()
+    }
+
+    #[test]
+    fn test_log() {
+        let _lock = AUTO_LOG.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+        assert!(logged("").is_empty());
+        ErrorBuilder::new(BadGateway, "UpstreamError")
+            .with_detail("attempts", Value::U32(3))
+            .with_detail("password", Value::String("hunter2".to_string()))
+            .with_request_id("req-1".to_string())
+            .build()
+            .log();
+
+        let records = logged("Server::BadGateway::UpstreamError");
+        assert_eq!(records.len(), 1);
+        let (level, message, kvs) = &records[0];
+        assert_eq!(*level, ::log::Level::Error);
+        assert_eq!(message, "Server::BadGateway::UpstreamError (502) - Bad Gateway");
+        assert_eq!(kvs["code"], "502");
+        assert_eq!(kvs["request_id"], "req-1");
+        assert_eq!(kvs["details.attempts"], "3");
+        assert_eq!(kvs["details.password"], "[REDACTED]");
+    }
+
+    #[test]
+    fn test_auto_log() {
+        let _lock = AUTO_LOG.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+        assert!(logged("").is_empty());
+        log::set_auto_log(true);
+        let err = ErrorBuilder::new(NotFound, "AutoLogged").build();
+        log::set_auto_log(false);
+        ErrorBuilder::new(NotFound, "AutoLogged").build();
+
+        let records = logged(err.class_ref());
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].0, ::log::Level::Warn);
+        assert!(!log::auto_log());
+    }
+}
@@ -2,7 +2,7 @@
 mod tests {
     use std::collections::BTreeMap;
     use serde_value::Value;
-    use cdumay_core::{define_errors, define_kinds, Error};
+    use cdumay_core::{bail, define_errors, define_kinds, ensure, err, Error, Result};
 
     define_kinds! {
         NotFound = (404, "Resource Not Found"),
@@ -13,7 +13,15 @@ mod tests {
         NotFoundError = NotFound,
         UnauthorizedError = Unauthorized,
         Forbidden = (Unauthorized, 403),
-        LoginTimeout = (Unauthorized, 440, "Login Time-out") 
+        LoginTimeout = (Unauthorized, 440, "Login Time-out"),
+        QuotaExceeded = (Unauthorized, 429) { limit: u64, used: u64 },
+        InvalidFilter = (NotFound, 400) { filter: Filter },
+    }
+
+    /// Payload type which does not implement `PartialEq`.
+    #[derive(Debug, Clone, serde::Serialize)]
+    struct Filter {
+        field: String,
     }
 
     #[test]
@@ -63,4 +71,153 @@ mod tests {
         assert_eq!(core.code(), 404);
         assert_eq!(core.message(), "Page missing");
     }
+
+    #[test]
+    fn test_typed_payload_fields() {
+        let mut details = BTreeMap::new();
+        details.insert("reason".to_string(), Value::String("monthly".into()));
+
+        let err = QuotaExceeded::new(100, 120).with_details(details);
+        assert_eq!(*err.limit(), 100);
+        assert_eq!(*err.used(), 120);
+        assert_eq!(err.code(), 429);
+        assert_eq!(err.details().get("limit"), Some(&Value::U64(100)));
+        assert_eq!(err.details().get("reason"), Some(&Value::String("monthly".into())));
+
+        let core: Error = err.into();
+        assert_eq!(core.details().get("used"), Some(&Value::U64(120)));
+    }
+
+    #[test]
+    fn test_err_macro() {
+        let err = err!(NotFound);
+        assert_eq!(err.class(), "Client::NotFound::NotFound");
+        assert_eq!(err.message(), "Resource Not Found");
+
+        let err = err!(NotFound; { "user_id" => 42u64 });
+        assert_eq!(err.message(), "Resource Not Found");
+        assert_eq!(err.details().get("user_id"), Some(&Value::U64(42)));
+
+        let err = err!(Unauthorized, "token {} expired", "abc"; { "token" => "abc", "expired" => true });
+        assert_eq!(err.code(), 401);
+        assert_eq!(err.message(), "token abc expired");
+        assert_eq!(err.details().get("expired"), Some(&Value::Bool(true)));
+    }
+
+    #[test]
+    fn test_bail_and_ensure_macros() {
+        fn check(value: u32) -> Result<u32> {
+            ensure!(value > 0, Unauthorized, "value must be positive");
+            if value > 10 {
+                bail!(NotFound, "{} is too big", value);
+            }
+            Ok(value)
+        }
+
+        assert_eq!(check(5).unwrap(), 5);
+        assert_eq!(check(0).unwrap_err().code(), 401);
+        assert_eq!(check(11).unwrap_err().message(), "11 is too big");
+    }
+
+    mod catalog {
+        use cdumay_core::define_kinds;
+
+        define_kinds! {
+            #![unique_codes]
+            /// Raised when the account is locked.
+            pub AccountLocked = (423, "Account locked"),
+            pub(crate) RateLimited = (429, "Rate limited"),
+            Maintenance = (503, "Maintenance"),
+        }
+    }
+
+    #[test]
+    fn test_kinds_with_visibility() {
+        assert_eq!(catalog::AccountLocked.code(), 423);
+        assert_eq!(catalog::RateLimited.name(), "RateLimited");
+        assert_eq!(catalog::Maintenance.description(), "Maintenance");
+    }
+
+    #[test]
+    fn test_default_and_eq() {
+        assert_eq!(NotFoundError::default(), NotFoundError::new());
+        assert_ne!(Forbidden::new(), Forbidden::new().with_code(401));
+        assert_eq!(QuotaExceeded::new(10, 12), QuotaExceeded::new(10, 12));
+        assert_ne!(QuotaExceeded::new(10, 12), QuotaExceeded::new(10, 11));
+
+        let filter = |field: &str| Filter { field: field.to_string() };
+        assert_eq!(InvalidFilter::new(filter("name")), InvalidFilter::new(filter("name")));
+        assert_ne!(InvalidFilter::new(filter("name")), InvalidFilter::new(filter("email")));
+    }
+
+    #[test]
+    fn test_source() {
+        use std::error::Error as _;
+
+        let err = NotFoundError::new().with_source(std::io::Error::other("file missing"));
+        assert_eq!(err.source().map(|source| source.to_string()), Some("file missing".to_string()));
+        assert_eq!(err, NotFoundError::new());
+
+        let err: Error = err.into();
+        assert_eq!(err.source().map(|source| source.to_string()), Some("file missing".to_string()));
+        assert!(err.to_builder().build().source().is_some());
+    }
+
+    #[test]
+    fn test_display() {
+        let err = QuotaExceeded::new(100, 120).with_source(std::io::Error::other("quota store unavailable"));
+        assert_eq!(format!("{}", err), "Client::Unauthorized::QuotaExceeded (429): Unauthorized Access");
+        assert_eq!(
+            format!("{:#}", err),
+            "Client::Unauthorized::QuotaExceeded (429): Unauthorized Access\n  details:\n    limit: 100\n    used: 120\n  caused by: quota store unavailable"
+        );
+        let err = LoginTimeout::new().with_message("Session expired".to_string());
+        assert_eq!(format!("{}", err), "Client::Unauthorized::LoginTimeout (440): Session expired");
+    }
+
+    mod domains {
+        use cdumay_core::define_kinds;
+
+        define_kinds! {
+            #![unique_codes]
+            namespace Billing {
+                InvalidInvoice = (400, "Invalid invoice"),
+                pub(crate) namespace Payment {
+                    Declined = (402, "Payment declined"),
+                },
+            }
+            Gone = (410, "Gone"),
+        }
+    }
+
+    #[test]
+    fn test_kind_namespaces() {
+        use cdumay_core::ErrorBuilder;
+
+        assert_eq!(domains::Billing::InvalidInvoice.name(), "Billing::InvalidInvoice");
+        assert_eq!(domains::Billing::Payment::Declined.code(), 402);
+        assert_eq!(domains::Gone.name(), "Gone");
+
+        let err = ErrorBuilder::new(domains::Billing::InvalidInvoice, "MissingVat").build();
+        assert_eq!(err.class(), "Client::Billing::InvalidInvoice::MissingVat");
+        assert!(err.is_kind(&domains::Billing::InvalidInvoice));
+        assert!(err.has_class_prefix("Client::Billing"));
+    }
+
+    define_errors! {
+        MissingVat = domains::Billing::InvalidInvoice,
+        CardDeclined = domains::Billing::Payment::Declined { last_digits: String },
+    }
+
+    #[test]
+    fn test_errors_of_namespaced_kinds() {
+        assert_eq!(MissingVat::kind, domains::Billing::InvalidInvoice);
+        assert_eq!(MissingVat::new().class(), "Client::Billing::InvalidInvoice::MissingVat");
+        assert_eq!(MissingVat::new().message(), "Invalid invoice");
+
+        let err: Error = CardDeclined::new("4242".to_string()).into();
+        assert_eq!(err.code(), 402);
+        assert_eq!(err.class(), "Client::Billing::Payment::Declined::CardDeclined");
+        assert_eq!(err.details().get("last_digits"), Some(&Value::String("4242".to_string())));
+    }
 }
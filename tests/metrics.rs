@@ -0,0 +1,39 @@
+#![cfg(feature = "metrics")]
+
+#[cfg(test)]
+mod tests {
+    use cdumay_core::{metrics, ErrorBuilder, ErrorKind};
+    use std::sync::{Mutex, PoisonError};
+
+    /// Held by the tests enabling the automatic recording.
+    static AUTO_RECORD: Mutex<()> = Mutex::new(());
+
+    #[test]
+    fn test_auto_record() {
+        let _lock = AUTO_RECORD.lock().unwrap_or_else(PoisonError::into_inner);
+        let labels = ["Client::MetricsTest::AutoRecorded", "404", "Client"];
+        let kind = ErrorKind("MetricsTest", 404, "Metrics test");
+
+        metrics::set_auto_record(true);
+        ErrorBuilder::new(kind.clone(), "AutoRecorded").build();
+        metrics::set_auto_record(false);
+        ErrorBuilder::new(kind, "AutoRecorded").build();
+
+        assert_eq!(metrics::error_counter().with_label_values(&labels).get(), 1);
+    }
+
+    #[test]
+    fn test_rebuild_not_recorded() {
+        let _lock = AUTO_RECORD.lock().unwrap_or_else(PoisonError::into_inner);
+        let labels = ["Client::MetricsTest::Rebuilt", "404", "Client"];
+        let kind = ErrorKind("MetricsTest", 404, "Metrics test");
+
+        metrics::set_auto_record(true);
+        let err = ErrorBuilder::new(kind, "Rebuilt").build();
+        let err = err.to_builder().with_detail("user", serde_value::Value::U64(42)).build();
+        metrics::set_auto_record(false);
+
+        assert!(err.details().contains_key("user"));
+        assert_eq!(metrics::error_counter().with_label_values(&labels).get(), 1);
+    }
+}
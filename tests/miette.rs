@@ -0,0 +1,39 @@
+#![cfg(feature = "miette")]
+
+#[cfg(test)]
+mod tests {
+    use cdumay_core::kinds::http::{InternalServerError, NotFound};
+    use cdumay_core::{Error, ErrorBuilder, ErrorDiagnostic};
+    use miette::{Diagnostic, Severity};
+
+    #[test]
+    fn test_diagnostic() {
+        let err = ErrorBuilder::new(InternalServerError, "DatabaseDown")
+            .with_help("Check the database connection settings".to_string())
+            .with_source(std::io::Error::other("connection refused"))
+            .build();
+        let diagnostic = ErrorDiagnostic::from(err.clone());
+        assert_eq!(diagnostic.code().unwrap().to_string(), "Server::InternalServerError::DatabaseDown");
+        assert_eq!(diagnostic.help().unwrap().to_string(), "Check the database connection settings");
+        assert_eq!(diagnostic.severity(), Some(Severity::Error));
+        assert_eq!(std::error::Error::source(&diagnostic).unwrap().to_string(), "connection refused");
+        assert_eq!(diagnostic.error(), &err);
+    }
+
+    #[test]
+    fn test_severity() {
+        let severity = |code| ErrorDiagnostic::from(Error::new(code, "Custom::Error".to_string(), String::new(), Default::default())).severity();
+        assert_eq!(severity(503), Some(Severity::Error));
+        assert_eq!(severity(404), Some(Severity::Warning));
+        assert_eq!(severity(302), Some(Severity::Advice));
+    }
+
+    #[test]
+    fn test_report() {
+        let report: miette::Report = ErrorBuilder::new(NotFound, "UnknownConfig").build().into();
+        assert_eq!(report.to_string(), "Client::NotFound::UnknownConfig (404) - Not Found");
+        assert!(report.help().is_none());
+        let diagnostic = report.downcast_ref::<ErrorDiagnostic>().unwrap();
+        assert_eq!(diagnostic.error().code(), 404);
+    }
+}
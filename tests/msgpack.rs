@@ -0,0 +1,55 @@
+#![cfg(feature = "rmp")]
+
+#[cfg(test)]
+mod tests {
+    use cdumay_core::breaker::CircuitSignal;
+    use cdumay_core::kinds::http::Conflict;
+    use cdumay_core::{Error, ErrorBuilder};
+    use serde_value::Value;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_roundtrip() {
+        let mut nested = BTreeMap::new();
+        nested.insert(Value::String("id".to_string()), Value::U8(42));
+        let err = ErrorBuilder::new(Conflict, "DuplicateUser")
+            .with_message("User already exists".to_string())
+            .with_detail("user", Value::Map(nested))
+            .with_detail("tags", Value::Seq(vec![Value::String("signup".to_string())]))
+            .with_request_id("req-42".to_string())
+            .with_retryable(true)
+            .with_circuit_signal(CircuitSignal::Ignore)
+            .with_help("Sign in instead".to_string())
+            .build()
+            .with_context("while creating the account");
+
+        let decoded = Error::from_msgpack(&err.to_msgpack().unwrap()).unwrap();
+        assert_eq!(decoded, err);
+        assert_eq!(decoded.code(), 409);
+        assert_eq!(decoded.kind().map(|kind| kind.name.as_str()), Some("Conflict"));
+        assert_eq!(decoded.context(), ["while creating the account"]);
+        assert_eq!(decoded.circuit_signal(), Some(CircuitSignal::Ignore));
+    }
+
+    #[test]
+    fn test_custom_code() {
+        let err = Error::new(418, "Teapot".to_string(), "I'm a teapot".to_string(), BTreeMap::new());
+        let decoded = Error::from_msgpack(&err.to_msgpack().unwrap()).unwrap();
+        assert_eq!(decoded.code(), 418);
+        assert_eq!(decoded.kind(), None);
+    }
+
+    #[test]
+    fn test_unsupported_version() {
+        let mut data = ErrorBuilder::default().build().to_msgpack().unwrap();
+        // The layout is an array whose first element is the version
+        data[1] = 4;
+        let err = Error::from_msgpack(&data).unwrap_err();
+        assert!(err.to_string().contains("unsupported error layout version 4"));
+    }
+
+    #[test]
+    fn test_invalid_data() {
+        assert!(Error::from_msgpack(b"not msgpack").is_err());
+    }
+}
@@ -0,0 +1,42 @@
+#[cfg(test)]
+mod tests {
+    use cdumay_core::{Error, ErrorBuilder, ErrorKind, MultiError};
+
+    const CLIENT_ERROR: ErrorKind = ErrorKind("ValidationError", 400, "Invalid input");
+    const SERVER_ERROR: ErrorKind = ErrorKind("TestError", 500, "Test error message");
+
+    #[test]
+    fn test_display() {
+        let errors: MultiError = vec![
+            ErrorBuilder::new(CLIENT_ERROR, "MissingField").build(),
+            ErrorBuilder::new(SERVER_ERROR, "MyError").build(),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(
+            format!("{}", errors),
+            "Client::ValidationError::MissingField (400) - Invalid input\nServer::TestError::MyError (500) - Test error message"
+        );
+        assert_eq!(errors.code(), 500);
+    }
+
+    #[test]
+    fn test_serialize_as_array() {
+        let mut errors = MultiError::new();
+        errors.push(ErrorBuilder::new(CLIENT_ERROR, "MissingField").build());
+        let json = serde_json::to_value(&errors).unwrap();
+        assert!(json.is_array());
+        assert_eq!(json[0]["class"], "Client::ValidationError::MissingField");
+    }
+
+    #[test]
+    fn test_conversion_to_core_error() {
+        let mut errors = MultiError::new();
+        errors.push(ErrorBuilder::new(CLIENT_ERROR, "MissingField").build());
+        errors.push(ErrorBuilder::new(CLIENT_ERROR, "InvalidEmail").build());
+        let err: Error = errors.into_result().unwrap_err();
+        assert_eq!(err.code(), 400);
+        assert_eq!(err.class(), "Client::MultipleErrors::MultiError");
+        assert_eq!(err.message(), "2 error(s) occurred");
+    }
+}
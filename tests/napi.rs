@@ -0,0 +1,14 @@
+#![cfg(feature = "napi")]
+
+#[cfg(test)]
+mod tests {
+    use cdumay_core::ErrorBuilder;
+    use cdumay_core::kinds::http::Conflict;
+
+    #[test]
+    fn test_into_napi_error() {
+        let err: napi::Error = ErrorBuilder::new(Conflict, "DuplicateUser").with_message("User already exists".to_string()).build().into();
+        assert_eq!(err.status, napi::Status::GenericFailure);
+        assert_eq!(err.reason, "Client::Conflict::DuplicateUser (409) - User already exists");
+    }
+}
@@ -0,0 +1,89 @@
+#![cfg(feature = "opentelemetry")]
+
+#[cfg(test)]
+mod tests {
+    use cdumay_core::ErrorBuilder;
+    use cdumay_core::kinds::http::ServiceUnavailable;
+    use opentelemetry::trace::{Span, SpanContext, Status};
+    use opentelemetry::{Key, KeyValue, Value};
+    use std::borrow::Cow;
+    use std::collections::BTreeMap;
+    use std::sync::{Arc, Mutex};
+    use std::time::SystemTime;
+
+    /// Attributes and status recorded on a span.
+    #[derive(Default)]
+    struct Recorded {
+        attributes: BTreeMap<Key, Value>,
+        status: Option<Status>,
+    }
+
+    /// Span keeping what is recorded on it, shared with the test.
+    #[derive(Default)]
+    struct RecordingSpan(Arc<Mutex<Recorded>>);
+
+    impl Span for RecordingSpan {
+        fn add_event_with_timestamp<T: Into<Cow<'static, str>>>(&mut self, _: T, _: SystemTime, _: Vec<KeyValue>) {}
+
+        fn span_context(&self) -> &SpanContext {
+            &SpanContext::NONE
+        }
+
+        fn is_recording(&self) -> bool {
+            true
+        }
+
+        fn set_attribute(&mut self, attribute: KeyValue) {
+            self.0.lock().unwrap().attributes.insert(attribute.key, attribute.value);
+        }
+
+        fn set_status(&mut self, status: Status) {
+            self.0.lock().unwrap().status = Some(status);
+        }
+
+        fn update_name<T: Into<Cow<'static, str>>>(&mut self, _: T) {}
+
+        fn add_link(&mut self, _: SpanContext, _: Vec<KeyValue>) {}
+
+        fn end_with_timestamp(&mut self, _: SystemTime) {}
+    }
+
+    fn error() -> cdumay_core::Error {
+        ErrorBuilder::new(ServiceUnavailable, "DatabaseDown")
+            .with_message("Database unreachable".to_string())
+            .with_detail("attempts", serde_value::Value::U8(3))
+            .with_detail("host", serde_value::Value::String("db-1".to_string()))
+            .with_detail("password", serde_value::Value::String("hunter2".to_string()))
+            .build()
+    }
+
+    fn assert_recorded(recorded: &Recorded) {
+        assert_eq!(recorded.status, Some(Status::error("Database unreachable")));
+        let attribute = |key: &'static str| recorded.attributes.get(&Key::from_static_str(key)).cloned();
+        assert_eq!(attribute("error.class"), Some(Value::from("Server::ServiceUnavailable::DatabaseDown")));
+        assert_eq!(attribute("exception.type"), Some(Value::from("Server::ServiceUnavailable::DatabaseDown")));
+        assert_eq!(attribute("exception.message"), Some(Value::from("Database unreachable")));
+        assert_eq!(attribute("error.code"), Some(Value::I64(503)));
+        assert_eq!(attribute("error.details.attempts"), Some(Value::I64(3)));
+        assert_eq!(attribute("error.details.host"), Some(Value::from("db-1")));
+        assert_eq!(attribute("error.details.password"), Some(Value::from("[REDACTED]")));
+    }
+
+    #[test]
+    fn test_record_on_span() {
+        let mut span = RecordingSpan::default();
+        error().record_on_span(&mut span);
+        assert_recorded(&span.0.lock().unwrap());
+    }
+
+    #[test]
+    fn test_record_on_active_span() {
+        let span = RecordingSpan::default();
+        let recorded = span.0.clone();
+        {
+            let _guard = opentelemetry::trace::mark_span_as_active(span);
+            error().record_on_active_span();
+        }
+        assert_recorded(&recorded.lock().unwrap());
+    }
+}
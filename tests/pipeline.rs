@@ -0,0 +1,35 @@
+#[cfg(test)]
+mod tests {
+    use cdumay_core::{Error, ErrorBuilder, pipeline};
+    use serde_value::Value;
+
+    struct Enrich;
+
+    impl pipeline::ErrorTransformer for Enrich {
+        fn transform(&self, error: Error) -> Error {
+            error.to_builder().with_detail("service", Value::String("users".to_string())).build()
+        }
+    }
+
+    #[test]
+    fn test_pipeline_order() {
+        pipeline::register(Enrich);
+        pipeline::register(|error: Error| match error.details().contains_key("service") {
+            true => error.to_builder().with_code(503).build(),
+            false => error,
+        });
+
+        let err = ErrorBuilder::default().build();
+        assert_eq!(err.code(), 500);
+        let err = err.finalize();
+        assert_eq!(err.code(), 503);
+        assert_eq!(err.details()["service"], Value::String("users".to_string()));
+        assert_eq!(err.clone().finalize(), err);
+
+        #[cfg(feature = "actix-web")]
+        assert_eq!(actix_web::ResponseError::error_response(&ErrorBuilder::default().build()).status(), 503);
+
+        pipeline::clear();
+        assert_eq!(ErrorBuilder::default().build().finalize().code(), 500);
+    }
+}
@@ -0,0 +1,67 @@
+#![cfg(feature = "pyo3")]
+
+#[cfg(test)]
+mod tests {
+    use cdumay_core::kinds::http::Conflict;
+    use cdumay_core::{Error, ErrorBuilder, PyCdumayError};
+    use pyo3::exceptions::PyValueError;
+    use pyo3::prelude::*;
+    use pyo3::types::PyDict;
+    use serde_value::Value;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_into_pyerr() {
+        Python::initialize();
+        Python::attach(|py| {
+            let mut user = BTreeMap::new();
+            user.insert(Value::String("id".to_string()), Value::U64(42));
+            let err: PyErr = ErrorBuilder::new(Conflict, "DuplicateUser")
+                .with_detail("user", Value::Map(user))
+                .with_detail("tags", Value::Seq(vec![Value::String("signup".to_string())]))
+                .build()
+                .into();
+
+            let module = PyModule::new(py, "bindings").unwrap();
+            PyCdumayError::register(&module).unwrap();
+            let locals = PyDict::new(py);
+            locals.set_item("bindings", module).unwrap();
+            locals.set_item("err", err.value(py)).unwrap();
+            py.run(
+                cr#"
+assert isinstance(err, bindings.CdumayError)
+assert isinstance(err, Exception)
+assert getattr(err, "class") == "Client::Conflict::DuplicateUser"
+assert err.code == 409
+assert err.message == "Conflict"
+assert err.details == {"user": {"id": 42}, "tags": ["signup"]}
+assert str(err) == "Client::Conflict::DuplicateUser (409) - Conflict"
+"#,
+                None,
+                Some(&locals),
+            )
+            .unwrap();
+        });
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        Python::initialize();
+        let err = ErrorBuilder::new(Conflict, "DuplicateUser").with_detail("user", Value::String("john".to_string())).build();
+        let decoded: Error = PyErr::from(err.clone()).into();
+        assert_eq!(decoded.code(), 409);
+        assert_eq!(decoded.class(), err.class());
+        assert_eq!(decoded.message(), err.message());
+        assert_eq!(decoded.details(), err.details());
+    }
+
+    #[test]
+    fn test_from_python_exception() {
+        Python::initialize();
+        let err: Error = PyValueError::new_err("invalid literal").into();
+        assert_eq!(err.code(), 500);
+        assert_eq!(err.class(), "Server::InternalServerError::ValueError");
+        assert_eq!(err.message(), "invalid literal");
+        assert!(err.source().is_some());
+    }
+}
@@ -0,0 +1,70 @@
+#[cfg(test)]
+mod tests {
+    use cdumay_core::{ErrorBuilder, Redactor};
+    use serde_value::Value;
+    use std::collections::BTreeMap;
+    use std::sync::{Mutex, MutexGuard, PoisonError};
+
+    static LOCK: Mutex<()> = Mutex::new(());
+
+    /// Replaces the global redactor, restoring the previous one when dropped.
+    struct GlobalRedactor {
+        previous: Redactor,
+        _lock: MutexGuard<'static, ()>,
+    }
+
+    impl GlobalRedactor {
+        fn set(redactor: Redactor) -> Self {
+            let lock = LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+            let previous = Redactor::global();
+            redactor.set_global();
+            Self { previous, _lock: lock }
+        }
+    }
+
+    impl Drop for GlobalRedactor {
+        fn drop(&mut self) {
+            self.previous.clone().set_global();
+        }
+    }
+
+    fn string(value: &str) -> Value {
+        Value::String(value.to_string())
+    }
+
+    #[test]
+    fn test_key_patterns() {
+        let redactor = Redactor::none().with_key_pattern("*_key").with_key_pattern("pin");
+        assert!(redactor.is_sensitive_key("AWS_ACCESS_KEY"));
+        assert!(redactor.is_sensitive_key("Pin"));
+        assert!(!redactor.is_sensitive_key("pinned"));
+        assert!(!redactor.is_sensitive_key("key"));
+    }
+
+    #[test]
+    fn test_nested_values() {
+        let mut credentials = BTreeMap::new();
+        credentials.insert(string("user"), string("john"));
+        credentials.insert(string("api_key"), string("abc"));
+        let err = ErrorBuilder::default()
+            .with_detail("credentials", Value::Map(credentials))
+            .with_detail("headers", Value::Seq(vec![string("Bearer abc"), string("text/plain")]))
+            .build();
+
+        let details = Redactor::default().redact(&err).details();
+        let Value::Map(credentials) = &details["credentials"] else { panic!("credentials is not a map") };
+        assert_eq!(credentials[&string("user")], string("john"));
+        assert_eq!(credentials[&string("api_key")], string("[REDACTED]"));
+        assert_eq!(details["headers"], Value::Seq(vec![string("[REDACTED]"), string("text/plain")]));
+        assert_eq!(err.details()["headers"], Value::Seq(vec![string("Bearer abc"), string("text/plain")]));
+    }
+
+    #[test]
+    fn test_response_redaction() {
+        let _redactor = GlobalRedactor::set(Redactor::default());
+        let err = ErrorBuilder::default().with_detail("token", string("abc")).build();
+        let body = cdumay_core::ErrorResponseConfig::default().apply(&err);
+        assert_eq!(body.details()["token"], string("[REDACTED]"));
+        assert_eq!(err.redacted_details()["token"], string("[REDACTED]"));
+    }
+}
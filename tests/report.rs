@@ -0,0 +1,37 @@
+#[cfg(test)]
+mod tests {
+    use cdumay_core::kinds::http::{BadGateway, NotFound};
+    use cdumay_core::{Error, ErrorBuilder, SyslogSeverity, report};
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn test_report() {
+        let reported = Arc::new(Mutex::new(Vec::new()));
+        ErrorBuilder::default().build().report();
+
+        let sink = reported.clone();
+        report::set_global_reporter(move |error: &Error| {
+            sink.lock().unwrap().push(error.class());
+            // Errors built while reporting are not reported again
+            ErrorBuilder::new(BadGateway, "ReporterError").build().report();
+        });
+        ErrorBuilder::new(NotFound, "UnknownUser").build().report();
+        assert_eq!(*reported.lock().unwrap(), ["Client::NotFound::UnknownUser"]);
+
+        report::set_auto_report_threshold(Some(SyslogSeverity::Error));
+        assert_eq!(report::auto_report_threshold(), Some(SyslogSeverity::Error));
+        ErrorBuilder::new(NotFound, "UnknownUser").build();
+        ErrorBuilder::new(BadGateway, "UpstreamError").build();
+        assert_eq!(*reported.lock().unwrap(), ["Client::NotFound::UnknownUser", "Server::BadGateway::UpstreamError"]);
+
+        report::set_auto_report_threshold(Some(SyslogSeverity::Warning));
+        ErrorBuilder::new(NotFound, "UnknownUser").build();
+        assert_eq!(reported.lock().unwrap().len(), 3);
+
+        report::set_auto_report_threshold(None);
+        ErrorBuilder::new(BadGateway, "UpstreamError").build();
+        report::clear_global_reporter();
+        ErrorBuilder::new(BadGateway, "UpstreamError").build().report();
+        assert_eq!(reported.lock().unwrap().len(), 3);
+    }
+}
@@ -0,0 +1,54 @@
+#[cfg(test)]
+mod tests {
+    use cdumay_core::kinds::http::{BadGateway, NotFound};
+    use cdumay_core::report::{self, ReportLimits};
+    use cdumay_core::{Error, ErrorBuilder};
+    use serde_value::Value;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    #[test]
+    fn test_report_limits() {
+        let reported = Arc::new(Mutex::new(Vec::new()));
+        let sink = reported.clone();
+        report::set_global_reporter(move |error: &Error| sink.lock().unwrap().push(error.clone()));
+
+        // Rate limiting, per fingerprint
+        report::set_report_limits(ReportLimits::new().with_rate_limit(2, Duration::from_millis(200)));
+        for _ in 0..5 {
+            ErrorBuilder::new(BadGateway, "UpstreamError").build().report();
+        }
+        ErrorBuilder::new(NotFound, "UnknownUser").build().report();
+        assert_eq!(reported.lock().unwrap().len(), 3);
+        assert!(reported.lock().unwrap().iter().all(|error| !error.details().contains_key("suppressed")));
+
+        // The suppressed count is recorded on the next report, once the window elapsed
+        std::thread::sleep(Duration::from_millis(250));
+        ErrorBuilder::new(BadGateway, "UpstreamError").build().report();
+        ErrorBuilder::new(BadGateway, "UpstreamError").build().report();
+        {
+            let reported = reported.lock().unwrap();
+            assert_eq!(reported.len(), 5);
+            assert_eq!(reported[3].details()["suppressed"], Value::U64(3));
+            assert!(!reported[4].details().contains_key("suppressed"));
+        }
+
+        // Sampling
+        report::set_report_limits(ReportLimits::new().with_sample_rate(0.0));
+        assert_eq!(report::report_limits().sample_rate(), 0.0);
+        ErrorBuilder::new(BadGateway, "UpstreamError").build().report();
+        assert_eq!(reported.lock().unwrap().len(), 5);
+
+        report::set_report_limits(ReportLimits::new().with_sample_rate(0.5));
+        for _ in 0..1000 {
+            ErrorBuilder::new(NotFound, "UnknownUser").build().report();
+        }
+        let sampled = reported.lock().unwrap().len() - 5;
+        assert!((350..650).contains(&sampled), "{sampled} reports sampled");
+
+        report::set_report_limits(ReportLimits::new());
+        ErrorBuilder::new(NotFound, "UnknownUser").build().report();
+        assert_eq!(reported.lock().unwrap().len(), 5 + sampled + 1);
+        report::clear_global_reporter();
+    }
+}
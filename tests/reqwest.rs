@@ -0,0 +1,44 @@
+#![cfg(feature = "reqwest")]
+
+#[cfg(test)]
+mod tests {
+    use cdumay_core::converters::ReqwestErrorConverter;
+    use cdumay_core::{Error, ErrorBuilder, ErrorConverter};
+    use serde_value::Value;
+    use std::collections::BTreeMap;
+
+    fn response(status: u16, body: &str) -> reqwest::Response {
+        http::Response::builder().status(status).body(body.to_string()).unwrap().into()
+    }
+
+    #[tokio::test]
+    async fn test_from_response_with_error_body() {
+        let remote = ErrorBuilder::new(cdumay_core::kinds::http::NotFound, "UserNotFound")
+            .with_message("User 42 not found".to_string())
+            .build();
+        let err = Error::from_response(response(404, &serde_json::to_string(&remote).unwrap())).await;
+        assert_eq!(err.code(), 404);
+        assert_eq!(err.class(), "Client::NotFound::UserNotFound");
+        assert_eq!(err.message(), "User 42 not found");
+    }
+
+    #[tokio::test]
+    async fn test_from_response_with_other_body() {
+        let err = Error::from_response(response(503, "maintenance")).await;
+        assert_eq!(err.class(), "Server::ServiceUnavailable::RemoteError");
+        assert_eq!(err.message(), "maintenance");
+        assert_eq!(err.details().get("status"), Some(&Value::U16(503)));
+
+        let err = Error::from_response(response(502, "")).await;
+        assert_eq!(err.message(), "Bad Gateway");
+    }
+
+    #[test]
+    fn test_status_error() {
+        let error = response(409, "").error_for_status().unwrap_err();
+        let err = ReqwestErrorConverter::convert_error(&error, None, BTreeMap::new());
+        assert_eq!(err.code(), 409);
+        assert_eq!(err.class(), "Client::Conflict::ReqwestError");
+        assert_eq!(err.details().get("status"), Some(&Value::U16(409)));
+    }
+}
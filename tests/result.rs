@@ -0,0 +1,129 @@
+#[cfg(test)]
+mod tests {
+    use cdumay_core::{ErrorBuilder, ErrorKind, Result, ResultEnvelope, ResultIteratorExt};
+
+    const TEST_ERROR: ErrorKind = ErrorKind("TestError", 500, "Test error message");
+
+    fn results() -> Vec<Result<u32>> {
+        vec![
+            Ok(1),
+            Err(ErrorBuilder::new(TEST_ERROR, "First").build()),
+            Ok(2),
+            Err(ErrorBuilder::new(TEST_ERROR, "Second").build()),
+        ]
+    }
+
+    #[test]
+    fn test_collect_short_circuits() {
+        let collected: Result<Vec<u32>> = results().into_iter().collect();
+        assert_eq!(collected.unwrap_err().class(), "Server::TestError::First");
+
+        let collected: Result<Vec<u32>> = vec![Ok(1), Ok(2)].into_iter().collect();
+        assert_eq!(collected.unwrap(), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_partition_results() {
+        let (values, errors) = results().into_iter().partition_results();
+        assert_eq!(values, vec![1, 2]);
+        assert_eq!(errors.iter().map(|e| e.class()).collect::<Vec<_>>(), vec!["Server::TestError::First", "Server::TestError::Second"]);
+    }
+
+    #[test]
+    fn test_iterators() {
+        let values: Vec<u32> = results().into_iter().flatten().collect();
+        assert_eq!(values, vec![1, 2]);
+
+        let mut result: Result<u32> = Ok(1);
+        result.iter_mut().for_each(|value| *value *= 10);
+        assert_eq!(result.iter().copied().sum::<u32>(), 10);
+        assert_eq!(result.into_iter().count(), 1);
+
+        let result: Result<u32> = Err(ErrorBuilder::new(TEST_ERROR, "Empty").build());
+        assert_eq!(result.iter().count(), 0);
+    }
+
+    #[test]
+    fn test_inspect() {
+        let mut seen = Vec::new();
+        let classes: Vec<String> = results()
+            .into_iter()
+            .map(|result| result.inspect(|value| seen.push(*value)).inspect_err(|err| assert_eq!(err.code(), 500)))
+            .filter_map(|result| result.err().map(|err| err.class()))
+            .collect();
+        assert_eq!(seen, vec![1, 2]);
+        assert_eq!(classes, vec!["Server::TestError::First", "Server::TestError::Second"]);
+    }
+
+    #[test]
+    fn test_unwrap_family() {
+        let ok: Result<u32> = Ok(1);
+        let err: Result<u32> = Err(ErrorBuilder::new(TEST_ERROR, "First").build());
+
+        assert_eq!(ok.clone().unwrap(), 1);
+        assert_eq!(ok.clone().expect("value"), 1);
+        assert_eq!(err.clone().unwrap_or(2), 2);
+        assert_eq!(err.clone().unwrap_or_else(|e| e.code() as u32), 500);
+        assert_eq!(err.clone().unwrap_or_default(), 0);
+        assert_eq!(ok.clone().ok(), Some(1));
+        assert!(ok.clone().err().is_none());
+        assert_eq!(err.clone().unwrap_err().class(), "Server::TestError::First");
+        assert!(ok.is_ok() && err.is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "Server::TestError::First")]
+    fn test_expect_panics_with_error() {
+        results().into_iter().nth(1).unwrap().expect("value");
+    }
+
+    #[test]
+    fn test_envelope_round_trip() {
+        for result in results() {
+            let json = serde_json::to_value(ResultEnvelope::from(result.clone())).unwrap();
+            assert!(json.get("ok").is_some() || json.get("err").is_some());
+            let restored: Result<u32> = serde_json::from_value::<ResultEnvelope<u32>>(json).unwrap().into();
+            assert_eq!(restored.is_ok(), result.is_ok());
+            assert_eq!(restored.err().map(|e| e.class()), result.err().map(|e| e.class()));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_combinators() {
+        use cdumay_core::AsyncResultExt;
+
+        fn assert_send<F: Send>(future: F) -> F {
+            future
+        }
+
+        let value = assert_send(async { Ok::<u32, cdumay_core::Error>(2) }.map_async(|value| async move { value * 21 })).await;
+        assert_eq!(value.unwrap(), 42);
+
+        let err = async { Err::<u32, _>(ErrorBuilder::new(TEST_ERROR, "First").build()) }
+            .map_ok(|value| value + 1)
+            .map_err_async(|err| err.with_context("mapped"))
+            .await
+            .unwrap_err();
+        assert_eq!(err.context(), ["mapped"]);
+
+        let recovered = async { Err::<u32, _>(ErrorBuilder::new(TEST_ERROR, "First").build()) }
+            .or_else_async(|err| async move { if err.code() == 500 { Ok(0) } else { Err(err) } })
+            .await;
+        assert_eq!(recovered.unwrap(), 0);
+    }
+
+    #[test]
+    fn test_eq_and_hash() {
+        use std::collections::HashSet;
+
+        let err = ErrorBuilder::new(TEST_ERROR, "First").build();
+        let results: Vec<Result<u32>> = vec![Ok(1), Err(err.clone()), Ok(1), Err(err.clone())];
+        assert_eq!(results[1].clone(), Err(err.clone()));
+        assert_ne!(results[0], results[1]);
+        assert_eq!(results.iter().cloned().collect::<HashSet<_>>().len(), 2);
+
+        let envelope = ResultEnvelope::<u32>::from(Err(err));
+        assert_eq!(envelope.clone(), envelope);
+        assert!(ResultEnvelope::Ok(1) < envelope);
+    }
+}
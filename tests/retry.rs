@@ -0,0 +1,64 @@
+#![cfg(feature = "retry")]
+
+#[cfg(test)]
+mod tests {
+    use cdumay_core::kinds::http::{Conflict, NotFound, ServiceUnavailable};
+    use cdumay_core::retry::{RetryPolicy, retry_async};
+    use cdumay_core::{ErrorBuilder, Result};
+    use serde_value::Value;
+    use std::cell::Cell;
+    use std::time::Duration;
+
+    fn policy() -> RetryPolicy {
+        RetryPolicy::new().with_max_attempts(4).with_initial_delay(Duration::from_millis(1))
+    }
+
+    #[test]
+    fn test_delay() {
+        let policy = RetryPolicy::new().with_initial_delay(Duration::from_millis(100)).with_max_delay(Duration::from_millis(300));
+        assert_eq!(policy.delay(1), Duration::from_millis(100));
+        assert_eq!(policy.delay(2), Duration::from_millis(200));
+        assert_eq!(policy.delay(3), Duration::from_millis(300));
+    }
+
+    #[tokio::test]
+    async fn test_retry_until_success() {
+        let calls = Cell::new(0);
+        let result = retry_async(&policy(), || async {
+            calls.set(calls.get() + 1);
+            match calls.get() {
+                3 => Ok(42),
+                _ => Err(ErrorBuilder::new(ServiceUnavailable, "Maintenance").build()),
+            }
+        })
+        .await;
+        assert_eq!(result.unwrap(), 42);
+        assert_eq!(calls.get(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_non_retryable_error() {
+        let calls = Cell::new(0);
+        let result: Result<()> = retry_async(&policy(), || async {
+            calls.set(calls.get() + 1);
+            Err(ErrorBuilder::new(Conflict, "VersionMismatch").build())
+        })
+        .await;
+        assert_eq!(result.unwrap_err().details()["attempts"], Value::U32(1));
+        assert_eq!(calls.get(), 1);
+
+        let result: Result<()> = retry_async(&policy(), || async { Err(ErrorBuilder::new(Conflict, "VersionMismatch").with_retryable(true).build()) }).await;
+        assert_eq!(result.unwrap_err().details()["attempts"], Value::U32(4));
+    }
+
+    #[tokio::test]
+    async fn test_kind_override() {
+        let policy = policy().with_kind_override(&NotFound, 2).with_kind_override(&ServiceUnavailable, 1);
+
+        let result: Result<()> = retry_async(&policy, || async { Err(ErrorBuilder::new(NotFound, "Replica").build()) }).await;
+        assert_eq!(result.unwrap_err().details()["attempts"], Value::U32(2));
+
+        let result: Result<()> = retry_async(&policy, || async { Err(ErrorBuilder::new(ServiceUnavailable, "Maintenance").build()) }).await;
+        assert_eq!(result.unwrap_err().details()["attempts"], Value::U32(1));
+    }
+}
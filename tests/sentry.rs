@@ -0,0 +1,48 @@
+#![cfg(feature = "sentry")]
+
+#[cfg(test)]
+mod tests {
+    use cdumay_core::kinds::http::{InternalServerError, NotFound};
+    use cdumay_core::{ErrorBuilder, ErrorKind};
+    use sentry_core::protocol::{Event, Level, Value};
+
+    #[test]
+    fn test_event() {
+        let err = ErrorBuilder::new(NotFound, "UnknownUser")
+            .with_detail("user", serde_value::Value::U64(42))
+            .with_detail("password", serde_value::Value::String("hunter2".to_string()))
+            .build()
+            .with_context("while loading user profile");
+        let event = Event::from(&err);
+        assert_eq!(event.level, Level::Warning);
+        assert_eq!(event.message.as_deref(), Some("Not Found"));
+        assert_eq!(event.fingerprint.as_ref(), ["Client::NotFound::UnknownUser"]);
+        assert_eq!(event.exception.values[0].ty, "Client::NotFound::UnknownUser");
+        assert_eq!(event.tags["class"], "Client::NotFound::UnknownUser");
+        assert_eq!(event.tags["code"], "404");
+        assert_eq!(event.extra["user"], Value::from(42));
+        assert_eq!(event.extra["password"], Value::from("[REDACTED]"));
+        assert_eq!(event.extra["context"], Value::from(vec!["while loading user profile"]));
+    }
+
+    #[test]
+    fn test_levels() {
+        let level = |kind: ErrorKind| Event::from(ErrorBuilder::new(kind, "Failure").build()).level;
+        assert_eq!(level(InternalServerError), Level::Error);
+        assert_eq!(level(NotFound), Level::Warning);
+        assert_eq!(level(ErrorKind("Found", 302, "Found")), Level::Info);
+    }
+
+    #[test]
+    fn test_capture() {
+        let err = ErrorBuilder::new(InternalServerError, "DatabaseDown").build();
+        let events = sentry_core::test::with_captured_events(|| {
+            err.capture();
+        });
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].level, Level::Error);
+        assert_eq!(events[0].tags["class"], "Server::InternalServerError::DatabaseDown");
+        assert!(err.context().is_empty());
+        assert!(!events[0].extra.contains_key("context"));
+    }
+}
@@ -0,0 +1,89 @@
+#![cfg(feature = "snafu")]
+
+#[cfg(test)]
+mod tests {
+    use cdumay_core::Error;
+    use cdumay_core::kinds::http::{Conflict, InternalServerError};
+    use serde_value::Value;
+    use snafu::{ResultExt, Snafu};
+
+    #[derive(Debug, Snafu, serde::Serialize)]
+    enum StoreError {
+        #[snafu(display("Cannot open store {name}"))]
+        Open {
+            name: String,
+            #[serde(skip)]
+            source: std::io::Error,
+        },
+        #[snafu(display("Item {id} already exists"))]
+        Duplicate { id: u64 },
+        #[snafu(display("Store is read-only"))]
+        ReadOnly,
+    }
+
+    #[derive(Debug, Snafu, serde::Serialize)]
+    #[snafu(display("Quota of {limit} exceeded"))]
+    struct QuotaError {
+        limit: u32,
+    }
+
+    #[derive(Snafu, serde::Serialize)]
+    enum TokenError {
+        #[snafu(display("Token expired {seconds}s ago"))]
+        Expired { seconds: u64 },
+    }
+
+    impl std::fmt::Debug for TokenError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str("<redacted token error>")
+        }
+    }
+
+    #[test]
+    fn test_context_fields() {
+        let err = Error::from_snafu(Conflict, DuplicateSnafu { id: 42u64 }.build());
+        assert_eq!(err.code(), 409);
+        assert_eq!(err.class(), "Client::Conflict::Duplicate");
+        assert_eq!(err.message(), "Item 42 already exists");
+        assert_eq!(err.details()["id"], Value::U64(42));
+        assert_eq!(
+            err.details()["origin_chain"],
+            Value::Seq(vec![Value::String("Item 42 already exists".to_string())])
+        );
+    }
+
+    #[test]
+    fn test_source_chain() {
+        let result: Result<(), _> = Err(std::io::Error::other("permission denied")).context(OpenSnafu { name: "users" });
+        let err = Error::from_snafu(InternalServerError, result.unwrap_err());
+        assert_eq!(err.class(), "Server::InternalServerError::Open");
+        assert_eq!(err.details()["name"], Value::String("users".to_string()));
+        assert_eq!(
+            err.details()["origin_chain"],
+            Value::Seq(vec![
+                Value::String("Cannot open store users".to_string()),
+                Value::String("permission denied".to_string())
+            ])
+        );
+        assert!(err.downcast_source::<StoreError>().is_some());
+        assert!(err.find_source::<std::io::Error>().is_some());
+    }
+
+    #[test]
+    fn test_unit_variant_and_struct() {
+        let err = Error::from_snafu(InternalServerError, ReadOnlySnafu.build());
+        assert_eq!(err.class(), "Server::InternalServerError::ReadOnly");
+        assert_eq!(err.details().len(), 1);
+
+        let err = Error::from_snafu(InternalServerError, QuotaSnafu { limit: 10u32 }.build());
+        assert_eq!(err.class(), "Server::InternalServerError::QuotaError");
+        assert_eq!(err.details()["limit"], Value::U32(10));
+    }
+
+    #[test]
+    fn test_custom_debug() {
+        let err = Error::from_snafu(Conflict, ExpiredSnafu { seconds: 30u64 }.build());
+        assert_eq!(err.class(), "Client::Conflict::Expired");
+        assert_eq!(err.details()["seconds"], Value::U64(30));
+    }
+}
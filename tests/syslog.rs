@@ -0,0 +1,39 @@
+#[cfg(test)]
+mod tests {
+    use cdumay_core::kinds::http::{BadGateway, BadRequest};
+    use cdumay_core::{Error, ErrorBuilder, SyslogSeverity};
+    use serde_value::Value;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_syslog_severity() {
+        assert_eq!(ErrorBuilder::new(BadGateway, "UpstreamError").build().syslog_severity(), SyslogSeverity::Error);
+        assert_eq!(ErrorBuilder::new(BadRequest, "Invalid").build().syslog_severity(), SyslogSeverity::Warning);
+        let err = Error::new(302, "Redirect".to_string(), "Found".to_string(), BTreeMap::new());
+        assert_eq!(err.syslog_severity(), SyslogSeverity::Informational);
+    }
+
+    #[test]
+    fn test_severity_codes() {
+        assert_eq!(SyslogSeverity::Emergency.code(), 0);
+        assert_eq!(SyslogSeverity::Debug.code(), 7);
+        assert_eq!(SyslogSeverity::Warning.priority(1), 12);
+        assert_eq!(SyslogSeverity::Critical.to_string(), "crit");
+        assert!(SyslogSeverity::Error < SyslogSeverity::Warning);
+    }
+
+    #[test]
+    fn test_structured_data() {
+        let err = ErrorBuilder::new(BadGateway, "UpstreamError")
+            .with_detail("query", Value::String(r#"name="a]b\c""#.to_string()))
+            .with_detail("upstream host", Value::String("billing".to_string()))
+            .with_detail("attempts", Value::U32(3))
+            .with_detail("token", Value::String("s3cr3t".to_string()))
+            .with_request_id("req-1".to_string())
+            .build();
+        assert_eq!(
+            err.syslog_structured_data("error@32473"),
+            r#"[error@32473 class="Server::BadGateway::UpstreamError" code="502" request_id="req-1" attempts="3" query="name=\"a\]b\\c\"" token="[REDACTED\]" upstream_host="billing"]"#
+        );
+    }
+}
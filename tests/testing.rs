@@ -0,0 +1,49 @@
+#![cfg(feature = "testing")]
+
+#[cfg(test)]
+mod tests {
+    use cdumay_core::kinds::http::{Conflict, NotFound};
+    use cdumay_core::{ErrorBuilder, Result};
+    use cdumay_core::{assert_error_class, assert_error_code, assert_error_detail, assert_error_kind};
+    use serde_value::Value;
+
+    fn find_user() -> Result<String> {
+        Err(ErrorBuilder::new(NotFound, "UnknownUser")
+            .with_detail("id", Value::U64(42))
+            .with_detail_lazy("tenant", || Value::String("acme".to_string()))
+            .build())
+    }
+
+    #[test]
+    fn test_assertions() {
+        let err = find_user().unwrap_err();
+        assert_error_kind!(err, NotFound);
+        assert_error_code!(&err, 404);
+        assert_error_class!(find_user(), "Client::NotFound::UnknownUser");
+        assert_error_detail!(find_user(), "tenant");
+        assert_error_detail!(find_user(), "id", Value::U64(42));
+    }
+
+    #[test]
+    fn test_failure_message() {
+        let panic = std::panic::catch_unwind(|| assert_error_code!(find_user(), 409)).unwrap_err();
+        let message = panic.downcast_ref::<String>().unwrap();
+        assert!(message.starts_with("error code mismatch\n  expected: 409\n    actual: 404\n     error: Client::NotFound::UnknownUser (404)"));
+
+        let panic = std::panic::catch_unwind(|| assert_error_detail!(find_user(), "user")).unwrap_err();
+        let message = panic.downcast_ref::<String>().unwrap();
+        assert!(message.contains("expected: user\n    actual: [id, tenant]"));
+    }
+
+    #[test]
+    #[should_panic(expected = "error kind mismatch\n  expected: Conflict (409)\n    actual: NotFound (404)")]
+    fn test_kind_mismatch() {
+        assert_error_kind!(find_user(), Conflict);
+    }
+
+    #[test]
+    #[should_panic(expected = "expected an error, got Ok(\"john\")")]
+    fn test_ok_result() {
+        assert_error_code!(Ok::<_, cdumay_core::Error>("john".to_string()), 404);
+    }
+}
@@ -0,0 +1,96 @@
+#![cfg(feature = "tower")]
+
+#[cfg(test)]
+mod tests {
+    use cdumay_core::kinds::http::{NotFound, ServiceUnavailable};
+    use cdumay_core::{Error, ErrorBuilder, ErrorMappingLayer};
+    use std::task::{Context, Poll};
+    use tower_layer::Layer;
+    use tower_service::Service;
+
+    /// Service failing with a `std::io::Error` when the path is `/io`, with a `NotFound` otherwise.
+    struct Handler {
+        ready: bool,
+    }
+
+    impl Service<http::Request<String>> for Handler {
+        type Response = http::Response<String>;
+        type Error = Error;
+        type Future = std::future::Ready<Result<Self::Response, Self::Error>>;
+
+        fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            match self.ready {
+                true => Poll::Ready(Ok(())),
+                false => Poll::Ready(Err(ErrorBuilder::new(ServiceUnavailable, "Overloaded").build())),
+            }
+        }
+
+        fn call(&mut self, request: http::Request<String>) -> Self::Future {
+            std::future::ready(match request.uri().path() {
+                "/ok" => Ok(http::Response::new("ok".to_string())),
+                "/io" => Err(std::io::Error::new(std::io::ErrorKind::PermissionDenied, "access denied").into()),
+                _ => Err(ErrorBuilder::new(NotFound, "UnknownUser").build()),
+            })
+        }
+    }
+
+    fn request(path: &str, accept: Option<&str>) -> http::Request<String> {
+        let mut builder = http::Request::builder().uri(path);
+        if let Some(accept) = accept {
+            builder = builder.header(http::header::ACCEPT, accept);
+        }
+        builder.body(String::new()).unwrap()
+    }
+
+    fn call(
+        service: &mut impl Service<http::Request<String>, Response = http::Response<String>, Error = std::convert::Infallible>,
+        request: http::Request<String>,
+    ) -> http::Response<String> {
+        let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+        runtime.block_on(async {
+            std::future::poll_fn(|cx| service.poll_ready(cx)).await.unwrap();
+            service.call(request).await.unwrap()
+        })
+    }
+
+    #[test]
+    fn test_success_is_kept() {
+        let mut service = ErrorMappingLayer.layer(Handler { ready: true });
+        let response = call(&mut service, request("/ok", None));
+        assert_eq!(response.status(), http::StatusCode::OK);
+        assert_eq!(response.body(), "ok");
+    }
+
+    #[test]
+    fn test_error_rendered_as_json() {
+        let mut service = ErrorMappingLayer.layer(Handler { ready: true });
+        let response = call(&mut service, request("/users/42", None));
+        assert_eq!(response.status(), http::StatusCode::NOT_FOUND);
+        assert_eq!(response.headers()[http::header::CONTENT_TYPE], "application/json");
+        assert_eq!(response.headers()["X-Error-Class"], "Client::NotFound::UnknownUser");
+        let body: serde_json::Value = serde_json::from_str(response.body()).unwrap();
+        assert_eq!(body["class"], "Client::NotFound::UnknownUser");
+
+        let response = call(&mut service, request("/io", None));
+        assert_eq!(response.status(), http::StatusCode::FORBIDDEN);
+    }
+
+    #[test]
+    fn test_error_rendered_in_negotiated_format() {
+        let mut service = ErrorMappingLayer.layer(Handler { ready: true });
+        let response = call(&mut service, request("/users/42", Some("text/plain")));
+        assert_eq!(response.headers()[http::header::CONTENT_TYPE], "text/plain; charset=utf-8");
+        assert_eq!(response.body(), "Client::NotFound::UnknownUser (404) - Not Found");
+
+        let response = call(&mut service, request("/users/42", Some("application/problem+json")));
+        let body: serde_json::Value = serde_json::from_str(response.body()).unwrap();
+        assert_eq!(body["status"], 404);
+    }
+
+    #[test]
+    fn test_readiness_error() {
+        let mut service = ErrorMappingLayer.layer(Handler { ready: false });
+        let response = call(&mut service, request("/ok", None));
+        assert_eq!(response.status(), http::StatusCode::SERVICE_UNAVAILABLE);
+    }
+}
@@ -0,0 +1,90 @@
+#![cfg(feature = "tracing")]
+
+#[cfg(test)]
+mod tests {
+    use cdumay_core::kinds::http::{BadGateway, NotFound};
+    use cdumay_core::{ErrorBuilder, ErrorKind};
+    use serde_value::Value;
+    use std::collections::BTreeMap;
+    use std::sync::{Arc, Mutex};
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Level, Metadata, Subscriber};
+
+    /// A recorded event: level and fields.
+    type Captured = (Level, BTreeMap<String, String>);
+
+    #[derive(Clone, Default)]
+    struct CaptureSubscriber(Arc<Mutex<Vec<Captured>>>);
+
+    struct Collect(BTreeMap<String, String>);
+
+    impl Visit for Collect {
+        fn record_u64(&mut self, field: &Field, value: u64) {
+            self.0.insert(field.name().to_string(), value.to_string());
+        }
+
+        fn record_str(&mut self, field: &Field, value: &str) {
+            self.0.insert(field.name().to_string(), value.to_string());
+        }
+
+        fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+            self.0.insert(field.name().to_string(), format!("{:?}", value));
+        }
+    }
+
+    impl Subscriber for CaptureSubscriber {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, _: &Attributes<'_>) -> Id {
+            Id::from_u64(1)
+        }
+
+        fn record(&self, _: &Id, _: &Record<'_>) {}
+
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+        fn event(&self, event: &Event<'_>) {
+            let mut collect = Collect(BTreeMap::new());
+            event.record(&mut collect);
+            self.0.lock().unwrap().push((*event.metadata().level(), collect.0));
+        }
+
+        fn enter(&self, _: &Id) {}
+
+        fn exit(&self, _: &Id) {}
+    }
+
+    /// Returns the events recorded while tracing the error built by the given builder.
+    fn traced(builder: ErrorBuilder) -> Vec<Captured> {
+        let subscriber = CaptureSubscriber::default();
+        let err = builder.build();
+        tracing::subscriber::with_default(subscriber.clone(), || err.trace());
+        subscriber.0.lock().unwrap().clone()
+    }
+
+    #[test]
+    fn test_levels() {
+        let levels = |builder| traced(builder).into_iter().map(|(level, _)| level).collect::<Vec<_>>();
+        assert_eq!(levels(ErrorBuilder::new(BadGateway, "UpstreamError")), vec![Level::ERROR]);
+        assert_eq!(levels(ErrorBuilder::new(NotFound, "UnknownUser")), vec![Level::WARN]);
+        assert_eq!(levels(ErrorBuilder::new(ErrorKind("Found", 302, "Found"), "Moved")), vec![Level::INFO]);
+    }
+
+    #[test]
+    fn test_fields() {
+        let events = traced(
+            ErrorBuilder::new(NotFound, "UnknownUser")
+                .with_detail("user", Value::U64(42))
+                .with_detail("password", Value::String("hunter2".to_string())),
+        );
+        let (_, fields) = &events[0];
+        assert_eq!(fields["class"], "Client::NotFound::UnknownUser");
+        assert_eq!(fields["code"], "404");
+        assert_eq!(fields["message"], "Not Found");
+        assert!(fields["details"].contains("\"user\": U64(42)"));
+        assert!(!fields["details"].contains("hunter2"));
+    }
+}
@@ -0,0 +1,42 @@
+#![cfg(feature = "uniffi")]
+
+#[cfg(test)]
+mod tests {
+    use cdumay_core::kinds::http::Conflict;
+    use cdumay_core::{CdumayError, Error, ErrorBuilder};
+    use serde_value::Value;
+    use std::collections::{BTreeMap, HashMap};
+
+    #[test]
+    fn test_into_cdumay_error() {
+        let mut user = BTreeMap::new();
+        user.insert(Value::String("id".to_string()), Value::U64(42));
+        let err: CdumayError = ErrorBuilder::new(Conflict, "DuplicateUser")
+            .with_detail("user", Value::Map(user))
+            .with_detail("name", Value::String("john".to_string()))
+            .build()
+            .into();
+        assert_eq!(err.to_string(), "Client::Conflict::DuplicateUser (409) - Conflict");
+        let CdumayError::Error { error_class, code, message, details } = err;
+        assert_eq!(error_class, "Client::Conflict::DuplicateUser");
+        assert_eq!(code, 409);
+        assert_eq!(message, "Conflict");
+        assert_eq!(details["user"], r#"{"id":42}"#);
+        assert_eq!(details["name"], r#""john""#);
+    }
+
+    #[test]
+    fn test_from_cdumay_error() {
+        let err: Error = CdumayError::Error {
+            error_class: "Client::Conflict::DuplicateUser".to_string(),
+            code: 409,
+            message: "User already exists".to_string(),
+            details: HashMap::from([("attempts".to_string(), "3".to_string()), ("name".to_string(), "john".to_string())]),
+        }
+        .into();
+        assert_eq!(err.code(), 409);
+        assert_eq!(err.class(), "Client::Conflict::DuplicateUser");
+        assert_eq!(err.details()["attempts"], Value::U64(3));
+        assert_eq!(err.details()["name"], Value::String("john".to_string()));
+    }
+}
@@ -0,0 +1,26 @@
+#![cfg(feature = "utoipa")]
+
+#[cfg(test)]
+mod tests {
+    use cdumay_core::define_errors;
+    use cdumay_core::kinds::http::{Conflict, NotFound};
+    use utoipa::IntoResponses;
+    use utoipa::openapi::RefOr;
+
+    define_errors! {
+        UserNotFound = NotFound,
+        UserAlreadyExists = (Conflict, 409, "User already exists") { login: String },
+    }
+
+    #[test]
+    fn test_into_responses() {
+        let responses = UserNotFound::responses();
+        let Some(RefOr::T(response)) = responses.get("404") else { panic!("missing 404 response") };
+        assert_eq!(response.description, "Not Found");
+        assert!(response.content.contains_key("application/json"));
+
+        let responses = UserAlreadyExists::responses();
+        let Some(RefOr::T(response)) = responses.get("409") else { panic!("missing 409 response") };
+        assert_eq!(response.description, "User already exists");
+    }
+}
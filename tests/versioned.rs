@@ -0,0 +1,51 @@
+#![cfg(feature = "serde-json")]
+
+#[cfg(test)]
+mod tests {
+    use cdumay_core::kinds::http::Conflict;
+    use cdumay_core::{Error, ErrorBuilder};
+    use serde_value::Value;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_roundtrip() {
+        let err = ErrorBuilder::new(Conflict, "DuplicateUser")
+            .with_detail("user", Value::String("john".to_string()))
+            .build()
+            .with_context("while creating the account");
+        let json: serde_json::Value = serde_json::from_str(&err.to_versioned_json().unwrap()).unwrap();
+        assert_eq!(json["schema_version"], Error::SCHEMA_VERSION);
+        assert_eq!(json["code"], 409);
+
+        let decoded = Error::from_versioned_json(&json.to_string()).unwrap();
+        assert_eq!(decoded, err);
+        assert_eq!(decoded.code(), 409);
+    }
+
+    #[test]
+    fn test_custom_code() {
+        let err = Error::new(418, "Teapot".to_string(), "I'm a teapot".to_string(), BTreeMap::new());
+        assert_eq!(Error::from_versioned_json(&err.to_versioned_json().unwrap()).unwrap().code(), 418);
+    }
+
+    #[test]
+    fn test_upgrade_unversioned() {
+        let legacy = r#"{"class":"Client::Conflict::DuplicateUser","message":"Conflict","details":{"kind":"legacy"},"kind":{"name":"Conflict","code":409,"description":"Conflict"}}"#;
+        let err = Error::from_versioned_json(legacy).unwrap();
+        assert_eq!(err.code(), 409);
+        assert_eq!(err.class(), "Client::Conflict::DuplicateUser");
+
+        let legacy = r#"{"class":"Server::InternalServerError::UnknownError","message":"Oops","details":{}}"#;
+        assert_eq!(Error::from_versioned_json(legacy).unwrap().code(), 500);
+    }
+
+    #[test]
+    fn test_unsupported_version() {
+        let json = r#"{"schema_version":3,"code":400,"class":"Client::BadRequest::Invalid","message":"Bad Request","details":{}}"#;
+        let err = Error::from_versioned_json(json).unwrap_err();
+        assert!(err.to_string().contains("unsupported error schema version 3"));
+
+        assert!(Error::from_versioned_json(r#"{"schema_version":"two"}"#).is_err());
+        assert!(Error::from_versioned_json("[]").is_err());
+    }
+}
@@ -0,0 +1,59 @@
+#![cfg(feature = "warp")]
+
+#[cfg(test)]
+mod tests {
+    use cdumay_core::kinds::http::{NotFound, TooManyRequests};
+    use cdumay_core::{ErrorBuilder, handle_cdumay_rejection};
+    use serde_value::Value;
+    use warp::Filter;
+
+    fn body_json(response: &warp::http::Response<warp::hyper::body::Bytes>) -> serde_json::Value {
+        serde_json::from_slice(response.body()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_reply() {
+        let route = warp::path("users").map(|| {
+            ErrorBuilder::new(TooManyRequests, "RateLimitExceeded")
+                .with_detail("retry_after", Value::U64(60))
+                .build()
+        });
+        let response = warp::test::request().path("/users").reply(&route).await;
+        assert_eq!(response.status(), 429);
+        assert_eq!(response.headers()["X-Error-Class"], "Client::TooManyRequests::RateLimitExceeded");
+        assert_eq!(response.headers()["X-Error-Code"], "429");
+        assert_eq!(response.headers()["Retry-After"], "60");
+        let body = body_json(&response);
+        assert_eq!(body["class"], "Client::TooManyRequests::RateLimitExceeded");
+        assert_eq!(body["message"], "Too Many Requests");
+        assert_eq!(body["details"]["retry_after"], 60);
+    }
+
+    #[tokio::test]
+    async fn test_rejection() {
+        let route = warp::path("users")
+            .and_then(|| async { Err::<String, _>(warp::reject::custom(ErrorBuilder::new(NotFound, "UnknownUser").build())) })
+            .recover(handle_cdumay_rejection);
+        let response = warp::test::request().path("/users").reply(&route).await;
+        assert_eq!(response.status(), 404);
+        assert_eq!(response.headers()["X-Error-Class"], "Client::NotFound::UnknownUser");
+        assert_eq!(response.headers()["X-Error-Code"], "404");
+        assert!(response.headers().get("Retry-After").is_none());
+        let body = body_json(&response);
+        assert_eq!(body["class"], "Client::NotFound::UnknownUser");
+        assert_eq!(body["message"], "Not Found");
+    }
+
+    #[tokio::test]
+    async fn test_other_rejection() {
+        let route = warp::path("users").map(|| "users").recover(handle_cdumay_rejection);
+        let response = warp::test::request().path("/groups").reply(&route).await;
+        assert_eq!(response.status(), 404);
+        assert!(response.headers().get("X-Error-Class").is_none());
+
+        let filter = warp::path("users").map(|| "users").recover(handle_cdumay_rejection);
+        let rejection = warp::test::request().path("/groups").filter(&filter).await.unwrap_err();
+        assert!(rejection.is_not_found());
+        assert!(rejection.find::<cdumay_core::Error>().is_none());
+    }
+}
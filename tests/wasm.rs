@@ -0,0 +1,50 @@
+#![cfg(all(feature = "wasm", target_arch = "wasm32"))]
+
+#[cfg(test)]
+mod tests {
+    use cdumay_core::kinds::http::Conflict;
+    use cdumay_core::{Error, ErrorBuilder};
+    use js_sys::Reflect;
+    use serde_value::Value;
+    use wasm_bindgen::JsValue;
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    fn property(value: &JsValue, key: &str) -> JsValue {
+        Reflect::get(value, &JsValue::from_str(key)).unwrap()
+    }
+
+    #[wasm_bindgen_test]
+    fn test_into_js_value() {
+        let value: JsValue = ErrorBuilder::new(Conflict, "DuplicateUser")
+            .with_detail("user", Value::String("john".to_string()))
+            .build()
+            .into();
+        assert!(value.is_instance_of::<js_sys::Error>());
+        assert_eq!(property(&value, "name").as_string().unwrap(), "Client::Conflict::DuplicateUser");
+        assert_eq!(property(&value, "class").as_string().unwrap(), "Client::Conflict::DuplicateUser");
+        assert_eq!(property(&value, "code").as_f64().unwrap(), 409.0);
+        assert_eq!(property(&value, "message").as_string().unwrap(), "Conflict");
+        assert_eq!(property(&property(&value, "details"), "user").as_string().unwrap(), "john");
+    }
+
+    #[wasm_bindgen_test]
+    fn test_roundtrip() {
+        let err = ErrorBuilder::new(Conflict, "DuplicateUser").with_detail("user", Value::String("john".to_string())).build();
+        let decoded: Error = JsValue::from(err.clone()).into();
+        assert_eq!(decoded.code(), 409);
+        assert_eq!(decoded.class(), err.class());
+        assert_eq!(decoded.details(), err.details());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_from_js_error() {
+        let error = js_sys::TypeError::new("undefined is not a function");
+        let err: Error = JsValue::from(error).into();
+        assert_eq!(err.class(), "Server::InternalServerError::TypeError");
+        assert_eq!(err.message(), "undefined is not a function");
+
+        let err: Error = JsValue::from_str("boom").into();
+        assert_eq!(err.class(), "Server::InternalServerError::UnknownError");
+        assert_eq!(err.message(), "boom");
+    }
+}